@@ -0,0 +1,58 @@
+//! Compare serializing a [`Value`] via serde against the direct writer.
+//!
+//! Run with `cargo bench -p zlisp-bin --bench value`.
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use zlisp_bin::{to_vec, value_to_vec};
+use zlisp_value::Value;
+
+const ITERATIONS: u32 = 200;
+
+/// Build a value resembling a typical document of key/value lists.
+fn document() -> Value {
+    let records = (0..2_000)
+        .map(|i| {
+            Value::List(vec![
+                Value::String("name".to_owned()),
+                Value::String(format!("record{}", i)),
+                Value::String("index".to_owned()),
+                Value::Int(i),
+                Value::String("position".to_owned()),
+                Value::List(vec![
+                    Value::Float(i as f32),
+                    Value::Float(0.5),
+                    Value::Float(-1.25),
+                ]),
+            ])
+        })
+        .collect();
+    Value::List(records)
+}
+
+fn bench<F>(name: &str, mut f: F) -> Duration
+where
+    F: FnMut() -> Vec<u8>,
+{
+    // warm up
+    black_box(f());
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+    let per_iter = start.elapsed() / ITERATIONS;
+    println!("{:<16}{:>12?}/iter", name, per_iter);
+    per_iter
+}
+
+fn main() {
+    let value = document();
+    let expected = to_vec(&value).unwrap();
+    assert_eq!(value_to_vec(&value).unwrap(), expected);
+
+    let serde = bench("to_vec", || to_vec(black_box(&value)).unwrap());
+    let direct = bench("value_to_vec", || value_to_vec(black_box(&value)).unwrap());
+    println!(
+        "value_to_vec is {:.2}x the speed of to_vec",
+        serde.as_secs_f64() / direct.as_secs_f64()
+    );
+}
@@ -1,10 +1,14 @@
 use crate::constants::MAX_STRING_LEN;
 use crate::error::{Error, ErrorCode, Result};
 
-pub fn from_raw<'a>(v: &'a [u8], start_offset: usize) -> Result<&'a str> {
+pub fn from_raw(v: &[u8], start_offset: usize) -> Result<&str> {
     // SAFETY: MAX_STRING_LEN < i32::MAX, usize::MIN > i32::MIN
     if v.len() > MAX_STRING_LEN {
-        return Err(Error::new(ErrorCode::StringTooLong, Some(start_offset)));
+        let code = ErrorCode::StringTooLong {
+            len: v.len(),
+            max: MAX_STRING_LEN,
+        };
+        return Err(Error::new(code, Some(start_offset)));
     }
 
     for (offset, b) in (start_offset..).zip(v.iter().copied()) {
@@ -26,11 +30,15 @@ pub fn from_raw<'a>(v: &'a [u8], start_offset: usize) -> Result<&'a str> {
     Ok(unsafe { std::str::from_utf8_unchecked(v) })
 }
 
-pub fn to_raw<'a>(s: &'a str) -> Result<(&'a [u8], i32)> {
+pub fn to_raw(s: &str) -> Result<(&[u8], i32)> {
     let v = s.as_bytes();
 
     if v.len() > MAX_STRING_LEN {
-        return Err(Error::new(ErrorCode::StringTooLong, None));
+        let code = ErrorCode::StringTooLong {
+            len: v.len(),
+            max: MAX_STRING_LEN,
+        };
+        return Err(Error::new(code, None));
     }
     // SAFETY: MAX_STRING_LEN < i32::MAX, usize::MIN > i32::MIN
     let len = v.len() as i32;
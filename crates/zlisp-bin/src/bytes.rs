@@ -0,0 +1,112 @@
+use serde::{de, ser};
+use std::fmt;
+
+/// Represents raw bytes in binary zlisp data.
+///
+/// Strings in binary zlisp data must be ASCII, and can't contain nulls or
+/// quotes. This newtype opts in to writing arbitrary bytes as a string token,
+/// which can be used to embed binary data, e.g. compressed payloads. The
+/// length is still limited to that of strings. Such data can only be read
+/// back as [`Bytes`], not as a string.
+///
+/// Readers that don't know the type, like [`events`](crate::events),
+/// [`RawDocument`](crate::RawDocument), or reading into a value, read every
+/// string token as a string. Unless the bytes happen to be a valid string,
+/// data containing them can't be read this way, and fails with
+/// [`ErrorCode::StringContainsInvalidByte`](crate::ErrorCode::StringContainsInvalidByte)
+/// or similar at the offset of the byte.
+///
+/// Text zlisp data doesn't support raw bytes, so this is only useful with the
+/// binary format.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Bytes(Vec<u8>);
+
+impl Bytes {
+    /// Construct a new value.
+    pub const fn new(value: Vec<u8>) -> Self {
+        Self(value)
+    }
+
+    /// Get the underlying bytes.
+    pub fn get(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Consume the value, returning the underlying bytes.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    fn from(value: Vec<u8>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&[u8]> for Bytes {
+    fn from(value: &[u8]) -> Self {
+        Self(value.to_vec())
+    }
+}
+
+impl From<Bytes> for Vec<u8> {
+    fn from(value: Bytes) -> Self {
+        value.0
+    }
+}
+
+impl PartialEq<[u8]> for Bytes {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<Bytes> for [u8] {
+    fn eq(&self, other: &Bytes) -> bool {
+        self == other.0
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> de::Visitor<'de> for BytesVisitor {
+    type Value = Bytes;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("bytes")
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Bytes(value.to_vec()))
+    }
+
+    fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Bytes(value))
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Bytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_byte_buf(BytesVisitor)
+    }
+}
+
+impl ser::Serialize for Bytes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_bytes(&self.0)
+    }
+}
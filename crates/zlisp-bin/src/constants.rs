@@ -5,3 +5,4 @@ pub(crate) const LIST: i32 = 4;
 pub(crate) const OUTER_LIST_LEN: i32 = 2;
 pub(crate) const MAX_STRING_LEN: usize = 255;
 pub(crate) const MAX_LIST_LEN: usize = 4096;
+pub(crate) const MAGIC: [u8; 4] = *b"ZLSP";
@@ -1,5 +1,6 @@
 use serde::{de, ser};
 use std::fmt;
+use std::sync::Arc;
 
 /// A high-level description of a token.
 #[derive(Debug, Clone, PartialEq)]
@@ -31,8 +32,45 @@ impl fmt::Display for TokenType {
     }
 }
 
+/// An I/O error.
+///
+/// Unlike [`std::io::Error`], this can be cloned and compared. Errors are equal
+/// if they have the same kind and message.
+#[derive(Debug, Clone)]
+pub struct IoError(Arc<std::io::Error>);
+
+impl IoError {
+    /// The kind of the I/O error.
+    pub fn kind(&self) -> std::io::ErrorKind {
+        self.0.kind()
+    }
+
+    /// The underlying I/O error.
+    pub fn get_ref(&self) -> &std::io::Error {
+        &self.0
+    }
+}
+
+impl From<std::io::Error> for IoError {
+    fn from(e: std::io::Error) -> Self {
+        Self(Arc::new(e))
+    }
+}
+
+impl PartialEq for IoError {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.kind() == other.0.kind() && self.0.to_string() == other.0.to_string()
+    }
+}
+
+impl fmt::Display for IoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
 /// The detailed cause of an error.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 #[non_exhaustive]
 pub enum ErrorCode {
     // --- General ---
@@ -41,7 +79,7 @@ pub enum ErrorCode {
     /// This is how serde errors are reported.
     Custom(String),
     /// An error occurred during an I/O operation.
-    IO(std::io::Error),
+    IO(IoError),
     /// The data type is not supported by the serializer or deserializer.
     UnsupportedType,
 
@@ -55,6 +93,15 @@ pub enum ErrorCode {
         /// The actual token type.
         found: TokenType,
     },
+    /// A token was expected, but the end of the data was reached.
+    ///
+    /// This is distinct from [`ErrorCode::ExpectedToken`], so that reaching
+    /// the end can be told apart from other mismatches. Data that ends in the
+    /// middle of a token is [`ErrorCode::InsufficientData`] instead.
+    UnexpectedEof {
+        /// The expected token type.
+        expected: TokenType,
+    },
     /// A list of a certain length was expected.
     ExpectedListOfLength {
         /// The minimum expected list length.
@@ -80,8 +127,24 @@ pub enum ErrorCode {
     InvalidTokenType,
     /// The data contained an invalid list length.
     InvalidListLength,
-    /// The data contained an invalid string length.
+    /// The data contained an invalid string length, i.e. a negative length.
     InvalidStringLength,
+    /// A sequence contained the same element more than once.
+    ///
+    /// This is only checked if duplicates are denied by the reader
+    /// configuration.
+    DuplicateElement,
+    /// The configured maximum nesting depth of lists was exceeded.
+    RecursionLimitExceeded,
+    /// Versioned data did not start with the magic bytes.
+    BadMagic,
+    /// Versioned data has a different version than expected.
+    UnsupportedVersion {
+        /// The expected version.
+        expected: u32,
+        /// The actual version.
+        found: u32,
+    },
 
     // --- Writers ---
     /// A sequence is too long to serialize.
@@ -92,8 +155,14 @@ pub enum ErrorCode {
     // --- Strings ---
     /// A string is too long.
     ///
-    /// Strings may not be longer than 255 bytes.
-    StringTooLong,
+    /// Strings may not be longer than 255 bytes, or the configured maximum
+    /// string length when reading.
+    StringTooLong {
+        /// The length of the string.
+        len: usize,
+        /// The maximum length of the string.
+        max: usize,
+    },
     /// A string contains a null byte/character.
     StringContainsNull,
     /// A string contains a quote byte/character.
@@ -101,6 +170,9 @@ pub enum ErrorCode {
     /// In the text format, there is no way to escape quotes.
     StringContainsQuote,
     /// A string contains an invalid byte/character.
+    ///
+    /// This is also reported when raw [`Bytes`](crate::Bytes) are read as a
+    /// string, e.g. by [`events`](crate::events).
     StringContainsInvalidByte,
 }
 
@@ -109,13 +181,17 @@ impl fmt::Display for ErrorCode {
         match self {
             // General
             ErrorCode::Custom(s) => write!(f, "{}", s),
-            ErrorCode::IO(e) => fmt::Display::fmt(e, f),
+            // the I/O error is the source, and so isn't repeated here
+            ErrorCode::IO(_) => f.write_str("I/O error"),
             ErrorCode::UnsupportedType => f.write_str("unsupported type"),
             // Deserializers
             ErrorCode::TrailingData => f.write_str("trailing data"),
             ErrorCode::ExpectedToken { expected, found } => {
                 write!(f, "expected {}, found {}", expected, found)
             }
+            ErrorCode::UnexpectedEof { expected } => {
+                write!(f, "expected {}, found end of file", expected)
+            }
             ErrorCode::ExpectedListOfLength {
                 expected_min,
                 expected_max,
@@ -141,12 +217,20 @@ impl fmt::Display for ErrorCode {
             }
             ErrorCode::InvalidTokenType => f.write_str("invalid token type"),
             ErrorCode::InvalidListLength => f.write_str("invalid list length"),
-            ErrorCode::InvalidStringLength => f.write_str("invalid string length"),
+            ErrorCode::InvalidStringLength => f.write_str("invalid string length (negative)"),
+            ErrorCode::DuplicateElement => f.write_str("duplicate element"),
+            ErrorCode::RecursionLimitExceeded => f.write_str("recursion limit exceeded"),
+            ErrorCode::BadMagic => f.write_str("bad magic bytes for versioned data"),
+            ErrorCode::UnsupportedVersion { expected, found } => {
+                write!(f, "unsupported version {} (expected {})", found, expected)
+            }
             // Writers
             ErrorCode::SequenceTooLong => f.write_str("sequence is too long"),
             ErrorCode::SequenceMustHaveLength => f.write_str("sequence must have a known length"),
             // Strings
-            ErrorCode::StringTooLong => f.write_str("string is too long"),
+            ErrorCode::StringTooLong { len, max } => {
+                write!(f, "string is too long ({} > {})", len, max)
+            }
             ErrorCode::StringContainsNull => f.write_str("string contains a null"),
             ErrorCode::StringContainsQuote => f.write_str("string contains a quote"),
             ErrorCode::StringContainsInvalidByte => f.write_str("string contains a non-ASCII byte"),
@@ -154,7 +238,7 @@ impl fmt::Display for ErrorCode {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 struct ErrorContext {
     code: ErrorCode,
     offset: Option<usize>,
@@ -171,7 +255,7 @@ impl fmt::Display for ErrorContext {
 
 /// This type represents all possible errors that can occur when serializing or
 /// deserializing binary zlisp data.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Error(Box<ErrorContext>);
 
 /// A specialized [Result](std::result::Result) type for serialization or
@@ -186,7 +270,6 @@ impl Error {
     }
 
     /// The error code.
-
     pub const fn code(&self) -> &ErrorCode {
         &self.0.code
     }
@@ -195,7 +278,6 @@ impl Error {
     ///
     /// For deserialization, this is the offset in the data. For serialization,
     /// likely `None`.
-
     pub const fn offset(&self) -> Option<usize> {
         self.0.offset
     }
@@ -237,8 +319,14 @@ impl de::Error for Error {
 impl de::StdError for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match &self.0.code {
-            ErrorCode::IO(e) => Some(e),
+            ErrorCode::IO(e) => Some(e.get_ref()),
             _ => None,
         }
     }
 }
+
+// errors must be usable across threads, e.g. in async tasks
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Error>();
+};
@@ -8,11 +8,24 @@
     unused
 )]
 mod ascii;
+mod bytes;
 mod constants;
 mod error;
 mod reader;
 mod writer;
 
-pub use error::{Error, ErrorCode, Result, TokenType};
-pub use reader::from_slice;
-pub use writer::{to_vec, to_writer};
+pub use bytes::Bytes;
+pub use error::{Error, ErrorCode, IoError, Result, TokenType};
+#[cfg(feature = "flate2")]
+pub use reader::from_reader_gz;
+pub use reader::{
+    events, events_with_config, from_reader, from_reader_with_config, from_slice,
+    from_slice_partial, from_slice_raw, from_slice_versioned, from_slice_with_config, Event,
+    Events, ListLength, RawDocument, RawKind, RawNode, Reader, ReaderConfig, ReaderConfigBuilder,
+};
+#[cfg(feature = "flate2")]
+pub use writer::to_writer_gz;
+pub use writer::{
+    list_header, to_vec, to_vec_raw, to_vec_versioned, to_vec_with_config, to_writer,
+    to_writer_with_config, value_to_vec, WriterConfig, WriterConfigBuilder,
+};
@@ -0,0 +1,232 @@
+use crate::constants::MAX_STRING_LEN;
+
+const DEFAULT_MAX_DEPTH: usize = 128;
+
+/// The convention used to encode list lengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListLength {
+    /// The length is one bigger than the number of elements, as written by
+    /// Zipper.
+    ZipperPlusOne,
+    /// The length is the exact number of elements.
+    ExactCount,
+}
+
+/// A builder of reader configuration.
+///
+/// This cannot be constructed, use [`ReaderConfig::builder`].
+#[derive(Debug, Clone)]
+pub struct ReaderConfigBuilder {
+    outer_list: bool,
+    max_string_len: usize,
+    deny_duplicates: bool,
+    list_length: ListLength,
+    infer_maps: bool,
+    unit_variant_index: bool,
+    max_depth: usize,
+}
+
+impl ReaderConfigBuilder {
+    /// Expect the value to be wrapped in an outer list of length 1.
+    ///
+    /// Zipper-compatible binary data always starts with this list. Disabling
+    /// it reads the value as-is, as written by a writer with
+    /// [`WriterConfigBuilder::outer_list`](crate::WriterConfigBuilder::outer_list)
+    /// disabled. The default is `true`.
+    #[inline]
+    pub const fn outer_list(mut self, outer_list: bool) -> Self {
+        self.outer_list = outer_list;
+        self
+    }
+
+    /// The maximum length of strings to read, in bytes.
+    ///
+    /// This can be used to enforce a stricter limit than the format allows.
+    /// Values larger than the format maximum of 255 bytes are clamped. The
+    /// default is 255 bytes.
+    #[inline]
+    pub const fn max_string_len(mut self, max_string_len: usize) -> Self {
+        self.max_string_len = if max_string_len < MAX_STRING_LEN {
+            max_string_len
+        } else {
+            MAX_STRING_LEN
+        };
+        self
+    }
+
+    /// Error if a sequence contains the same element more than once.
+    ///
+    /// Serde does not distinguish sets from other sequences, so this applies
+    /// to all sequences, e.g. a `Vec` as well as a `HashSet`. Otherwise,
+    /// duplicate elements silently collapse when reading into a set. The
+    /// default is `false`.
+    #[inline]
+    pub const fn deny_duplicates(mut self, deny_duplicates: bool) -> Self {
+        self.deny_duplicates = deny_duplicates;
+        self
+    }
+
+    /// The convention used to encode list lengths.
+    ///
+    /// Some producers store the exact number of elements, instead of the
+    /// number of elements plus one as Zipper does. This also applies to the
+    /// outer list. The default is [`ListLength::ZipperPlusOne`].
+    #[inline]
+    pub const fn list_length(mut self, list_length: ListLength) -> Self {
+        self.list_length = list_length;
+        self
+    }
+
+    /// Read lists of alternating string keys and values as maps, when reading
+    /// untyped data.
+    ///
+    /// When enabled, a non-empty, even-length list where every key (i.e. the
+    /// first, third, ... element) is a string is visited as a map instead of
+    /// a sequence when the type is not known, e.g. when reading into a
+    /// `serde_json::Value`. This requires scanning each such list before
+    /// reading it. Typed reads are not affected. The default is `false`, as
+    /// such lists may also be sequences.
+    #[inline]
+    pub const fn infer_maps(mut self, infer_maps: bool) -> Self {
+        self.infer_maps = infer_maps;
+        self
+    }
+
+    /// Read unit enum variants from their index as well as their name.
+    ///
+    /// When enabled, an int where an enum variant is expected is read as the
+    /// variant index, as written by a writer with
+    /// [`WriterConfigBuilder::unit_variant_index`](crate::WriterConfigBuilder::unit_variant_index)
+    /// enabled. Variants written by name can still be read. The default is
+    /// `false`.
+    #[inline]
+    pub const fn unit_variant_index(mut self, unit_variant_index: bool) -> Self {
+        self.unit_variant_index = unit_variant_index;
+        self
+    }
+
+    /// The maximum nesting depth of lists to read.
+    ///
+    /// Reading nested lists recurses, so deeply nested data could otherwise
+    /// overflow the stack. Exceeding the depth is an error. The outer list
+    /// does not count towards the depth. The default is 128.
+    #[inline]
+    pub const fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Construct a new reader configuration.
+    #[inline]
+    pub const fn build(self) -> ReaderConfig {
+        ReaderConfig {
+            outer_list: self.outer_list,
+            max_string_len: self.max_string_len,
+            deny_duplicates: self.deny_duplicates,
+            list_length: self.list_length,
+            infer_maps: self.infer_maps,
+            unit_variant_index: self.unit_variant_index,
+            max_depth: self.max_depth,
+        }
+    }
+}
+
+/// Configuration for binary readers.
+#[derive(Debug, Clone)]
+pub struct ReaderConfig {
+    /// Expect the value to be wrapped in an outer list of length 1.
+    pub(crate) outer_list: bool,
+    /// The maximum length of strings to read, in bytes.
+    pub(crate) max_string_len: usize,
+    /// Error if a sequence contains the same element more than once.
+    pub(crate) deny_duplicates: bool,
+    /// The convention used to encode list lengths.
+    pub(crate) list_length: ListLength,
+    /// Read lists of alternating string keys and values as maps, when reading
+    /// untyped data.
+    pub(crate) infer_maps: bool,
+    /// Read unit enum variants from their index as well as their name.
+    pub(crate) unit_variant_index: bool,
+    /// The maximum nesting depth of lists to read.
+    pub(crate) max_depth: usize,
+}
+
+impl ReaderConfig {
+    /// The default, Zipper-compatible reader configuration.
+    ///
+    /// This expects the value to be wrapped in an outer list.
+    pub const DEFAULT: Self = Self {
+        outer_list: true,
+        max_string_len: MAX_STRING_LEN,
+        deny_duplicates: false,
+        list_length: ListLength::ZipperPlusOne,
+        infer_maps: false,
+        unit_variant_index: false,
+        max_depth: DEFAULT_MAX_DEPTH,
+    };
+
+    /// The default, Zipper-compatible reader configuration.
+    ///
+    /// This expects the value to be wrapped in an outer list.
+    #[inline(always)]
+    pub const fn default() -> &'static Self {
+        &Self::DEFAULT
+    }
+
+    /// Construct a builder for a reader configuration.
+    #[inline]
+    pub const fn builder() -> ReaderConfigBuilder {
+        ReaderConfigBuilder {
+            outer_list: true,
+            max_string_len: MAX_STRING_LEN,
+            deny_duplicates: false,
+            list_length: ListLength::ZipperPlusOne,
+            infer_maps: false,
+            unit_variant_index: false,
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+
+    /// Expect the value to be wrapped in an outer list of length 1.
+    #[inline(always)]
+    pub const fn outer_list(&self) -> bool {
+        self.outer_list
+    }
+
+    /// The maximum length of strings to read, in bytes.
+    #[inline(always)]
+    pub const fn max_string_len(&self) -> usize {
+        self.max_string_len
+    }
+
+    /// Error if a sequence contains the same element more than once.
+    #[inline(always)]
+    pub const fn deny_duplicates(&self) -> bool {
+        self.deny_duplicates
+    }
+
+    /// The convention used to encode list lengths.
+    #[inline(always)]
+    pub const fn list_length(&self) -> ListLength {
+        self.list_length
+    }
+
+    /// Read lists of alternating string keys and values as maps, when reading
+    /// untyped data.
+    #[inline(always)]
+    pub const fn infer_maps(&self) -> bool {
+        self.infer_maps
+    }
+
+    /// Read unit enum variants from their index as well as their name.
+    #[inline(always)]
+    pub const fn unit_variant_index(&self) -> bool {
+        self.unit_variant_index
+    }
+
+    /// The maximum nesting depth of lists to read.
+    #[inline(always)]
+    pub const fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+}
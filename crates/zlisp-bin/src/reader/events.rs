@@ -0,0 +1,102 @@
+use super::config::ReaderConfig;
+use super::token_reader::{SliceReader, Token};
+use crate::error::Result;
+
+/// An event produced while reading binary zlisp data.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event<'a> {
+    /// The start of a list, with the number of elements in the list.
+    ListStart(usize),
+    /// The end of a list.
+    ListEnd,
+    /// An integer.
+    Int(i32),
+    /// A float.
+    Float(f32),
+    /// A string.
+    Str(&'a str),
+}
+
+/// An iterator over the events of binary zlisp data.
+///
+/// This cannot be constructed, use [`events`] or [`events_with_config`].
+#[derive(Debug, Clone)]
+pub struct Events<'a> {
+    /// The reader, or `None` after the end of the data or an error.
+    reader: Option<SliceReader<'a>>,
+    /// The number of elements remaining in each open list, including a
+    /// single-element list for the root value.
+    remaining: Vec<usize>,
+    outer_list: bool,
+}
+
+impl<'a> Events<'a> {
+    fn advance(&mut self, reader: &mut SliceReader<'a>) -> Result<Option<Event<'a>>> {
+        if self.outer_list {
+            self.outer_list = false;
+            reader.unwrap_outer_list()?;
+        }
+        match self.remaining.last_mut() {
+            None => Ok(None),
+            Some(0) => {
+                self.remaining.pop();
+                if self.remaining.is_empty() {
+                    Ok(None)
+                } else {
+                    Ok(Some(Event::ListEnd))
+                }
+            }
+            Some(count) => {
+                *count -= 1;
+                let event = match reader.read_any()? {
+                    Token::Int(v) => Event::Int(v),
+                    Token::Float(v) => Event::Float(v),
+                    Token::Str(v) => Event::Str(v),
+                    Token::List(len) => {
+                        self.remaining.push(len);
+                        Event::ListStart(len)
+                    }
+                };
+                Ok(Some(event))
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for Events<'a> {
+    type Item = Result<Event<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut reader = self.reader.take()?;
+        match self.advance(&mut reader) {
+            Ok(Some(event)) => {
+                self.reader = Some(reader);
+                Some(Ok(event))
+            }
+            Ok(None) => reader.finish().err().map(Err),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// Read events from binary zlisp data, without building the value.
+///
+/// After the first error, the iterator is exhausted. String tokens are always
+/// read as strings, so data containing [`Bytes`](crate::Bytes) can't be read
+/// unless the bytes are a valid string.
+pub fn events(s: &[u8]) -> Events<'_> {
+    events_with_config(s, ReaderConfig::default())
+}
+
+/// Read events from binary zlisp data, using the reader configuration.
+///
+/// Options that require the value, like
+/// [`deny_duplicates`](crate::ReaderConfigBuilder::deny_duplicates), are
+/// ignored.
+pub fn events_with_config<'a>(s: &'a [u8], config: &ReaderConfig) -> Events<'a> {
+    Events {
+        reader: Some(SliceReader::from_slice(s, config)),
+        remaining: vec![1],
+        outer_list: config.outer_list,
+    }
+}
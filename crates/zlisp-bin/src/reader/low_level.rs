@@ -0,0 +1,84 @@
+use super::config::ReaderConfig;
+use super::events::Event;
+use super::token_reader::{SliceReader, Token};
+use crate::error::Result;
+
+/// A low-level reader for binary zlisp data, for hand-written parsers.
+///
+/// This is useful for formats where the structure depends on earlier values,
+/// which can't be expressed with serde. Binary lists are prefixed by their
+/// length, so there is no end of a list to read.
+#[derive(Debug, Clone)]
+pub struct Reader<'a> {
+    inner: SliceReader<'a>,
+}
+
+impl<'a> Reader<'a> {
+    /// Construct a new reader over binary zlisp data.
+    ///
+    /// This fails if the data does not start with the outer list.
+    pub fn new(s: &'a [u8]) -> Result<Self> {
+        Self::with_config(s, ReaderConfig::default())
+    }
+
+    /// Construct a new reader over binary zlisp data, using the reader
+    /// configuration.
+    ///
+    /// If [`outer_list`](crate::ReaderConfigBuilder::outer_list) is set, this
+    /// fails if the data does not start with the outer list. Options that
+    /// require the value, like
+    /// [`deny_duplicates`](crate::ReaderConfigBuilder::deny_duplicates), are
+    /// ignored.
+    pub fn with_config(s: &'a [u8], config: &ReaderConfig) -> Result<Self> {
+        let mut inner = SliceReader::from_slice(s, config);
+        if config.outer_list {
+            inner.unwrap_outer_list()?;
+        }
+        Ok(Self { inner })
+    }
+
+    /// The offset of the next token in the data.
+    pub const fn offset(&self) -> usize {
+        self.inner.source().offset()
+    }
+
+    /// Read an integer.
+    pub fn read_i32(&mut self) -> Result<i32> {
+        self.inner.read_i32()
+    }
+
+    /// Read a float.
+    pub fn read_f32(&mut self) -> Result<f32> {
+        self.inner.read_f32()
+    }
+
+    /// Read a string.
+    pub fn read_str(&mut self) -> Result<&'a str> {
+        self.inner.read_str()
+    }
+
+    /// Read the start of a list, returning the number of elements in the
+    /// list.
+    pub fn read_list(&mut self) -> Result<usize> {
+        self.inner.read_list().map(|(len, _offset)| len)
+    }
+
+    /// Read any token.
+    ///
+    /// This is never [`Event::ListEnd`]. For [`Event::ListStart`], the
+    /// elements of the list follow.
+    pub fn read_any(&mut self) -> Result<Event<'a>> {
+        let event = match self.inner.read_any()? {
+            Token::Int(v) => Event::Int(v),
+            Token::Float(v) => Event::Float(v),
+            Token::Str(v) => Event::Str(v),
+            Token::List(len) => Event::ListStart(len),
+        };
+        Ok(event)
+    }
+
+    /// Check the end of the data has been reached.
+    pub fn finish(self) -> Result<()> {
+        self.inner.finish()
+    }
+}
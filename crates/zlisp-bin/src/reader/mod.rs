@@ -1,15 +1,152 @@
-mod slice_reader;
+mod config;
+mod events;
+mod low_level;
+mod raw;
+mod source;
+mod token_reader;
+
+pub use config::{ListLength, ReaderConfig, ReaderConfigBuilder};
+pub use events::{events, events_with_config, Event, Events};
+pub use low_level::Reader;
+pub use raw::{RawDocument, RawKind, RawNode};
 
 use crate::error::Result;
 
 /// Deserialize a value from binary zlisp data.
+///
+/// The data must start with an outer list of length 1 containing the value,
+/// as written by [`to_vec`](crate::to_vec). To read data without it, use
+/// [`from_slice_raw`] or [`ReaderConfigBuilder::outer_list`].
 pub fn from_slice<'a, T>(s: &'a [u8]) -> Result<T>
 where
     T: serde::Deserialize<'a>,
 {
-    let mut reader = slice_reader::SliceReader::new(s);
+    from_slice_with_config(s, ReaderConfig::default())
+}
+
+/// Deserialize a value from binary zlisp data, using the reader configuration.
+pub fn from_slice_with_config<'a, T>(s: &'a [u8], config: &ReaderConfig) -> Result<T>
+where
+    T: serde::Deserialize<'a>,
+{
+    let mut reader = token_reader::SliceReader::from_slice(s, config);
+    if config.outer_list {
+        reader.unwrap_outer_list()?;
+    }
+    let v = T::deserialize(&mut reader)?;
+    reader.finish()?;
+    Ok(v)
+}
+
+/// Deserialize a value from the start of binary zlisp data, returning the
+/// remaining data.
+///
+/// Unlike [`from_slice`], data after the value is not an error, but is
+/// returned instead. This can be used to read multiple values written one
+/// after another by [`to_vec`](crate::to_vec), e.g. by calling this until the
+/// remaining data is empty.
+pub fn from_slice_partial<'a, T>(s: &'a [u8]) -> Result<(T, &'a [u8])>
+where
+    T: serde::Deserialize<'a>,
+{
+    let mut reader = token_reader::SliceReader::from_slice(s, ReaderConfig::default());
+    reader.unwrap_outer_list()?;
+    let v = T::deserialize(&mut reader)?;
+    Ok((v, reader.remaining()))
+}
+
+/// Deserialize a value from binary zlisp data, read from an I/O source.
+///
+/// Unlike [`from_slice`], the data is read incrementally, so it does not have
+/// to be held in memory at once. Reads are not buffered, so wrapping the
+/// source in a [`BufReader`](std::io::BufReader) is recommended. Error offsets
+/// are relative to the start of the source.
+pub fn from_reader<R, T>(reader: R) -> Result<T>
+where
+    R: std::io::Read,
+    T: serde::de::DeserializeOwned,
+{
+    from_reader_with_config(reader, ReaderConfig::default())
+}
+
+/// Deserialize a value from binary zlisp data, read from an I/O source, using
+/// the reader configuration.
+///
+/// Options that inspect or compare the elements of a list, like
+/// [`ReaderConfigBuilder::infer_maps`], buffer those elements while they are
+/// read.
+pub fn from_reader_with_config<R, T>(reader: R, config: &ReaderConfig) -> Result<T>
+where
+    R: std::io::Read,
+    T: serde::de::DeserializeOwned,
+{
+    let mut reader = token_reader::ReadReader::from_reader(reader, config);
+    if config.outer_list {
+        reader.unwrap_outer_list()?;
+    }
+    let v = T::deserialize(&mut reader)?;
+    reader.finish()?;
+    Ok(v)
+}
+
+/// Deserialize a value from versioned binary zlisp data.
+///
+/// This reads data written by [`to_vec_versioned`](crate::to_vec_versioned),
+/// and checks the header matches the version. Offsets in errors include the
+/// header.
+pub fn from_slice_versioned<'a, T>(s: &'a [u8], version: u32) -> Result<T>
+where
+    T: serde::Deserialize<'a>,
+{
+    let mut reader = token_reader::SliceReader::from_slice(s, ReaderConfig::default());
+    reader.read_header(version)?;
     reader.unwrap_outer_list()?;
     let v = T::deserialize(&mut reader)?;
     reader.finish()?;
     Ok(v)
 }
+
+/// Deserialize a value from binary zlisp data without an outer list.
+///
+/// This reads data written by [`to_vec_raw`](crate::to_vec_raw).
+pub fn from_slice_raw<'a, T>(s: &'a [u8]) -> Result<T>
+where
+    T: serde::Deserialize<'a>,
+{
+    const RAW: ReaderConfig = ReaderConfig::builder().outer_list(false).build();
+    from_slice_with_config(s, &RAW)
+}
+
+#[cfg(feature = "flate2")]
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Deserialize a value from binary zlisp data, which may be gzip-compressed.
+///
+/// If the data starts with the gzip magic bytes, it is transparently
+/// decompressed. Otherwise, the data is read as-is. In either case, the data
+/// is read incrementally like [`from_reader`], and error offsets are relative
+/// to the decompressed data.
+#[cfg(feature = "flate2")]
+pub fn from_reader_gz<R, T>(mut reader: R) -> Result<T>
+where
+    R: std::io::Read,
+    T: serde::de::DeserializeOwned,
+{
+    use crate::error::{Error, ErrorCode};
+    use std::io::Read as _;
+
+    // the magic bytes have to be read to be inspected, so they are chained
+    // back in front of the remaining data afterwards.
+    let mut magic = Vec::with_capacity(GZIP_MAGIC.len());
+    (&mut reader)
+        .take(GZIP_MAGIC.len() as u64)
+        .read_to_end(&mut magic)
+        .map_err(|e| Error::new(ErrorCode::IO(e.into()), None))?;
+    let chained = magic.as_slice().chain(reader);
+
+    if magic == GZIP_MAGIC {
+        from_reader(flate2::read::GzDecoder::new(chained))
+    } else {
+        from_reader(chained)
+    }
+}
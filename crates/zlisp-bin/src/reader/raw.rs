@@ -0,0 +1,186 @@
+use super::config::{ListLength, ReaderConfig};
+use super::token_reader::{SliceReader, Token};
+use crate::error::Result;
+use crate::writer::value_to_vec_raw;
+use zlisp_value::Value;
+
+/// The length of a list header, i.e. the type and the length.
+const LIST_HEADER_LEN: usize = 8;
+
+/// The decoded contents of a [`RawNode`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum RawKind<'a> {
+    /// An integer.
+    Int(i32),
+    /// A float.
+    Float(f32),
+    /// A string.
+    Str(&'a str),
+    /// A list, with the nodes of the elements.
+    List(Vec<RawNode<'a>>),
+}
+
+/// A value in binary zlisp data, which remembers its exact bytes.
+///
+/// Unless the node is replaced, it is written back byte-identical, including
+/// any producer-specific quirks such as the list length convention.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawNode<'a> {
+    raw: &'a [u8],
+    kind: RawKind<'a>,
+    replacement: Option<Value>,
+}
+
+impl<'a> RawNode<'a> {
+    fn parse(reader: &mut SliceReader<'a>, input: &'a [u8]) -> Result<Self> {
+        let start = reader.offset();
+        let kind = match reader.read_any()? {
+            Token::Int(v) => RawKind::Int(v),
+            Token::Float(v) => RawKind::Float(v),
+            Token::Str(v) => RawKind::Str(v),
+            Token::List(len) => {
+                let children = reader.nested(|reader| {
+                    (0..len)
+                        .map(|_| Self::parse(reader, input))
+                        .collect::<Result<Vec<_>>>()
+                })?;
+                RawKind::List(children)
+            }
+        };
+        let raw = &input[start..reader.offset()];
+        Ok(Self {
+            raw,
+            kind,
+            replacement: None,
+        })
+    }
+
+    fn write(&self, buf: &mut Vec<u8>, list_length: ListLength) -> Result<()> {
+        match (&self.replacement, &self.kind) {
+            (Some(value), _) => buf.extend(value_to_vec_raw(value, list_length)?),
+            // a list's bytes are its header followed by the elements' bytes,
+            // so the elements can be written individually.
+            (None, RawKind::List(children)) => {
+                buf.extend_from_slice(&self.raw[..LIST_HEADER_LEN]);
+                for child in children {
+                    child.write(buf, list_length)?;
+                }
+            }
+            (None, _) => buf.extend_from_slice(self.raw),
+        }
+        Ok(())
+    }
+
+    /// The original bytes of the node.
+    pub const fn raw(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    /// The original, decoded contents of the node.
+    pub const fn kind(&self) -> &RawKind<'a> {
+        &self.kind
+    }
+
+    /// The value the node was replaced with, if any.
+    pub const fn replacement(&self) -> Option<&Value> {
+        self.replacement.as_ref()
+    }
+
+    /// Replace the node with a value.
+    ///
+    /// The value is encoded when the document is written, so the bytes of
+    /// the node (and any elements) are not preserved, and the elements can
+    /// no longer be edited. Lists are encoded with the list length
+    /// convention the document was read with.
+    pub fn set(&mut self, value: Value) {
+        self.replacement = Some(value);
+    }
+
+    /// The element nodes, if the node is a list.
+    pub fn children(&self) -> Option<&[RawNode<'a>]> {
+        match &self.kind {
+            RawKind::List(children) => Some(children),
+            _ => None,
+        }
+    }
+
+    /// The original element nodes, for editing, unless the node was replaced.
+    ///
+    /// Edits to the elements of a replaced node would not be written, so
+    /// they can't be edited.
+    fn children_mut(&mut self) -> Option<&mut [RawNode<'a>]> {
+        match (&self.replacement, &mut self.kind) {
+            (None, RawKind::List(children)) => Some(children),
+            _ => None,
+        }
+    }
+
+    /// The element node at the index, if the node is a list and wasn't
+    /// replaced.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut RawNode<'a>> {
+        self.children_mut()?.get_mut(index)
+    }
+
+    /// The value node for the field, if the node is a list of alternating
+    /// keys and values, e.g. a struct, and wasn't replaced.
+    pub fn field_mut(&mut self, name: &str) -> Option<&mut RawNode<'a>> {
+        self.children_mut()?
+            .chunks_exact_mut(2)
+            .find(|pair| pair[0].kind == RawKind::Str(name))
+            .map(|pair| &mut pair[1])
+    }
+}
+
+/// Binary zlisp data that can be edited in place.
+///
+/// Any nodes that aren't replaced are written back byte-identical. String
+/// tokens are always read as strings, so data containing
+/// [`Bytes`](crate::Bytes) can't be read unless the bytes are a valid string.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawDocument<'a> {
+    /// The outer list header, if any.
+    prefix: &'a [u8],
+    root: RawNode<'a>,
+    /// The list length convention, to encode replacements with.
+    list_length: ListLength,
+}
+
+impl<'a> RawDocument<'a> {
+    /// Read binary zlisp data.
+    pub fn from_slice(s: &'a [u8]) -> Result<Self> {
+        Self::from_slice_with_config(s, ReaderConfig::default())
+    }
+
+    /// Read binary zlisp data, using the reader configuration.
+    pub fn from_slice_with_config(s: &'a [u8], config: &ReaderConfig) -> Result<Self> {
+        let mut reader = SliceReader::from_slice(s, config);
+        if config.outer_list {
+            reader.unwrap_outer_list()?;
+        }
+        let prefix = &s[..reader.offset()];
+        let root = RawNode::parse(&mut reader, s)?;
+        reader.finish()?;
+        Ok(Self {
+            prefix,
+            root,
+            list_length: config.list_length,
+        })
+    }
+
+    /// The root node.
+    pub const fn root(&self) -> &RawNode<'a> {
+        &self.root
+    }
+
+    /// The root node, for editing.
+    pub fn root_mut(&mut self) -> &mut RawNode<'a> {
+        &mut self.root
+    }
+
+    /// Write the document back to binary zlisp data.
+    pub fn to_vec(&self) -> Result<Vec<u8>> {
+        let mut buf = self.prefix.to_vec();
+        self.root.write(&mut buf, self.list_length)?;
+        Ok(buf)
+    }
+}
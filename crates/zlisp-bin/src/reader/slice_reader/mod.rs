@@ -1,4 +0,0 @@
-mod de;
-mod private;
-
-pub use private::{SliceReader, Token};
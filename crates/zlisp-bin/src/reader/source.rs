@@ -0,0 +1,274 @@
+use crate::ascii::from_raw;
+use crate::error::{Error, ErrorCode, Result};
+use serde::de::Visitor;
+use std::hash::Hash;
+use std::io::Read;
+
+fn insufficient_data(expected: usize, available: usize, offset: usize) -> Error {
+    let code = ErrorCode::InsufficientData {
+        expected,
+        available,
+    };
+    Error::new(code, Some(offset))
+}
+
+/// A source of binary zlisp data, which the tokens are decoded from.
+///
+/// Data can be retained from a mark, so it can be read again or inspected.
+/// Marks must be rewound or released in the reverse order they were made.
+pub trait Source<'de> {
+    /// A string taken from the source.
+    type Str;
+    /// Bytes taken from the source.
+    type Bytes: AsRef<[u8]> + Hash + Eq;
+
+    /// The offset of the next byte, relative to the start of the source.
+    fn offset(&self) -> usize;
+
+    /// Whether the end of the data was reached.
+    fn is_empty(&mut self) -> Result<bool>;
+
+    /// The next 4 bytes, without consuming them, or `None` if there are
+    /// less than 4 bytes left.
+    fn peek_4(&mut self) -> Result<Option<[u8; 4]>>;
+
+    fn take_4(&mut self) -> Result<[u8; 4]>;
+
+    fn take_bytes(&mut self, n: usize) -> Result<Self::Bytes>;
+
+    /// Take `n` bytes, which must be ASCII.
+    fn take_str(&mut self, n: usize) -> Result<Self::Str>;
+
+    /// Retain the data from the current offset on, until the mark is rewound
+    /// or released.
+    fn mark(&mut self) -> usize;
+
+    /// Go back to the mark, and stop retaining the data.
+    fn rewind(&mut self, mark: usize);
+
+    /// Stop retaining the data, returning the data read since the mark.
+    fn release(&mut self, mark: usize) -> Self::Bytes;
+
+    fn visit_str<V: Visitor<'de>>(v: Self::Str, visitor: V) -> Result<V::Value>;
+
+    fn visit_bytes<V: Visitor<'de>>(v: Self::Bytes, visitor: V) -> Result<V::Value>;
+}
+
+/// A source of binary zlisp data in memory, which strings are borrowed from.
+#[derive(Debug, Clone)]
+pub struct SliceSource<'a> {
+    input: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> SliceSource<'a> {
+    pub const fn new(input: &'a [u8]) -> Self {
+        Self { input, offset: 0 }
+    }
+
+    /// The offset of the next byte.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The input that has not been read yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.input[self.offset..]
+    }
+
+    fn take_n(&mut self, n: usize) -> Result<&'a [u8]> {
+        let remaining = self.remaining();
+        match remaining.get(..n) {
+            Some(take) => {
+                self.offset += n;
+                Ok(take)
+            }
+            None => Err(insufficient_data(n, remaining.len(), self.offset)),
+        }
+    }
+}
+
+impl<'a> Source<'a> for SliceSource<'a> {
+    type Str = &'a str;
+    type Bytes = &'a [u8];
+
+    #[inline]
+    fn offset(&self) -> usize {
+        self.offset
+    }
+
+    #[inline]
+    fn is_empty(&mut self) -> Result<bool> {
+        Ok(self.offset >= self.input.len())
+    }
+
+    fn peek_4(&mut self) -> Result<Option<[u8; 4]>> {
+        Ok(self
+            .remaining()
+            .get(..4)
+            .and_then(|buf| buf.try_into().ok()))
+    }
+
+    fn take_4(&mut self) -> Result<[u8; 4]> {
+        let offset = self.offset;
+        let take = self.take_n(4)?;
+        // take_n returns a slice of length 4, so this can't fail. but an
+        // error is cheap, and malformed input can never panic.
+        take.try_into()
+            .map_err(|_| insufficient_data(4, take.len(), offset))
+    }
+
+    fn take_bytes(&mut self, n: usize) -> Result<&'a [u8]> {
+        self.take_n(n)
+    }
+
+    fn take_str(&mut self, n: usize) -> Result<&'a str> {
+        let offset = self.offset;
+        let v = self.take_n(n)?;
+        from_raw(v, offset)
+    }
+
+    #[inline]
+    fn mark(&mut self) -> usize {
+        self.offset
+    }
+
+    #[inline]
+    fn rewind(&mut self, mark: usize) {
+        self.offset = mark;
+    }
+
+    fn release(&mut self, mark: usize) -> &'a [u8] {
+        &self.input[mark..self.offset]
+    }
+
+    fn visit_str<V: Visitor<'a>>(v: &'a str, visitor: V) -> Result<V::Value> {
+        visitor.visit_borrowed_str(v)
+    }
+
+    fn visit_bytes<V: Visitor<'a>>(v: &'a [u8], visitor: V) -> Result<V::Value> {
+        visitor.visit_borrowed_bytes(v)
+    }
+}
+
+/// A source of binary zlisp data read from an I/O source.
+///
+/// Data is only buffered as far as needed to decode the next token, or from
+/// the oldest mark on.
+#[derive(Debug)]
+pub struct IoSource<R> {
+    inner: R,
+    /// Data read from the inner reader. Data before `pos` was consumed, and
+    /// is only kept while there are marks.
+    buf: Vec<u8>,
+    pos: usize,
+    offset: usize,
+    marks: usize,
+}
+
+impl<R: Read> IoSource<R> {
+    pub const fn new(inner: R) -> Self {
+        Self {
+            inner,
+            buf: Vec::new(),
+            pos: 0,
+            offset: 0,
+            marks: 0,
+        }
+    }
+
+    /// Read from the inner reader until at least `n` bytes are available, or
+    /// the end of the data is reached. Returns the available bytes.
+    fn fill(&mut self, n: usize) -> Result<usize> {
+        if self.marks == 0 && self.pos > 0 {
+            self.buf.drain(..self.pos);
+            self.pos = 0;
+        }
+        let available = self.buf.len() - self.pos;
+        if available < n {
+            // reads are retried on interrupts, and stop at the end of the data
+            (&mut self.inner)
+                .take((n - available) as u64)
+                .read_to_end(&mut self.buf)
+                .map_err(|e| Error::new(ErrorCode::IO(e.into()), Some(self.offset)))?;
+        }
+        Ok(self.buf.len() - self.pos)
+    }
+
+    fn take_n(&mut self, n: usize) -> Result<&[u8]> {
+        let available = self.fill(n)?;
+        if available >= n {
+            let start = self.pos;
+            self.pos += n;
+            self.offset += n;
+            Ok(&self.buf[start..self.pos])
+        } else {
+            Err(insufficient_data(n, available, self.offset))
+        }
+    }
+}
+
+impl<'de, R: Read> Source<'de> for IoSource<R> {
+    type Str = String;
+    type Bytes = Vec<u8>;
+
+    #[inline]
+    fn offset(&self) -> usize {
+        self.offset
+    }
+
+    fn is_empty(&mut self) -> Result<bool> {
+        self.fill(1).map(|available| available == 0)
+    }
+
+    fn peek_4(&mut self) -> Result<Option<[u8; 4]>> {
+        self.fill(4)?;
+        Ok(self.buf[self.pos..]
+            .get(..4)
+            .and_then(|buf| buf.try_into().ok()))
+    }
+
+    fn take_4(&mut self) -> Result<[u8; 4]> {
+        let offset = self.offset;
+        let take = self.take_n(4)?;
+        // take_n returns a slice of length 4, so this can't fail. but an
+        // error is cheap, and malformed input can never panic.
+        take.try_into()
+            .map_err(|_| insufficient_data(4, take.len(), offset))
+    }
+
+    fn take_bytes(&mut self, n: usize) -> Result<Vec<u8>> {
+        self.take_n(n).map(<[u8]>::to_vec)
+    }
+
+    fn take_str(&mut self, n: usize) -> Result<String> {
+        let offset = self.offset;
+        let v = self.take_n(n)?;
+        from_raw(v, offset).map(str::to_owned)
+    }
+
+    fn mark(&mut self) -> usize {
+        self.marks += 1;
+        self.offset
+    }
+
+    fn rewind(&mut self, mark: usize) {
+        self.pos -= self.offset - mark;
+        self.offset = mark;
+        self.marks -= 1;
+    }
+
+    fn release(&mut self, mark: usize) -> Vec<u8> {
+        let start = self.pos - (self.offset - mark);
+        self.marks -= 1;
+        self.buf[start..self.pos].to_vec()
+    }
+
+    fn visit_str<V: Visitor<'de>>(v: String, visitor: V) -> Result<V::Value> {
+        visitor.visit_string(v)
+    }
+
+    fn visit_bytes<V: Visitor<'de>>(v: Vec<u8>, visitor: V) -> Result<V::Value> {
+        visitor.visit_byte_buf(v)
+    }
+}
@@ -1,6 +1,8 @@
-use super::{SliceReader, Token};
+use super::{Token, TokenReader};
 use crate::error::{Error, ErrorCode, Result};
-use serde::de::{self, Deserializer as _, Visitor};
+use crate::reader::source::Source;
+use serde::de::{self, Deserializer as _, IntoDeserializer as _, Visitor};
+use std::collections::HashSet;
 
 macro_rules! unsupported {
     ($method:ident) => {
@@ -8,12 +10,12 @@ macro_rules! unsupported {
         where
             V: Visitor<'de>,
         {
-            Err(Error::new(ErrorCode::UnsupportedType, Some(self.offset)))
+            Err(Error::new(ErrorCode::UnsupportedType, Some(self.offset())))
         }
     };
 }
 
-impl<'a, 'de: 'a> de::Deserializer<'de> for &'a mut SliceReader<'de> {
+impl<'de, S: Source<'de>> de::Deserializer<'de> for &mut TokenReader<S> {
     type Error = Error;
 
     fn is_human_readable(&self) -> bool {
@@ -30,8 +32,6 @@ impl<'a, 'de: 'a> de::Deserializer<'de> for &'a mut SliceReader<'de> {
     unsupported!(deserialize_u64);
     unsupported!(deserialize_f64);
     unsupported!(deserialize_char);
-    unsupported!(deserialize_bytes);
-    unsupported!(deserialize_byte_buf);
 
     fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
     where
@@ -40,10 +40,18 @@ impl<'a, 'de: 'a> de::Deserializer<'de> for &'a mut SliceReader<'de> {
         match self.read_any()? {
             Token::Int(v) => visitor.visit_i32(v),
             Token::Float(v) => visitor.visit_f32(v),
-            Token::Str(v) => visitor.visit_borrowed_str(v),
-            Token::List(len) => visitor.visit_seq(SizedSeqAccess {
-                deserializer: self,
-                len,
+            Token::Str(v) => S::visit_str(v, visitor),
+            Token::List(len) if self.infer_maps && self.is_string_keyed(len) => self.nested(|de| {
+                visitor.visit_map(SizedSeqAccess {
+                    deserializer: de,
+                    len,
+                })
+            }),
+            Token::List(len) => self.nested(|de| {
+                visitor.visit_seq(SizedSeqAccess {
+                    deserializer: de,
+                    len,
+                })
             }),
         }
     }
@@ -66,7 +74,7 @@ impl<'a, 'de: 'a> de::Deserializer<'de> for &'a mut SliceReader<'de> {
     where
         V: Visitor<'de>,
     {
-        visitor.visit_borrowed_str(self.read_str()?)
+        S::visit_str(self.read_str()?, visitor)
     }
 
     fn deserialize_string<V>(self, visitor: V) -> Result<V::Value>
@@ -76,6 +84,20 @@ impl<'a, 'de: 'a> de::Deserializer<'de> for &'a mut SliceReader<'de> {
         self.deserialize_str(visitor)
     }
 
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        S::visit_bytes(self.read_bytes()?, visitor)
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_bytes(visitor)
+    }
+
     fn deserialize_option<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
@@ -83,7 +105,7 @@ impl<'a, 'de: 'a> de::Deserializer<'de> for &'a mut SliceReader<'de> {
         let (len, offset) = self.read_list()?;
         match len {
             0 => visitor.visit_none(),
-            1 => visitor.visit_some(self),
+            1 => self.nested(|de| visitor.visit_some(de)),
             _ => {
                 let code = ErrorCode::ExpectedListOfLength {
                     expected_min: 0,
@@ -134,10 +156,22 @@ impl<'a, 'de: 'a> de::Deserializer<'de> for &'a mut SliceReader<'de> {
         V: Visitor<'de>,
     {
         let (len, _offset) = self.read_list()?;
-        visitor.visit_seq(SizedSeqAccess {
-            deserializer: self,
-            len,
-        })
+        if self.deny_duplicates {
+            self.nested(|de| {
+                visitor.visit_seq(UniqueSeqAccess {
+                    deserializer: de,
+                    len,
+                    seen: HashSet::new(),
+                })
+            })
+        } else {
+            self.nested(|de| {
+                visitor.visit_seq(SizedSeqAccess {
+                    deserializer: de,
+                    len,
+                })
+            })
+        }
     }
 
     fn deserialize_tuple<V>(self, tuple_len: usize, visitor: V) -> Result<V::Value>
@@ -153,9 +187,11 @@ impl<'a, 'de: 'a> de::Deserializer<'de> for &'a mut SliceReader<'de> {
             };
             return Err(Error::new(code, Some(offset)));
         }
-        visitor.visit_seq(SizedSeqAccess {
-            deserializer: self,
-            len: tuple_len,
+        self.nested(|de| {
+            visitor.visit_seq(SizedSeqAccess {
+                deserializer: de,
+                len: tuple_len,
+            })
         })
     }
 
@@ -176,9 +212,11 @@ impl<'a, 'de: 'a> de::Deserializer<'de> for &'a mut SliceReader<'de> {
         V: Visitor<'de>,
     {
         let (len, _offset) = self.read_list()?;
-        visitor.visit_map(SizedSeqAccess {
-            deserializer: self,
-            len,
+        self.nested(|de| {
+            visitor.visit_map(SizedSeqAccess {
+                deserializer: de,
+                len,
+            })
         })
     }
 
@@ -224,12 +262,12 @@ impl<'a, 'de: 'a> de::Deserializer<'de> for &'a mut SliceReader<'de> {
     }
 }
 
-struct SizedSeqAccess<'a, 'de> {
-    deserializer: &'a mut SliceReader<'de>,
+struct SizedSeqAccess<'a, S> {
+    deserializer: &'a mut TokenReader<S>,
     len: usize,
 }
 
-impl<'a, 'de: 'a> de::SeqAccess<'de> for SizedSeqAccess<'a, 'de> {
+impl<'de, S: Source<'de>> de::SeqAccess<'de> for SizedSeqAccess<'_, S> {
     type Error = Error;
 
     fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
@@ -238,7 +276,7 @@ impl<'a, 'de: 'a> de::SeqAccess<'de> for SizedSeqAccess<'a, 'de> {
     {
         if self.len > 0 {
             self.len -= 1;
-            let offset = self.deserializer.offset;
+            let offset = self.deserializer.offset();
             seed.deserialize(&mut *self.deserializer)
                 .map(Some)
                 .map_err(|e| e.attach_offset(offset))
@@ -252,7 +290,44 @@ impl<'a, 'de: 'a> de::SeqAccess<'de> for SizedSeqAccess<'a, 'de> {
     }
 }
 
-impl<'a, 'de: 'a> de::MapAccess<'de> for SizedSeqAccess<'a, 'de> {
+struct UniqueSeqAccess<'a, 'de, S: Source<'de>> {
+    deserializer: &'a mut TokenReader<S>,
+    len: usize,
+    seen: HashSet<S::Bytes>,
+}
+
+impl<'de, S: Source<'de>> de::SeqAccess<'de> for UniqueSeqAccess<'_, 'de, S> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        if self.len > 0 {
+            self.len -= 1;
+            let offset = self.deserializer.offset();
+            let mark = self.deserializer.mark();
+            let v = seed.deserialize(&mut *self.deserializer);
+            // the binary encoding of equal elements is identical, so compare
+            // the raw bytes of the element.
+            let raw = self.deserializer.release(mark);
+            let v = v.map_err(|e| e.attach_offset(offset))?;
+            if self.seen.insert(raw) {
+                Ok(Some(v))
+            } else {
+                Err(Error::new(ErrorCode::DuplicateElement, Some(offset)))
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<'de, S: Source<'de>> de::MapAccess<'de> for SizedSeqAccess<'_, S> {
     type Error = Error;
 
     fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
@@ -264,11 +339,11 @@ impl<'a, 'de: 'a> de::MapAccess<'de> for SizedSeqAccess<'a, 'de> {
         } else if self.len < 2 {
             Err(Error::new(
                 ErrorCode::ExpectedKeyValuePair,
-                Some(self.deserializer.offset),
+                Some(self.deserializer.offset()),
             ))
         } else {
             self.len -= 2;
-            let offset = self.deserializer.offset;
+            let offset = self.deserializer.offset();
             seed.deserialize(&mut *self.deserializer)
                 .map(Some)
                 .map_err(|e| e.attach_offset(offset))
@@ -279,7 +354,7 @@ impl<'a, 'de: 'a> de::MapAccess<'de> for SizedSeqAccess<'a, 'de> {
     where
         V: de::DeserializeSeed<'de>,
     {
-        let offset = self.deserializer.offset;
+        let offset = self.deserializer.offset();
         seed.deserialize(&mut *self.deserializer)
             .map_err(|e| e.attach_offset(offset))
     }
@@ -289,7 +364,7 @@ impl<'a, 'de: 'a> de::MapAccess<'de> for SizedSeqAccess<'a, 'de> {
     }
 }
 
-impl<'a, 'de: 'a> de::EnumAccess<'de> for &'a mut SliceReader<'de> {
+impl<'de, S: Source<'de>> de::EnumAccess<'de> for &mut TokenReader<S> {
     type Error = Error;
     type Variant = Self;
 
@@ -297,15 +372,26 @@ impl<'a, 'de: 'a> de::EnumAccess<'de> for &'a mut SliceReader<'de> {
     where
         V: de::DeserializeSeed<'de>,
     {
-        let offset = self.offset;
-        match seed.deserialize(&mut *self) {
-            Ok(v) => Ok((v, self)),
-            Err(e) => Err(e.attach_offset(offset)),
-        }
+        let offset = self.offset();
+        let v = if self.unit_variant_index && self.peek_is_int()? {
+            // unit variants may be represented by their index instead
+            self.read_i32().and_then(|index| {
+                let index = u32::try_from(index).map_err(|_| {
+                    de::Error::invalid_value(
+                        de::Unexpected::Signed(index.into()),
+                        &"a variant index",
+                    )
+                })?;
+                seed.deserialize(index.into_deserializer())
+            })
+        } else {
+            seed.deserialize(&mut *self)
+        };
+        v.map(|v| (v, self)).map_err(|e| e.attach_offset(offset))
     }
 }
 
-impl<'a, 'de: 'a> de::VariantAccess<'de> for &'a mut SliceReader<'de> {
+impl<'de, S: Source<'de>> de::VariantAccess<'de> for &mut TokenReader<S> {
     type Error = Error;
 
     fn unit_variant(self) -> Result<()> {
@@ -329,8 +415,8 @@ impl<'a, 'de: 'a> de::VariantAccess<'de> for &'a mut SliceReader<'de> {
             };
             return Err(Error::new(code, Some(offset)));
         }
-        let offset = self.offset;
-        seed.deserialize(&mut *self)
+        let offset = self.offset();
+        self.nested(|de| seed.deserialize(de))
             .map_err(|e| e.attach_offset(offset))
     }
 
@@ -352,9 +438,11 @@ impl<'a, 'de: 'a> de::VariantAccess<'de> for &'a mut SliceReader<'de> {
         let (len, _offset) = self.read_list()?;
         // Warning: do not compare len to the fields, this would break for e.g.
         // optional fields.
-        visitor.visit_map(SizedSeqAccess {
-            deserializer: &mut *self,
-            len,
+        self.nested(|de| {
+            visitor.visit_map(SizedSeqAccess {
+                deserializer: de,
+                len,
+            })
         })
     }
 }
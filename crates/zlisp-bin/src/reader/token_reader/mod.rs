@@ -0,0 +1,4 @@
+mod de;
+mod private;
+
+pub use private::{ReadReader, SliceReader, Token, TokenReader};
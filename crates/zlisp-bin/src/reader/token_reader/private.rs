@@ -0,0 +1,358 @@
+use crate::constants::{FLOAT, INT, LIST, MAGIC, MAX_LIST_LEN, STRING};
+use crate::error::{Error, ErrorCode, Result, TokenType};
+use crate::reader::config::{ListLength, ReaderConfig};
+use crate::reader::source::{IoSource, SliceSource, Source};
+use std::io::Read;
+
+fn unexpected_eof(expected: TokenType, offset: usize) -> Error {
+    Error::new(ErrorCode::UnexpectedEof { expected }, Some(offset))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token<S> {
+    Int(i32),
+    Float(f32),
+    Str(S),
+    List(usize),
+}
+
+/// A reader of binary zlisp tokens, decoded from the source.
+#[derive(Debug, Clone)]
+pub struct TokenReader<S> {
+    source: S,
+    max_string_len: usize,
+    pub deny_duplicates: bool,
+    list_length: ListLength,
+    pub infer_maps: bool,
+    pub unit_variant_index: bool,
+    depth: usize,
+    max_depth: usize,
+}
+
+pub type SliceReader<'a> = TokenReader<SliceSource<'a>>;
+pub type ReadReader<R> = TokenReader<IoSource<R>>;
+
+impl<'a> SliceReader<'a> {
+    pub const fn from_slice(input: &'a [u8], config: &ReaderConfig) -> Self {
+        Self::new(SliceSource::new(input), config)
+    }
+
+    /// The input that has not been read yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.source.remaining()
+    }
+}
+
+impl<R: Read> ReadReader<R> {
+    pub const fn from_reader(inner: R, config: &ReaderConfig) -> Self {
+        Self::new(IoSource::new(inner), config)
+    }
+}
+
+impl<'de, S: Source<'de>> TokenReader<S> {
+    pub const fn new(source: S, config: &ReaderConfig) -> Self {
+        Self {
+            source,
+            max_string_len: config.max_string_len,
+            deny_duplicates: config.deny_duplicates,
+            list_length: config.list_length,
+            infer_maps: config.infer_maps,
+            unit_variant_index: config.unit_variant_index,
+            depth: 0,
+            max_depth: config.max_depth,
+        }
+    }
+
+    /// The source the tokens are decoded from.
+    pub const fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// The offset of the next token, relative to the start of the source.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.source.offset()
+    }
+
+    /// Retain the data from the current offset on, see [`Source::mark`].
+    #[inline]
+    pub fn mark(&mut self) -> usize {
+        self.source.mark()
+    }
+
+    /// Stop retaining the data, see [`Source::release`].
+    #[inline]
+    pub fn release(&mut self, mark: usize) -> S::Bytes {
+        self.source.release(mark)
+    }
+
+    fn take_4(&mut self) -> Result<[u8; 4]> {
+        self.source.take_4()
+    }
+
+    fn take_i32(&mut self) -> Result<i32> {
+        self.take_4().map(i32::from_le_bytes)
+    }
+
+    fn take_f32(&mut self) -> Result<f32> {
+        self.take_4().map(f32::from_le_bytes)
+    }
+
+    fn take_string_len(&mut self) -> Result<usize> {
+        let offset = self.offset();
+        self.take_i32().and_then(|len| {
+            if len < 0 {
+                Err(Error::new(ErrorCode::InvalidStringLength, Some(offset)))
+            } else if len as usize > self.max_string_len {
+                let code = ErrorCode::StringTooLong {
+                    len: len as usize,
+                    max: self.max_string_len,
+                };
+                Err(Error::new(code, Some(offset)))
+            } else {
+                Ok(len as usize)
+            }
+        })
+    }
+
+    fn take_bytes(&mut self) -> Result<S::Bytes> {
+        let len = self.take_string_len()?;
+        self.source.take_bytes(len)
+    }
+
+    fn take_str(&mut self) -> Result<S::Str> {
+        let len = self.take_string_len()?;
+        self.source.take_str(len)
+    }
+
+    fn take_list(&mut self) -> Result<usize> {
+        let offset = self.offset();
+        let list_length = self.list_length;
+        self.take_i32().and_then(|len| {
+            let len = match list_length {
+                // for some reason, the length is one bigger than the values in
+                // the list. at the bottom end, the length is invalid anyway...
+                ListLength::ZipperPlusOne => len.saturating_sub(1),
+                ListLength::ExactCount => len,
+            };
+            if len < 0 {
+                Err(Error::new(ErrorCode::InvalidListLength, Some(offset)))
+            } else if len > MAX_LIST_LEN as i32 {
+                Err(Error::new(ErrorCode::SequenceTooLong, Some(offset)))
+            } else {
+                Ok(len as usize)
+            }
+        })
+    }
+
+    pub fn read_i32(&mut self) -> Result<i32> {
+        fn expected_int(found: TokenType, offset: usize) -> Error {
+            let code = ErrorCode::ExpectedToken {
+                expected: TokenType::Int,
+                found,
+            };
+            Error::new(code, Some(offset))
+        }
+
+        if self.source.is_empty()? {
+            return Err(unexpected_eof(TokenType::Int, self.offset()));
+        }
+
+        let offset = self.offset();
+        let ty = self.take_i32()?;
+        match ty {
+            INT => self.take_i32(),
+            FLOAT => Err(expected_int(TokenType::Float, offset)),
+            STRING => Err(expected_int(TokenType::String, offset)),
+            LIST => Err(expected_int(TokenType::List, offset)),
+            _ => Err(Error::new(ErrorCode::InvalidTokenType, Some(offset))),
+        }
+    }
+
+    pub fn read_f32(&mut self) -> Result<f32> {
+        fn expected_float(found: TokenType, offset: usize) -> Error {
+            let code = ErrorCode::ExpectedToken {
+                expected: TokenType::Float,
+                found,
+            };
+            Error::new(code, Some(offset))
+        }
+
+        if self.source.is_empty()? {
+            return Err(unexpected_eof(TokenType::Float, self.offset()));
+        }
+
+        let offset = self.offset();
+        let ty = self.take_i32()?;
+        match ty {
+            FLOAT => self.take_f32(),
+            INT => Err(expected_float(TokenType::Int, offset)),
+            STRING => Err(expected_float(TokenType::String, offset)),
+            LIST => Err(expected_float(TokenType::List, offset)),
+            _ => Err(Error::new(ErrorCode::InvalidTokenType, Some(offset))),
+        }
+    }
+
+    pub fn read_str(&mut self) -> Result<S::Str> {
+        self.read_string_token()?;
+        self.take_str()
+    }
+
+    /// Read a string token as bytes, without validating the contents.
+    pub fn read_bytes(&mut self) -> Result<S::Bytes> {
+        self.read_string_token()?;
+        self.take_bytes()
+    }
+
+    fn read_string_token(&mut self) -> Result<()> {
+        fn expected_str(found: TokenType, offset: usize) -> Error {
+            let code = ErrorCode::ExpectedToken {
+                expected: TokenType::String,
+                found,
+            };
+            Error::new(code, Some(offset))
+        }
+
+        if self.source.is_empty()? {
+            return Err(unexpected_eof(TokenType::String, self.offset()));
+        }
+
+        let offset = self.offset();
+        let ty = self.take_i32()?;
+        match ty {
+            STRING => Ok(()),
+            INT => Err(expected_str(TokenType::Int, offset)),
+            FLOAT => Err(expected_str(TokenType::Float, offset)),
+            LIST => Err(expected_str(TokenType::List, offset)),
+            _ => Err(Error::new(ErrorCode::InvalidTokenType, Some(offset))),
+        }
+    }
+
+    pub fn read_list(&mut self) -> Result<(usize, usize)> {
+        fn expected_list(found: TokenType, offset: usize) -> Error {
+            let code = ErrorCode::ExpectedToken {
+                expected: TokenType::List,
+                found,
+            };
+            Error::new(code, Some(offset))
+        }
+
+        if self.source.is_empty()? {
+            return Err(unexpected_eof(TokenType::List, self.offset()));
+        }
+
+        let ty_offset = self.offset();
+        let ty = self.take_i32()?;
+        let len_offset = self.offset();
+        match ty {
+            LIST => self.take_list().map(|len| (len, len_offset)),
+            INT => Err(expected_list(TokenType::Int, ty_offset)),
+            FLOAT => Err(expected_list(TokenType::Float, ty_offset)),
+            STRING => Err(expected_list(TokenType::String, ty_offset)),
+            _ => Err(Error::new(ErrorCode::InvalidTokenType, Some(ty_offset))),
+        }
+    }
+
+    pub fn read_any(&mut self) -> Result<Token<S::Str>> {
+        if self.source.is_empty()? {
+            return Err(unexpected_eof(TokenType::Any, self.offset()));
+        }
+
+        let offset = self.offset();
+        let ty = self.take_i32()?;
+        match ty {
+            INT => self.take_i32().map(Token::Int),
+            FLOAT => self.take_f32().map(Token::Float),
+            STRING => self.take_str().map(Token::Str),
+            LIST => self.take_list().map(Token::List),
+            _ => Err(Error::new(ErrorCode::InvalidTokenType, Some(offset))),
+        }
+    }
+
+    /// Read the elements of a list one level deeper, checking the depth limit.
+    ///
+    /// This must be called after the list header was read, so an error is
+    /// reported at the first element.
+    pub fn nested<T, F>(&mut self, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Self) -> Result<T>,
+    {
+        if self.depth >= self.max_depth {
+            return Err(Error::new(
+                ErrorCode::RecursionLimitExceeded,
+                Some(self.offset()),
+            ));
+        }
+        self.depth += 1;
+        let v = f(self);
+        self.depth -= 1;
+        v
+    }
+
+    /// Whether the next token is an int, without consuming it.
+    pub fn peek_is_int(&mut self) -> Result<bool> {
+        self.source
+            .peek_4()
+            .map(|buf| buf == Some(INT.to_le_bytes()))
+    }
+
+    /// Skip the next element, including any nested elements.
+    fn skip_any(&mut self) -> Result<()> {
+        if let Token::List(len) = self.read_any()? {
+            self.nested(|reader| (0..len).try_for_each(|_| reader.skip_any()))?;
+        }
+        Ok(())
+    }
+
+    /// Whether the next elements of a list of this length are alternating
+    /// string keys and values, without consuming them.
+    ///
+    /// Empty lists are ambiguous, and never considered keyed. Invalid data
+    /// is also never considered keyed, so the error is reported when the
+    /// elements are read.
+    pub fn is_string_keyed(&mut self, len: usize) -> bool {
+        if len == 0 || len % 2 == 1 {
+            return false;
+        }
+        let mark = self.source.mark();
+        let keyed = (0..len / 2)
+            .all(|_| matches!(self.read_any(), Ok(Token::Str(_))) && self.skip_any().is_ok());
+        self.source.rewind(mark);
+        keyed
+    }
+
+    pub fn finish(mut self) -> Result<()> {
+        if self.source.is_empty()? {
+            Ok(())
+        } else {
+            Err(Error::new(ErrorCode::TrailingData, Some(self.offset())))
+        }
+    }
+
+    /// Versioned data starts with the magic bytes and the version
+    pub fn read_header(&mut self, version: u32) -> Result<()> {
+        let offset = self.offset();
+        if self.take_4()? != MAGIC {
+            return Err(Error::new(ErrorCode::BadMagic, Some(offset)));
+        }
+        let offset = self.offset();
+        let found = self.take_4().map(u32::from_le_bytes)?;
+        if found != version {
+            let code = ErrorCode::UnsupportedVersion {
+                expected: version,
+                found,
+            };
+            return Err(Error::new(code, Some(offset)));
+        }
+        Ok(())
+    }
+
+    /// Binary zlisp data must always start with a list of length 1
+    pub fn unwrap_outer_list(&mut self) -> Result<()> {
+        let (len, offset) = self.read_list()?;
+        match len {
+            1 => Ok(()),
+            _ => Err(Error::new(ErrorCode::InvalidListLength, Some(offset))),
+        }
+    }
+}
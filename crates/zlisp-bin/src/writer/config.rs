@@ -0,0 +1,95 @@
+/// A builder of writer configuration.
+///
+/// This cannot be constructed, use [`WriterConfig::builder`].
+#[derive(Debug, Clone)]
+pub struct WriterConfigBuilder {
+    outer_list: bool,
+    unit_variant_index: bool,
+}
+
+impl WriterConfigBuilder {
+    /// Wrap the value in an outer list of length 1.
+    ///
+    /// Zipper-compatible binary data always starts with this list. Disabling
+    /// it writes the value as-is, which can only be read back by a reader
+    /// with [`ReaderConfigBuilder::outer_list`](crate::ReaderConfigBuilder::outer_list)
+    /// disabled. The default is `true`.
+    #[inline]
+    pub const fn outer_list(mut self, outer_list: bool) -> Self {
+        self.outer_list = outer_list;
+        self
+    }
+
+    /// Write unit enum variants as their index instead of their name.
+    ///
+    /// When enabled, unit variants are written as an int of the variant
+    /// index, which is more compact than the name. Other variants are still
+    /// written with their name. Reading this data requires
+    /// [`ReaderConfigBuilder::unit_variant_index`](crate::ReaderConfigBuilder::unit_variant_index).
+    /// Reordering the variants changes the data, so this is not suitable for
+    /// long-lived data. The default is `false`.
+    #[inline]
+    pub const fn unit_variant_index(mut self, unit_variant_index: bool) -> Self {
+        self.unit_variant_index = unit_variant_index;
+        self
+    }
+
+    /// Construct a new writer configuration.
+    #[inline]
+    pub const fn build(self) -> WriterConfig {
+        WriterConfig {
+            outer_list: self.outer_list,
+            unit_variant_index: self.unit_variant_index,
+        }
+    }
+}
+
+/// Configuration for binary writers.
+#[derive(Debug, Clone)]
+pub struct WriterConfig {
+    /// Wrap the value in an outer list of length 1.
+    pub(crate) outer_list: bool,
+    /// Write unit enum variants as their index instead of their name.
+    pub(crate) unit_variant_index: bool,
+}
+
+impl WriterConfig {
+    /// The default, Zipper-compatible writer configuration.
+    ///
+    /// This wraps the value in an outer list, and writes unit variants by
+    /// name.
+    pub const DEFAULT: Self = Self {
+        outer_list: true,
+        unit_variant_index: false,
+    };
+
+    /// The default, Zipper-compatible writer configuration.
+    ///
+    /// This wraps the value in an outer list, and writes unit variants by
+    /// name.
+    #[inline(always)]
+    pub const fn default() -> &'static Self {
+        &Self::DEFAULT
+    }
+
+    /// Construct a builder for a writer configuration.
+    #[inline]
+    pub const fn builder() -> WriterConfigBuilder {
+        WriterConfigBuilder {
+            outer_list: true,
+            unit_variant_index: false,
+        }
+    }
+
+    /// Wrap the value in an outer list of length 1.
+    #[inline(always)]
+    pub const fn outer_list(&self) -> bool {
+        self.outer_list
+    }
+
+    /// Write unit enum variants as their index instead of their name.
+    #[inline(always)]
+    pub const fn unit_variant_index(&self) -> bool {
+        self.unit_variant_index
+    }
+}
@@ -1,16 +1,24 @@
 use crate::ascii::to_raw;
-use crate::constants::{FLOAT, INT, LIST, MAX_LIST_LEN, OUTER_LIST_LEN, STRING};
+use crate::constants::{FLOAT, INT, LIST, MAX_LIST_LEN, MAX_STRING_LEN, OUTER_LIST_LEN, STRING};
 use crate::error::{Error, ErrorCode, Result};
+use crate::reader::ListLength;
+use crate::writer::config::WriterConfig;
 use std::io::Write;
 
 #[derive(Debug, Clone)]
 pub struct IoWriter<W> {
     inner: W,
+    pub unit_variant_index: bool,
+    pub list_length: ListLength,
 }
 
 impl<W> IoWriter<W> {
-    pub const fn new(inner: W) -> Self {
-        Self { inner }
+    pub const fn new(inner: W, config: &WriterConfig) -> Self {
+        Self {
+            inner,
+            unit_variant_index: config.unit_variant_index,
+            list_length: ListLength::ZipperPlusOne,
+        }
     }
 }
 
@@ -18,7 +26,7 @@ impl<W: Write> IoWriter<W> {
     fn write_all(&mut self, buf: &[u8]) -> Result<()> {
         self.inner
             .write_all(buf)
-            .map_err(|e| Error::new(ErrorCode::IO(e), None))
+            .map_err(|e| Error::new(ErrorCode::IO(e.into()), None))
     }
 
     pub fn write_i32(&mut self, v: i32) -> Result<()> {
@@ -38,6 +46,21 @@ impl<W: Write> IoWriter<W> {
         self.write_all(v)
     }
 
+    pub fn write_bytes(&mut self, v: &[u8]) -> Result<()> {
+        if v.len() > MAX_STRING_LEN {
+            let code = ErrorCode::StringTooLong {
+                len: v.len(),
+                max: MAX_STRING_LEN,
+            };
+            return Err(Error::new(code, None));
+        }
+        // SAFETY: MAX_STRING_LEN < i32::MAX
+        let len = v.len() as i32;
+        self.write_all(&STRING.to_le_bytes())?;
+        self.write_all(&len.to_le_bytes())?;
+        self.write_all(v)
+    }
+
     pub fn write_list(&mut self, len: Option<usize>) -> Result<()> {
         let len: i32 = len
             .ok_or_else(|| Error::new(ErrorCode::SequenceMustHaveLength, None))
@@ -53,7 +76,10 @@ impl<W: Write> IoWriter<W> {
     }
 
     pub fn write_list_unchecked(&mut self, len: i32) -> Result<()> {
-        let count = len + 1;
+        let count = match self.list_length {
+            ListLength::ZipperPlusOne => len + 1,
+            ListLength::ExactCount => len,
+        };
         self.write_all(&LIST.to_le_bytes())?;
         self.write_all(&count.to_le_bytes())
     }
@@ -61,7 +87,7 @@ impl<W: Write> IoWriter<W> {
     pub fn finish(mut self) -> Result<W> {
         self.inner
             .flush()
-            .map_err(|e| Error::new(ErrorCode::IO(e), None))?;
+            .map_err(|e| Error::new(ErrorCode::IO(e.into()), None))?;
         Ok(self.inner)
     }
 
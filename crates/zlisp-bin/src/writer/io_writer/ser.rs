@@ -20,7 +20,7 @@ macro_rules! unsupported {
     };
 }
 
-impl<'a, W: Write> ser::Serializer for &'a mut IoWriter<W> {
+impl<W: Write> ser::Serializer for &mut IoWriter<W> {
     type Ok = ();
     type Error = Error;
 
@@ -32,6 +32,10 @@ impl<'a, W: Write> ser::Serializer for &'a mut IoWriter<W> {
     type SerializeStruct = Self;
     type SerializeStructVariant = Self;
 
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
     unsupported!(serialize_bool, bool);
     unsupported!(serialize_i8, i8);
     unsupported!(serialize_i16, i16);
@@ -42,7 +46,6 @@ impl<'a, W: Write> ser::Serializer for &'a mut IoWriter<W> {
     unsupported!(serialize_u64, u64);
     unsupported!(serialize_f64, f64);
     unsupported!(serialize_char, char);
-    unsupported!(serialize_bytes, &[u8]);
 
     fn serialize_i32(self, v: i32) -> Result<()> {
         self.write_i32(v)
@@ -56,6 +59,10 @@ impl<'a, W: Write> ser::Serializer for &'a mut IoWriter<W> {
         self.write_str(v)
     }
 
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.write_bytes(v)
+    }
+
     fn serialize_none(self) -> Result<()> {
         self.serialize_unit()
     }
@@ -79,10 +86,16 @@ impl<'a, W: Write> ser::Serializer for &'a mut IoWriter<W> {
     fn serialize_unit_variant(
         self,
         _name: &'static str,
-        _variant_index: u32,
+        variant_index: u32,
         variant: &'static str,
     ) -> Result<()> {
-        self.serialize_str(variant)
+        if self.unit_variant_index {
+            let index = i32::try_from(variant_index)
+                .map_err(|_| Error::new(ErrorCode::UnsupportedType, None))?;
+            self.serialize_i32(index)
+        } else {
+            self.serialize_str(variant)
+        }
     }
 
     fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<()>
@@ -163,7 +176,7 @@ impl<'a, W: Write> ser::Serializer for &'a mut IoWriter<W> {
     }
 }
 
-impl<'a, W: Write> ser::SerializeSeq for &'a mut IoWriter<W> {
+impl<W: Write> ser::SerializeSeq for &mut IoWriter<W> {
     type Ok = ();
     type Error = Error;
 
@@ -179,7 +192,7 @@ impl<'a, W: Write> ser::SerializeSeq for &'a mut IoWriter<W> {
     }
 }
 
-impl<'a, W: Write> ser::SerializeTuple for &'a mut IoWriter<W> {
+impl<W: Write> ser::SerializeTuple for &mut IoWriter<W> {
     type Ok = ();
     type Error = Error;
 
@@ -195,7 +208,7 @@ impl<'a, W: Write> ser::SerializeTuple for &'a mut IoWriter<W> {
     }
 }
 
-impl<'a, W: Write> ser::SerializeTupleStruct for &'a mut IoWriter<W> {
+impl<W: Write> ser::SerializeTupleStruct for &mut IoWriter<W> {
     type Ok = ();
     type Error = Error;
 
@@ -211,7 +224,7 @@ impl<'a, W: Write> ser::SerializeTupleStruct for &'a mut IoWriter<W> {
     }
 }
 
-impl<'a, W: Write> ser::SerializeTupleVariant for &'a mut IoWriter<W> {
+impl<W: Write> ser::SerializeTupleVariant for &mut IoWriter<W> {
     type Ok = ();
     type Error = Error;
 
@@ -227,7 +240,7 @@ impl<'a, W: Write> ser::SerializeTupleVariant for &'a mut IoWriter<W> {
     }
 }
 
-impl<'a, W: Write> ser::SerializeMap for &'a mut IoWriter<W> {
+impl<W: Write> ser::SerializeMap for &mut IoWriter<W> {
     type Ok = ();
     type Error = Error;
 
@@ -245,10 +258,10 @@ impl<'a, W: Write> ser::SerializeMap for &'a mut IoWriter<W> {
         value.serialize(&mut **self)
     }
 
-    fn serialize_entry<K: ?Sized, V: ?Sized>(&mut self, key: &K, value: &V) -> Result<()>
+    fn serialize_entry<K, V>(&mut self, key: &K, value: &V) -> Result<()>
     where
-        K: Serialize,
-        V: Serialize,
+        K: ?Sized + Serialize,
+        V: ?Sized + Serialize,
     {
         key.serialize(&mut **self)?;
         value.serialize(&mut **self)
@@ -259,7 +272,7 @@ impl<'a, W: Write> ser::SerializeMap for &'a mut IoWriter<W> {
     }
 }
 
-impl<'a, W: Write> ser::SerializeStruct for &'a mut IoWriter<W> {
+impl<W: Write> ser::SerializeStruct for &mut IoWriter<W> {
     type Ok = ();
     type Error = Error;
 
@@ -276,7 +289,7 @@ impl<'a, W: Write> ser::SerializeStruct for &'a mut IoWriter<W> {
     }
 }
 
-impl<'a, W: Write> ser::SerializeStructVariant for &'a mut IoWriter<W> {
+impl<W: Write> ser::SerializeStructVariant for &mut IoWriter<W> {
     type Ok = ();
     type Error = Error;
 
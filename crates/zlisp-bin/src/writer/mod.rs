@@ -1,17 +1,80 @@
+mod config;
 mod io_writer;
+mod value;
 
+pub use config::{WriterConfig, WriterConfigBuilder};
+pub use value::value_to_vec;
+pub(crate) use value::value_to_vec_raw;
+
+use crate::constants::MAGIC;
 use crate::error::Result;
 
 /// Serialize a value to binary zlisp data.
+///
+/// Zipper-compatible binary data always starts with a list, so the value is
+/// wrapped in an outer list of length 1. For example, `1i32` is written as
+/// the list token and length (`4`, `2`; list lengths count the list itself),
+/// followed by the int token and value (`1`, `1`), each as a little-endian
+/// `i32`. [`from_slice`](crate::from_slice) expects and removes this list. To
+/// write the value without it, use [`to_vec_raw`] or
+/// [`WriterConfigBuilder::outer_list`].
 pub fn to_vec<T>(value: &T) -> Result<Vec<u8>>
 where
     T: ?Sized + serde::Serialize,
 {
-    let mut serializer = io_writer::IoWriter::new(std::io::Cursor::new(Vec::new()));
-    serializer.wrap_outer_list()?;
-    value.serialize(&mut serializer)?;
-    let cursor = serializer.finish()?;
-    Ok(cursor.into_inner())
+    to_vec_with_config(value, WriterConfig::default())
+}
+
+/// Serialize a value to binary zlisp data, using the writer configuration.
+pub fn to_vec_with_config<T>(value: &T, config: &WriterConfig) -> Result<Vec<u8>>
+where
+    T: ?Sized + serde::Serialize,
+{
+    let mut buf = Vec::new();
+    to_writer_with_config(&mut buf, value, config)?;
+    Ok(buf)
+}
+
+/// Serialize a value to binary zlisp data without an outer list.
+///
+/// This data is not Zipper-compatible, and can be read by
+/// [`from_slice_raw`](crate::from_slice_raw).
+pub fn to_vec_raw<T>(value: &T) -> Result<Vec<u8>>
+where
+    T: ?Sized + serde::Serialize,
+{
+    const RAW: WriterConfig = WriterConfig::builder().outer_list(false).build();
+    to_vec_with_config(value, &RAW)
+}
+
+/// Serialize a value to versioned binary zlisp data.
+///
+/// The data starts with an 8-byte header: the magic bytes `ZLSP`, followed
+/// by the version as a little-endian `u32`. The header is followed by the
+/// value, as written by [`to_vec`]. This data is not Zipper-compatible, and
+/// can be read by [`from_slice_versioned`](crate::from_slice_versioned).
+pub fn to_vec_versioned<T>(value: &T, version: u32) -> Result<Vec<u8>>
+where
+    T: ?Sized + serde::Serialize,
+{
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&MAGIC);
+    buf.extend_from_slice(&version.to_le_bytes());
+    to_writer(&mut buf, value)?;
+    Ok(buf)
+}
+
+/// Encode the header of a list with this many elements.
+///
+/// The header is the list token and length, as written by [`to_vec`]. Since
+/// binary lists are prefixed by their length, this can be used to write the
+/// header once the elements of a list are known, e.g. when converting from
+/// another format.
+pub fn list_header(len: usize) -> Result<[u8; 8]> {
+    let mut header = [0; 8];
+    let mut writer = io_writer::IoWriter::new(&mut header[..], WriterConfig::default());
+    writer.write_list(Some(len))?;
+    Ok(header)
 }
 
 /// Serialize a value to binary zlisp data.
@@ -20,9 +83,41 @@ where
     T: ?Sized + serde::Serialize,
     W: std::io::Write,
 {
-    let mut serializer = io_writer::IoWriter::new(writer);
-    serializer.wrap_outer_list()?;
+    to_writer_with_config(writer, value, WriterConfig::default())
+}
+
+/// Serialize a value to binary zlisp data, using the writer configuration.
+pub fn to_writer_with_config<W, T>(writer: W, value: &T, config: &WriterConfig) -> Result<()>
+where
+    T: ?Sized + serde::Serialize,
+    W: std::io::Write,
+{
+    let mut serializer = io_writer::IoWriter::new(writer, config);
+    if config.outer_list {
+        serializer.wrap_outer_list()?;
+    }
     value.serialize(&mut serializer)?;
     let _ = serializer.finish()?;
     Ok(())
 }
+
+/// Serialize a value to gzip-compressed binary zlisp data.
+#[cfg(feature = "flate2")]
+pub fn to_writer_gz<W, T>(writer: W, value: &T) -> Result<()>
+where
+    T: ?Sized + serde::Serialize,
+    W: std::io::Write,
+{
+    use crate::error::{Error, ErrorCode};
+
+    let encoder = flate2::write::GzEncoder::new(writer, flate2::Compression::default());
+    let mut serializer = io_writer::IoWriter::new(encoder, WriterConfig::default());
+    serializer.wrap_outer_list()?;
+    value.serialize(&mut serializer)?;
+    let encoder = serializer.finish()?;
+    // flushing the encoder does not write the gzip trailer
+    let _ = encoder
+        .finish()
+        .map_err(|e| Error::new(ErrorCode::IO(e.into()), None))?;
+    Ok(())
+}
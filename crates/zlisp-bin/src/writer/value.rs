@@ -0,0 +1,40 @@
+use super::config::WriterConfig;
+use super::io_writer::IoWriter;
+use crate::error::Result;
+use crate::reader::ListLength;
+use std::io::Write;
+use zlisp_value::Value;
+
+fn write_value<W: Write>(writer: &mut IoWriter<W>, value: &Value) -> Result<()> {
+    match value {
+        Value::Int(v) => writer.write_i32(*v),
+        Value::Float(v) => writer.write_f32(*v),
+        Value::String(v) => writer.write_str(v),
+        Value::List(items) => {
+            writer.write_list(Some(items.len()))?;
+            items.iter().try_for_each(|item| write_value(writer, item))
+        }
+    }
+}
+
+/// Serialize a [`Value`] to binary zlisp data.
+///
+/// The output is identical to [`to_vec`](crate::to_vec), but the value tree
+/// is written directly instead of going through serde.
+pub fn value_to_vec(value: &Value) -> Result<Vec<u8>> {
+    let mut writer = IoWriter::new(std::io::Cursor::new(Vec::new()), WriterConfig::default());
+    writer.wrap_outer_list()?;
+    write_value(&mut writer, value)?;
+    let cursor = writer.finish()?;
+    Ok(cursor.into_inner())
+}
+
+/// Serialize a [`Value`] to binary zlisp data without an outer list, using
+/// the list length convention.
+pub(crate) fn value_to_vec_raw(value: &Value, list_length: ListLength) -> Result<Vec<u8>> {
+    let mut writer = IoWriter::new(std::io::Cursor::new(Vec::new()), WriterConfig::default());
+    writer.list_length = list_length;
+    write_value(&mut writer, value)?;
+    let cursor = writer.finish()?;
+    Ok(cursor.into_inner())
+}
@@ -54,8 +54,11 @@ impl BinBuilder {
         self
     }
 
-    pub fn str(mut self, s: &str) -> Self {
-        let v = s.as_bytes();
+    pub fn str(self, s: &str) -> Self {
+        self.bytes(s.as_bytes())
+    }
+
+    pub fn bytes(mut self, v: &[u8]) -> Self {
         let len = v.len().try_into().unwrap();
         self.push_i32(STRING);
         self.push_i32(len);
@@ -0,0 +1,80 @@
+use super::bin_builder::BinBuilder;
+use assert_matches::assert_matches;
+use serde_derive::{Deserialize, Serialize};
+use zlisp_bin::{events, from_reader, from_slice, to_vec, Bytes, ErrorCode, RawDocument};
+use zlisp_value::Value;
+
+#[test]
+fn round_trip_tests() {
+    let all: Vec<u8> = (0..=255).collect();
+    let cases: [&[u8]; 5] = [b"", b"\x00", b"\xFF", b"a\"b\x00\xFF", &all[..255]];
+    for case in cases {
+        let value = Bytes::from(case);
+        let data = to_vec(&value).unwrap();
+        assert_eq!(data, BinBuilder::root().bytes(case).build());
+
+        let actual: Bytes = from_slice(&data).unwrap();
+        assert_eq!(actual, value);
+        let actual: Bytes = from_reader(&data[..]).unwrap();
+        assert_eq!(actual, value);
+    }
+}
+
+#[test]
+fn struct_tests() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Payload {
+        name: String,
+        data: Bytes,
+    }
+
+    let value = Payload {
+        name: "payload".to_owned(),
+        data: Bytes::new(vec![0x1f, 0x8b, 0x00, 0xff]),
+    };
+    let data = to_vec(&value).unwrap();
+    let actual: Payload = from_slice(&data).unwrap();
+    assert_eq!(actual, value);
+}
+
+#[test]
+fn too_long_tests() {
+    let value = Bytes::new(vec![0; 256]);
+    let err = to_vec(&value).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::StringTooLong { len: 256, max: 255 });
+}
+
+#[test]
+fn not_a_string_tests() {
+    // arbitrary bytes can't be read as a string
+    let data = to_vec(&Bytes::from(&b"\xFF"[..])).unwrap();
+    let err = from_slice::<String>(&data).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::StringContainsInvalidByte);
+    assert_eq!(err.offset(), Some(16));
+
+    let data = BinBuilder::root().int(0).build();
+    let err = from_slice::<Bytes>(&data).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ExpectedToken { .. });
+}
+
+#[test]
+fn untyped_tests() {
+    // readers that don't know the type read bytes as strings
+    let bin = to_vec(&(1, Bytes::new(vec![b'a', 0xff]))).unwrap();
+    let offset = Some(bin.len() - 1);
+
+    let err = from_slice::<Value>(&bin).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::StringContainsInvalidByte);
+    assert_eq!(err.offset(), offset);
+    let err = events(&bin).find_map(Result::err).unwrap();
+    assert_matches!(err.code(), ErrorCode::StringContainsInvalidByte);
+    assert_eq!(err.offset(), offset);
+    let err = RawDocument::from_slice(&bin).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::StringContainsInvalidByte);
+    assert_eq!(err.offset(), offset);
+
+    // unless the bytes are a valid string
+    let bin = to_vec(&Bytes::new(b"ab".to_vec())).unwrap();
+    let v: Value = from_slice(&bin).unwrap();
+    assert_eq!(v, Value::String("ab".to_owned()));
+}
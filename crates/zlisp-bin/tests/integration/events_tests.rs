@@ -0,0 +1,79 @@
+use super::bin_builder::BinBuilder;
+use assert_matches::assert_matches;
+use zlisp_bin::{events, events_with_config, ErrorCode, Event, ReaderConfig, Result, TokenType};
+
+#[test]
+fn events_tests() {
+    let input = BinBuilder::root()
+        .list(4)
+        .int(1)
+        .float(2.0)
+        .str("foo")
+        .list(0)
+        .build();
+    let v = events(&input).collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(
+        v,
+        vec![
+            Event::ListStart(4),
+            Event::Int(1),
+            Event::Float(2.0),
+            Event::Str("foo"),
+            Event::ListStart(0),
+            Event::ListEnd,
+            Event::ListEnd,
+        ]
+    );
+
+    let input = BinBuilder::root().int(1).build();
+    let v = events(&input).collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(v, vec![Event::Int(1)]);
+
+    let config = ReaderConfig::builder().outer_list(false).build();
+    let input = BinBuilder::empty().list(1).int(1).build();
+    let v = events_with_config(&input, &config)
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(v, vec![Event::ListStart(1), Event::Int(1), Event::ListEnd]);
+}
+
+#[test]
+fn events_aggregate_tests() {
+    let mut builder = BinBuilder::root().list(100);
+    for i in 0..100 {
+        builder = builder.list(1).int(i);
+    }
+    let input = builder.build();
+    let mut sum = 0;
+    for event in events(&input) {
+        if let Event::Int(v) = event.unwrap() {
+            sum += v;
+        }
+    }
+    assert_eq!(sum, 4950);
+}
+
+#[test]
+fn events_error_tests() {
+    let input = BinBuilder::root().list(2).int(1).build();
+    let mut iter = events(&input);
+    assert_eq!(iter.next().unwrap().unwrap(), Event::ListStart(2));
+    assert_eq!(iter.next().unwrap().unwrap(), Event::Int(1));
+    let err = iter.next().unwrap().unwrap_err();
+    assert_matches!(
+        err.code(),
+        ErrorCode::UnexpectedEof {
+            expected: TokenType::Any
+        }
+    );
+    assert_eq!(err.offset(), Some(24));
+    // the iterator is exhausted after an error
+    assert!(iter.next().is_none());
+
+    let input = BinBuilder::root().int(1).int(2).build();
+    let mut iter = events(&input);
+    assert_eq!(iter.next().unwrap().unwrap(), Event::Int(1));
+    let err = iter.next().unwrap().unwrap_err();
+    assert_matches!(err.code(), ErrorCode::TrailingData);
+    assert!(iter.next().is_none());
+}
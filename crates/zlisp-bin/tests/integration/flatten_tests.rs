@@ -0,0 +1,40 @@
+use super::bin_builder::BinBuilder;
+use serde_derive::Deserialize;
+use std::collections::HashMap;
+use zlisp_bin::from_slice;
+use zlisp_value::Value;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Known {
+    a: i32,
+    o: Option<i32>,
+    #[serde(flatten)]
+    extra: HashMap<String, Value>,
+}
+
+#[test]
+fn flatten_extra_tests() {
+    let input = BinBuilder::root()
+        .list(8)
+        .str("b")
+        .str("foo")
+        .str("a")
+        .int(1)
+        .str("c")
+        .list(2)
+        .int(1)
+        .float(2.5)
+        .str("o")
+        .list(1)
+        .int(2)
+        .build();
+    let v: Known = from_slice(&input).unwrap();
+    assert_eq!(v.a, 1);
+    assert_eq!(v.o, Some(2));
+    assert_eq!(v.extra.len(), 2);
+    assert_eq!(v.extra["b"], Value::String("foo".to_owned()));
+    assert_eq!(
+        v.extra["c"],
+        Value::List(vec![Value::Int(1), Value::Float(2.5)])
+    );
+}
@@ -0,0 +1,183 @@
+use super::bin_builder::BinBuilder;
+use assert_matches::assert_matches;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashSet;
+use std::io::{self, Read};
+use zlisp_bin::{
+    from_reader, from_reader_with_config, from_slice, from_slice_with_config, to_vec, ErrorCode,
+    ReaderConfig, TokenType,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Variant {
+    Unit,
+    NewType(i32),
+    Tuple(i32, f32),
+    Struct { a: String },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Test {
+    name: String,
+    value: f32,
+    list: Vec<Option<i32>>,
+    variants: Vec<Variant>,
+}
+
+fn test_value() -> Test {
+    Test {
+        name: "foo".to_owned(),
+        value: 1.5,
+        list: vec![Some(1), None],
+        variants: vec![
+            Variant::Unit,
+            Variant::NewType(-1),
+            Variant::Tuple(2, 3.0),
+            Variant::Struct {
+                a: "bar".to_owned(),
+            },
+        ],
+    }
+}
+
+/// A reader that returns at most one byte per read.
+struct OneByte<'a>(&'a [u8]);
+
+impl<'a> Read for OneByte<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = buf.len().min(self.0.len()).min(1);
+        buf[..n].copy_from_slice(&self.0[..n]);
+        self.0 = &self.0[n..];
+        Ok(n)
+    }
+}
+
+/// A reader that fails after some bytes.
+struct Failing<'a>(&'a [u8]);
+
+impl<'a> Read for Failing<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.0.is_empty() {
+            return Err(io::Error::other("failed"));
+        }
+        self.0.read(buf)
+    }
+}
+
+#[test]
+fn round_trip_tests() {
+    let expected = test_value();
+    let bin = to_vec(&expected).unwrap();
+    let actual: Test = from_reader(bin.as_slice()).unwrap();
+    assert_eq!(actual, expected);
+    let actual: Test = from_reader(OneByte(&bin)).unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn truncated_tests() {
+    let bin = to_vec(&test_value()).unwrap();
+    // errors are the same as when reading from a slice
+    for len in 0..bin.len() {
+        let expected = from_slice::<Test>(&bin[..len]).unwrap_err();
+        let actual = from_reader::<_, Test>(OneByte(&bin[..len])).unwrap_err();
+        assert_eq!(actual.to_string(), expected.to_string(), "len: {}", len);
+    }
+}
+
+#[test]
+fn insufficient_data_tests() {
+    let bin = BinBuilder::root().str("foo").build();
+    let err = from_reader::<_, String>(&bin[..bin.len() - 1]).unwrap_err();
+    assert_matches!(
+        err.code(),
+        ErrorCode::InsufficientData {
+            expected: 3,
+            available: 2
+        }
+    );
+    assert_eq!(err.offset(), Some(16));
+}
+
+#[test]
+fn unexpected_eof_tests() {
+    let bin = BinBuilder::root().build();
+    let err = from_reader::<_, i32>(bin.as_slice()).unwrap_err();
+    assert_matches!(
+        err.code(),
+        ErrorCode::UnexpectedEof {
+            expected: TokenType::Int
+        }
+    );
+    assert_eq!(err.offset(), Some(8));
+}
+
+#[test]
+fn trailing_data_tests() {
+    let bin = BinBuilder::root().int(0).int(1).build();
+    let err = from_reader::<_, i32>(bin.as_slice()).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::TrailingData);
+    assert_eq!(err.offset(), Some(16));
+}
+
+#[test]
+fn io_error_tests() {
+    let bin = BinBuilder::root().int(0).build();
+    let err = from_reader::<_, i32>(Failing(&bin[..10])).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::IO(_));
+    assert_eq!(err.offset(), Some(8));
+}
+
+#[test]
+fn outer_list_tests() {
+    let config = ReaderConfig::builder().outer_list(false).build();
+    let bin = BinBuilder::empty().int(1).build();
+    let v: i32 = from_reader_with_config(OneByte(&bin), &config).unwrap();
+    assert_eq!(v, 1);
+}
+
+#[test]
+fn infer_maps_tests() {
+    let config = ReaderConfig::builder().infer_maps(true).build();
+    let bin = BinBuilder::root()
+        .list(4)
+        .str("a")
+        .list(2)
+        .str("b")
+        .int(1)
+        .str("c")
+        .list(3)
+        .str("d")
+        .int(2)
+        .int(3)
+        .build();
+    let v: serde_json::Value = from_reader_with_config(OneByte(&bin), &config).unwrap();
+    assert_eq!(v, json!({ "a": { "b": 1 }, "c": ["d", 2, 3] }));
+    let expected: serde_json::Value = from_slice_with_config(&bin, &config).unwrap();
+    assert_eq!(v, expected);
+}
+
+#[test]
+fn deny_duplicates_tests() {
+    let config = ReaderConfig::builder().deny_duplicates(true).build();
+    let bin = BinBuilder::root()
+        .list(3)
+        .list(1)
+        .str("a")
+        .list(1)
+        .str("b")
+        .list(1)
+        .str("a")
+        .build();
+    let err =
+        from_reader_with_config::<_, HashSet<Vec<String>>>(OneByte(&bin), &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::DuplicateElement);
+    assert_eq!(err.offset(), Some(50));
+    let expected = from_slice_with_config::<HashSet<Vec<String>>>(&bin, &config).unwrap_err();
+    assert_eq!(err.to_string(), expected.to_string());
+
+    let bin = BinBuilder::root().list(2).int(1).int(2).build();
+    let v: HashSet<i32> = from_reader_with_config(OneByte(&bin), &config).unwrap();
+    assert_eq!(v, HashSet::from([1, 2]));
+}
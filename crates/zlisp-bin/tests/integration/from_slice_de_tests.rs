@@ -77,11 +77,16 @@ fn string_tests() {
     let input = BinBuilder::root().str("foo").build();
     assert_ok!(&str, &input, "foo");
     assert_ok!(String, &input, "foo");
+
+    let input = BinBuilder::root().str("").build();
+    assert_ok!(&str, &input, "");
+    assert_ok!(String, &input, "");
 }
 
 #[test]
 fn bytes_tests() {
-    assert_unsupported!(&[u8]);
+    let input = BinBuilder::root().bytes(b"\x00\xFF").build();
+    assert_ok!(&[u8], &input, b"\x00\xFF");
 }
 
 #[test]
@@ -169,7 +174,7 @@ fn seq_tests() {
     type Value = Vec<i32>;
 
     let input = BinBuilder::root().list(0).build();
-    assert_ok!(Value, &input, vec![]);
+    assert_ok!(Value, &input, Value::new());
     let input = BinBuilder::root().list(1).int(-1).build();
     assert_ok!(Value, &input, vec![-1]);
     let input = BinBuilder::root().list(2).int(-1).int(-2).build();
@@ -489,3 +494,20 @@ fn enum_struct_variant_optional_tests() {
     let input = BinBuilder::root().str("V").list(3).str("a").int(-1).build();
     assert_err!(Value, &input, 42, ErrorCode::ExpectedKeyValuePair);
 }
+
+#[test]
+fn error_eq_tests() {
+    let bin = BinBuilder::root().float(1.0).build();
+    let errors: Vec<zlisp_bin::Error> = (0..2)
+        .map(|_| from_slice::<i32>(&bin).unwrap_err())
+        .collect();
+    assert_eq!(errors[0], errors[1]);
+    assert_eq!(errors[0].clone(), errors[0]);
+    assert_eq!(errors[0].code().clone(), *errors[1].code());
+
+    // errors at different offsets are different
+    let bin = BinBuilder::root().list(1).float(1.0).build();
+    let err = from_slice::<(i32,)>(&bin).unwrap_err();
+    assert_eq!(err.code(), errors[0].code());
+    assert_ne!(err, errors[0]);
+}
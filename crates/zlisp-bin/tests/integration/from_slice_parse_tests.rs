@@ -1,7 +1,8 @@
 use super::any::Any;
 use super::bin_builder::{BinBuilder, FLOAT, INT, INVALID_TYPE, LIST, MAX_LIST_LEN, STRING};
 use assert_matches::assert_matches;
-use zlisp_bin::{from_slice, ErrorCode, TokenType};
+use zlisp_bin::{events, from_slice, ErrorCode, RawDocument, TokenType};
+use zlisp_value::Value;
 
 macro_rules! assert_err {
     ($type:ty, $input:expr, $offset:expr, $code:pat) => {
@@ -24,11 +25,15 @@ fn must_have_outer_list() {
         i32,
         &[],
         0,
-        ErrorCode::ExpectedToken {
+        ErrorCode::UnexpectedEof {
             expected: TokenType::List,
-            found: TokenType::Eof,
         }
     );
+    let err = from_slice::<i32>(&[]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected list, found end of file (at offset: 0)"
+    );
 
     let input = BinBuilder::empty().i32(INT).build();
     assert_err!(
@@ -109,9 +114,8 @@ fn parse_int() {
         i32,
         &input,
         8,
-        ErrorCode::ExpectedToken {
+        ErrorCode::UnexpectedEof {
             expected: TokenType::Int,
-            found: TokenType::Eof,
         }
     );
 
@@ -199,9 +203,8 @@ fn parse_float() {
         f32,
         &input,
         8,
-        ErrorCode::ExpectedToken {
+        ErrorCode::UnexpectedEof {
             expected: TokenType::Float,
-            found: TokenType::Eof,
         }
     );
 
@@ -283,9 +286,8 @@ fn parse_str() {
         &str,
         &input,
         8,
-        ErrorCode::ExpectedToken {
+        ErrorCode::UnexpectedEof {
             expected: TokenType::String,
-            found: TokenType::Eof,
         }
     );
 
@@ -404,6 +406,11 @@ fn parse_str() {
     assert_err!(&str, &input, 12, ErrorCode::InvalidStringLength);
     let input = BinBuilder::root().i32(STRING).i32(i32::MIN).build();
     assert_err!(&str, &input, 12, ErrorCode::InvalidStringLength);
+    let err = from_slice::<&str>(&input).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid string length (negative) (at offset: 12)"
+    );
 
     let input = BinBuilder::root().i32(STRING).i32(1).build();
     assert_err!(
@@ -452,13 +459,18 @@ fn parse_str_content() {
 
     let over_len = " ".repeat(256);
     let input = BinBuilder::root().str(&over_len).build();
-    assert_err!(&str, &input, 12, ErrorCode::StringTooLong);
+    assert_err!(
+        &str,
+        &input,
+        12,
+        ErrorCode::StringTooLong { len: 256, max: 255 }
+    );
 }
 
 #[test]
 fn parse_list() {
     let input = BinBuilder::root().list(0).build();
-    assert_ok!(Vec<i32>, &input, &[]);
+    assert_ok!(Vec<i32>, &input, &[] as &[i32]);
     let input = BinBuilder::root().list(1).int(2).build();
     assert_ok!(Vec<i32>, &input, &[2]);
 
@@ -467,9 +479,8 @@ fn parse_list() {
         Vec<i32>,
         &input,
         8,
-        ErrorCode::ExpectedToken {
+        ErrorCode::UnexpectedEof {
             expected: TokenType::List,
-            found: TokenType::Eof,
         }
     );
 
@@ -580,7 +591,7 @@ fn parse_list() {
     );
 
     let input = BinBuilder::root().i32(LIST).i32(1).build();
-    assert_ok!(Vec<i32>, &input, &[]);
+    assert_ok!(Vec<i32>, &input, &[] as &[i32]);
     let mut builder = BinBuilder::root().i32(LIST).i32(MAX_LIST_LEN + 1);
     let mut expected = Vec::with_capacity(MAX_LIST_LEN as usize);
     for i in 0..MAX_LIST_LEN {
@@ -621,9 +632,8 @@ fn parse_any() {
         Any,
         &input,
         8,
-        ErrorCode::ExpectedToken {
+        ErrorCode::UnexpectedEof {
             expected: TokenType::Any,
-            found: TokenType::Eof,
         }
     );
 
@@ -661,3 +671,25 @@ fn parse_any() {
     let input = BinBuilder::root().i32(INVALID_TYPE).build();
     assert_err!(Any, &input, 8, ErrorCode::InvalidTokenType);
 }
+
+#[test]
+fn truncated_tests() {
+    let input = BinBuilder::root()
+        .list(3)
+        .int(1)
+        .float(2.0)
+        .list(2)
+        .str("foo")
+        .list(0)
+        .build();
+    from_slice::<Value>(&input).unwrap();
+
+    // truncating the input anywhere is an error, never a panic
+    for len in 0..input.len() {
+        let truncated = &input[..len];
+        let err = from_slice::<Value>(truncated).unwrap_err();
+        assert!(err.offset().unwrap() <= len);
+        RawDocument::from_slice(truncated).unwrap_err();
+        assert!(events(truncated).any(|event| event.is_err()));
+    }
+}
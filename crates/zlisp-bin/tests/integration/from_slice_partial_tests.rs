@@ -0,0 +1,52 @@
+use super::bin_builder::BinBuilder;
+use assert_matches::assert_matches;
+use zlisp_bin::{from_slice, from_slice_partial, to_vec, ErrorCode};
+
+#[test]
+fn partial_tests() {
+    let mut bin = to_vec(&1).unwrap();
+    bin.extend(to_vec(&(2, String::from("a"))).unwrap());
+    bin.extend(to_vec(&3.0f32).unwrap());
+
+    let (a, rest): (i32, _) = from_slice_partial(&bin).unwrap();
+    assert_eq!(a, 1);
+    let (b, rest): ((i32, &str), _) = from_slice_partial(rest).unwrap();
+    assert_eq!(b, (2, "a"));
+    let (c, rest): (f32, _) = from_slice_partial(rest).unwrap();
+    assert_eq!(c, 3.0);
+    assert!(rest.is_empty());
+
+    // the whole data is not valid for `from_slice`
+    let err = from_slice::<i32>(&bin).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::TrailingData);
+}
+
+#[test]
+fn partial_loop_tests() {
+    let expected = vec![0, 1, 2, 3];
+    let mut bin = Vec::new();
+    for v in &expected {
+        bin.extend(to_vec(v).unwrap());
+    }
+    let mut actual = Vec::new();
+    let mut rest = bin.as_slice();
+    while !rest.is_empty() {
+        let (v, r): (i32, _) = from_slice_partial(rest).unwrap();
+        actual.push(v);
+        rest = r;
+    }
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn partial_error_tests() {
+    // arbitrary trailing data is returned as-is
+    let mut bin = BinBuilder::root().int(1).build();
+    bin.extend([0xff, 0xff]);
+    let (v, rest): (i32, _) = from_slice_partial(&bin).unwrap();
+    assert_eq!(v, 1);
+    assert_eq!(rest, &[0xff, 0xff]);
+
+    let err = from_slice_partial::<i32>(&[]).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::UnexpectedEof { .. });
+}
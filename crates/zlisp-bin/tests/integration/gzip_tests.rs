@@ -0,0 +1,51 @@
+use super::bin_builder::BinBuilder;
+use assert_matches::assert_matches;
+use zlisp_bin::{from_reader_gz, to_vec, to_writer_gz, ErrorCode};
+
+#[test]
+fn round_trip_tests() {
+    let expected = vec![-1, -2];
+    let mut gz = Vec::new();
+    to_writer_gz(&mut gz, &expected).unwrap();
+    assert_eq!(&gz[..2], &[0x1f, 0x8b]);
+    let actual: Vec<i32> = from_reader_gz(gz.as_slice()).unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn uncompressed_tests() {
+    let expected = vec![-1, -2];
+    let bin = to_vec(&expected).unwrap();
+    let actual: Vec<i32> = from_reader_gz(bin.as_slice()).unwrap();
+    assert_eq!(actual, expected);
+
+    // shorter than the magic
+    let err = from_reader_gz::<_, i32>(&[0x1f][..]).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::InsufficientData { .. });
+}
+
+#[test]
+fn offset_tests() {
+    let bin = BinBuilder::root().int(0).int(1).build();
+    let mut gz = Vec::new();
+    {
+        use std::io::Write as _;
+        let mut encoder = flate2::write::GzEncoder::new(&mut gz, flate2::Compression::default());
+        encoder.write_all(&bin).unwrap();
+        encoder.finish().unwrap();
+    }
+    // the offset is relative to the decompressed data
+    let err = from_reader_gz::<_, i32>(gz.as_slice()).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::TrailingData);
+    assert_eq!(err.offset(), Some(16));
+}
+
+#[test]
+fn streaming_tests() {
+    // the data is not read to the end, so endless trailing data is an error
+    let bin = BinBuilder::root().int(0).build();
+    let reader = std::io::Read::chain(bin.as_slice(), std::io::repeat(0));
+    let err = from_reader_gz::<_, i32>(reader).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::TrailingData);
+    assert_eq!(err.offset(), Some(16));
+}
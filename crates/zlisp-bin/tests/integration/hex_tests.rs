@@ -0,0 +1,56 @@
+use super::bin_builder::BinBuilder;
+use serde_derive::{Deserialize, Serialize};
+use zlisp_bin::{from_slice, to_vec};
+use zlisp_hex::{Hex, HexU32};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct HexStruct {
+    a: Hex,
+    b: i32,
+}
+
+#[test]
+fn hex_round_trip_tests() {
+    let expected = HexStruct {
+        a: 0xdead.try_into().unwrap(),
+        b: -1,
+    };
+    let bin = to_vec(&expected).unwrap();
+    // hex values are not human-readable in the binary format
+    let raw = BinBuilder::root()
+        .list(4)
+        .str("a")
+        .int(0xdead)
+        .str("b")
+        .int(-1)
+        .build();
+    assert_eq!(bin, raw);
+    let actual: HexStruct = from_slice(&bin).unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct HexU32Struct {
+    a: HexU32,
+    b: HexU32,
+}
+
+#[test]
+fn hex_u32_round_trip_tests() {
+    let expected = HexU32Struct {
+        a: HexU32::new(0xFFFFFFFF),
+        b: HexU32::new(0x80000000),
+    };
+    let bin = to_vec(&expected).unwrap();
+    // the bits are reinterpreted as a signed integer
+    let raw = BinBuilder::root()
+        .list(4)
+        .str("a")
+        .int(-1)
+        .str("b")
+        .int(i32::MIN)
+        .build();
+    assert_eq!(bin, raw);
+    let actual: HexU32Struct = from_slice(&bin).unwrap();
+    assert_eq!(actual, expected);
+}
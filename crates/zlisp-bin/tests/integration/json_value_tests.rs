@@ -0,0 +1,33 @@
+use super::bin_builder::BinBuilder;
+use serde_json::{json, Value};
+use zlisp_bin::from_slice;
+
+// lists always become JSON arrays. zlisp has no map type, so structs and maps
+// (which are flat lists of keys and values) also become arrays, not objects.
+
+#[test]
+fn scalar_tests() {
+    let input = BinBuilder::root().int(-1).build();
+    assert_eq!(from_slice::<Value>(&input).unwrap(), json!(-1));
+    let input = BinBuilder::root().float(1.5).build();
+    assert_eq!(from_slice::<Value>(&input).unwrap(), json!(1.5));
+    let input = BinBuilder::root().str("foo").build();
+    assert_eq!(from_slice::<Value>(&input).unwrap(), json!("foo"));
+}
+
+#[test]
+fn list_tests() {
+    let input = BinBuilder::root().list(0).build();
+    assert_eq!(from_slice::<Value>(&input).unwrap(), json!([]));
+    let input = BinBuilder::root()
+        .list(4)
+        .int(1)
+        .float(2.5)
+        .str("foo")
+        .list(2)
+        .str("bar")
+        .list(0)
+        .build();
+    let v: Value = from_slice(&input).unwrap();
+    assert_eq!(v, json!([1, 2.5, "foo", ["bar", []]]));
+}
@@ -0,0 +1,114 @@
+use super::bin_builder::BinBuilder;
+use assert_matches::assert_matches;
+use zlisp_bin::{ErrorCode, Event, Reader, ReaderConfig, Result, TokenType};
+
+#[derive(Debug, PartialEq)]
+enum Shape {
+    Circle(f32),
+    Rect(i32, i32),
+}
+
+/// A format where the structure depends on earlier values, i.e. a list of
+/// shapes, each tagged by a leading int.
+fn read_shapes(input: &[u8]) -> Result<Vec<Shape>> {
+    let mut reader = Reader::new(input)?;
+    let count = reader.read_list()?;
+    let mut shapes = Vec::with_capacity(count);
+    for _ in 0..count {
+        let len = reader.read_list()?;
+        let shape = match (reader.read_i32()?, len) {
+            (0, 2) => Shape::Circle(reader.read_f32()?),
+            (_, _) => Shape::Rect(reader.read_i32()?, reader.read_i32()?),
+        };
+        shapes.push(shape);
+    }
+    reader.finish()?;
+    Ok(shapes)
+}
+
+#[test]
+fn read_tests() {
+    let input = BinBuilder::root()
+        .list(2)
+        .list(2)
+        .int(0)
+        .float(1.5)
+        .list(3)
+        .int(1)
+        .int(2)
+        .int(3)
+        .build();
+    let shapes = read_shapes(&input).unwrap();
+    assert_eq!(shapes, vec![Shape::Circle(1.5), Shape::Rect(2, 3)]);
+
+    let input = BinBuilder::root().list(0).build();
+    let shapes = read_shapes(&input).unwrap();
+    assert!(shapes.is_empty());
+}
+
+#[test]
+fn read_error_tests() {
+    // wrong type for the tag
+    let input = BinBuilder::root()
+        .list(1)
+        .list(2)
+        .float(0.0)
+        .float(1.5)
+        .build();
+    let err = read_shapes(&input).unwrap_err();
+    assert_matches!(
+        err.code(),
+        ErrorCode::ExpectedToken {
+            expected: TokenType::Int,
+            found: TokenType::Float,
+        }
+    );
+    assert_eq!(err.offset(), Some(24));
+
+    // trailing data
+    let input = BinBuilder::root().list(0).int(1).build();
+    let err = read_shapes(&input).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::TrailingData);
+    assert_eq!(err.offset(), Some(16));
+
+    // missing outer list
+    let input = BinBuilder::empty().int(1).build();
+    let err = Reader::new(&input).unwrap_err();
+    assert_matches!(
+        err.code(),
+        ErrorCode::ExpectedToken {
+            expected: TokenType::List,
+            found: TokenType::Int,
+        }
+    );
+}
+
+#[test]
+fn read_any_tests() {
+    let input = BinBuilder::root()
+        .list(4)
+        .int(1)
+        .float(2.0)
+        .str("foo")
+        .list(0)
+        .build();
+    let mut reader = Reader::new(&input).unwrap();
+    assert_eq!(reader.offset(), 8);
+    assert_eq!(reader.read_any().unwrap(), Event::ListStart(4));
+    assert_eq!(reader.read_any().unwrap(), Event::Int(1));
+    assert_eq!(reader.read_any().unwrap(), Event::Float(2.0));
+    assert_eq!(reader.read_any().unwrap(), Event::Str("foo"));
+    assert_eq!(reader.read_any().unwrap(), Event::ListStart(0));
+    assert_eq!(reader.offset(), input.len());
+    reader.finish().unwrap();
+}
+
+#[test]
+fn read_without_outer_list_tests() {
+    let config = ReaderConfig::builder().outer_list(false).build();
+    let input = BinBuilder::empty().str("foo").build();
+    let mut reader = Reader::with_config(&input, &config).unwrap();
+    assert_eq!(reader.offset(), 0);
+    assert_eq!(reader.read_str().unwrap(), "foo");
+    reader.finish().unwrap();
+}
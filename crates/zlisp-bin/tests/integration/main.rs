@@ -1,9 +1,26 @@
 mod any;
 mod bin_builder;
+mod bytes_tests;
+mod events_tests;
+mod flatten_tests;
+mod from_reader_tests;
 mod from_slice_de_tests;
 mod from_slice_parse_tests;
+mod from_slice_partial_tests;
+#[cfg(feature = "flate2")]
+mod gzip_tests;
+mod hex_tests;
+mod json_value_tests;
+mod low_level_reader_tests;
+mod outer_list_tests;
+mod raw_tests;
+mod reader_config_tests;
+mod recursion_limit_tests;
 mod round_trip_tests;
 mod to_vec_ser_tests;
+mod unit_variant_index_tests;
+mod value_tests;
+mod versioned_tests;
 
 #[macro_export]
 macro_rules! map {
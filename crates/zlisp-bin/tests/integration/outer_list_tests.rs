@@ -0,0 +1,70 @@
+use super::bin_builder::BinBuilder;
+use assert_matches::assert_matches;
+use zlisp_bin::{
+    from_slice, from_slice_raw, from_slice_with_config, to_vec, to_vec_raw, to_vec_with_config,
+    to_writer_with_config, ErrorCode, ReaderConfig, WriterConfig,
+};
+
+#[test]
+fn raw_tests() {
+    let v: Vec<i32> = vec![1, 2];
+    let expected = BinBuilder::empty().list(2).int(1).int(2).build();
+    let bin = to_vec_raw(&v).unwrap();
+    assert_eq!(bin, expected);
+    let actual: Vec<i32> = from_slice_raw(&bin).unwrap();
+    assert_eq!(actual, v);
+
+    let bin = to_vec(&v).unwrap();
+    assert_eq!(bin.len(), expected.len() + 8);
+    assert_eq!(&bin[8..], &expected[..]);
+}
+
+#[test]
+fn config_tests() {
+    assert!(WriterConfig::default().outer_list());
+    assert!(ReaderConfig::default().outer_list());
+
+    let v: i32 = 42;
+    let bin = to_vec_with_config(&v, WriterConfig::default()).unwrap();
+    assert_eq!(bin, to_vec(&v).unwrap());
+
+    let writer = WriterConfig::builder().outer_list(false).build();
+    let reader = ReaderConfig::builder().outer_list(false).build();
+    let bin = to_vec_with_config(&v, &writer).unwrap();
+    assert_eq!(bin, BinBuilder::empty().int(42).build());
+    let actual: i32 = from_slice_with_config(&bin, &reader).unwrap();
+    assert_eq!(actual, v);
+
+    let mut buf = Vec::new();
+    to_writer_with_config(&mut buf, &v, &writer).unwrap();
+    assert_eq!(buf, bin);
+}
+
+#[test]
+fn mismatch_tests() {
+    let v: i32 = 42;
+    let raw = to_vec_raw(&v).unwrap();
+    let err = from_slice::<i32>(&raw).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ExpectedToken { .. });
+
+    let wrapped = to_vec(&v).unwrap();
+    let err = from_slice_raw::<i32>(&wrapped).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ExpectedToken { .. });
+}
+
+#[test]
+fn wrapped_scalar_bytes_tests() {
+    let bin = to_vec(&1i32).unwrap();
+    #[rustfmt::skip]
+    let expected: Vec<u8> = vec![
+        // outer list, with a length of 1 (plus 1)
+        4, 0, 0, 0, 2, 0, 0, 0,
+        // int, with a value of 1
+        1, 0, 0, 0, 1, 0, 0, 0,
+    ];
+    assert_eq!(bin, expected);
+    assert_eq!(bin, BinBuilder::root().int(1).build());
+
+    let raw = to_vec_raw(&1i32).unwrap();
+    assert_eq!(raw, &expected[8..]);
+}
@@ -0,0 +1,136 @@
+use super::bin_builder::{BinBuilder, LIST};
+use assert_matches::assert_matches;
+use zlisp_bin::{ErrorCode, ListLength, RawDocument, RawKind, ReaderConfig};
+use zlisp_value::Value;
+
+#[test]
+fn raw_unmodified_tests() {
+    // a non-canonical NaN is preserved
+    let nan = f32::from_bits(0x7fc0_1234);
+    let input = BinBuilder::root()
+        .list(4)
+        .str("a")
+        .int(1)
+        .str("b")
+        .float(nan)
+        .build();
+    let doc = RawDocument::from_slice(&input).unwrap();
+    assert_eq!(doc.to_vec().unwrap(), input);
+    assert_eq!(doc.root().raw(), &input[8..]);
+    assert_eq!(doc.root().children().unwrap().len(), 4);
+}
+
+#[test]
+fn raw_patch_tests() {
+    let input = BinBuilder::root()
+        .list(4)
+        .str("a")
+        .int(1)
+        .str("b")
+        .list(2)
+        .int(2)
+        .int(3)
+        .build();
+    let mut doc = RawDocument::from_slice(&input).unwrap();
+    let node = doc.root_mut().field_mut("a").unwrap();
+    assert_eq!(node.kind(), &RawKind::Int(1));
+    node.set(Value::Int(0x1234_5678));
+    assert_eq!(node.replacement(), Some(&Value::Int(0x1234_5678)));
+
+    let output = doc.to_vec().unwrap();
+    assert_eq!(output.len(), input.len());
+    let changed: Vec<usize> = (0..input.len())
+        .filter(|&i| input[i] != output[i])
+        .collect();
+    assert_eq!(changed, vec![29, 30, 31, 32]);
+
+    // nested nodes can be replaced
+    let b = doc.root_mut().field_mut("b").unwrap();
+    b.get_mut(1).unwrap().set(Value::Int(4));
+    let expected = BinBuilder::root()
+        .list(4)
+        .str("a")
+        .int(0x1234_5678)
+        .str("b")
+        .list(2)
+        .int(2)
+        .int(4)
+        .build();
+    assert_eq!(doc.to_vec().unwrap(), expected);
+
+    assert!(doc.root_mut().field_mut("c").is_none());
+    assert!(doc.root_mut().get_mut(4).is_none());
+
+    // the elements of a replaced list can't be edited, since the edits would
+    // not be written
+    let b = doc.root_mut().field_mut("b").unwrap();
+    b.set(Value::List(vec![Value::Int(5)]));
+    assert!(b.get_mut(0).is_none());
+    assert!(doc.root_mut().field_mut("b").unwrap().get_mut(1).is_none());
+    doc.root_mut().set(Value::List(Vec::new()));
+    assert!(doc.root_mut().field_mut("a").is_none());
+    // the original elements can still be inspected
+    assert_eq!(doc.root().children().unwrap().len(), 4);
+    let expected = BinBuilder::root().list(0).build();
+    assert_eq!(doc.to_vec().unwrap(), expected);
+}
+
+#[test]
+fn raw_list_length_tests() {
+    // list lengths from other producers are preserved
+    let config = ReaderConfig::builder()
+        .list_length(ListLength::ExactCount)
+        .build();
+    let input = BinBuilder::empty()
+        .i32(LIST)
+        .i32(1)
+        .i32(LIST)
+        .i32(2)
+        .int(1)
+        .int(2)
+        .build();
+    let mut doc = RawDocument::from_slice_with_config(&input, &config).unwrap();
+    doc.root_mut().get_mut(0).unwrap().set(Value::Int(3));
+    let expected = BinBuilder::empty()
+        .i32(LIST)
+        .i32(1)
+        .i32(LIST)
+        .i32(2)
+        .int(3)
+        .int(2)
+        .build();
+    assert_eq!(doc.to_vec().unwrap(), expected);
+
+    // replacements are written with the same convention
+    doc.root_mut()
+        .get_mut(1)
+        .unwrap()
+        .set(Value::List(vec![Value::Int(7)]));
+    let expected = BinBuilder::empty()
+        .i32(LIST)
+        .i32(1)
+        .i32(LIST)
+        .i32(2)
+        .int(3)
+        .i32(LIST)
+        .i32(1)
+        .int(7)
+        .build();
+    let output = doc.to_vec().unwrap();
+    assert_eq!(output, expected);
+    let doc = RawDocument::from_slice_with_config(&output, &config).unwrap();
+    assert_eq!(doc.to_vec().unwrap(), expected);
+}
+
+#[test]
+fn raw_error_tests() {
+    let input = BinBuilder::root().int(1).int(2).build();
+    let err = RawDocument::from_slice(&input).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::TrailingData);
+
+    let input = BinBuilder::root().int(1).build();
+    let mut doc = RawDocument::from_slice(&input).unwrap();
+    doc.root_mut().set(Value::from("\""));
+    let err = doc.to_vec().unwrap_err();
+    assert_matches!(err.code(), ErrorCode::StringContainsQuote);
+}
@@ -0,0 +1,182 @@
+use super::bin_builder::BinBuilder;
+use super::bin_builder::LIST;
+use assert_matches::assert_matches;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use zlisp_bin::{from_slice_with_config, ErrorCode, ListLength, ReaderConfig};
+
+#[test]
+fn max_string_len_tests() {
+    let config = ReaderConfig::builder().max_string_len(3).build();
+    assert_eq!(config.max_string_len(), 3);
+
+    let input = BinBuilder::root().str("abc").build();
+    let v: String = from_slice_with_config(&input, &config).unwrap();
+    assert_eq!(v, "abc");
+
+    let input = BinBuilder::root().str("abcd").build();
+    let err = from_slice_with_config::<String>(&input, &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::StringTooLong { len: 4, max: 3 });
+    assert_eq!(err.offset(), Some(12));
+
+    // the limit applies to map keys as well
+    let input = BinBuilder::root().list(2).str("abcd").int(1).build();
+    let err = from_slice_with_config::<HashMap<String, i32>>(&input, &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::StringTooLong { len: 4, max: 3 });
+}
+
+#[test]
+fn max_string_len_clamped_tests() {
+    assert_eq!(ReaderConfig::default().max_string_len(), 255);
+    let config = ReaderConfig::builder().max_string_len(1000).build();
+    assert_eq!(config.max_string_len(), 255);
+
+    let input = BinBuilder::root().str(&" ".repeat(256)).build();
+    let err = from_slice_with_config::<String>(&input, &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::StringTooLong { len: 256, max: 255 });
+}
+
+#[test]
+fn deny_duplicates_tests() {
+    assert!(!ReaderConfig::default().deny_duplicates());
+    let config = ReaderConfig::builder().deny_duplicates(true).build();
+
+    let input = BinBuilder::root().list(3).int(1).int(2).int(1).build();
+    let v: HashSet<i32> = from_slice_with_config(&input, ReaderConfig::default()).unwrap();
+    assert_eq!(v.len(), 2);
+
+    let err = from_slice_with_config::<HashSet<i32>>(&input, &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::DuplicateElement);
+    assert_eq!(err.offset(), Some(32));
+    let err = from_slice_with_config::<Vec<i32>>(&input, &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::DuplicateElement);
+
+    let input = BinBuilder::root().list(3).int(1).int(2).int(3).build();
+    let v: BTreeSet<i32> = from_slice_with_config(&input, &config).unwrap();
+    assert_eq!(v.len(), 3);
+
+    // nested elements are compared by value
+    let input = BinBuilder::root()
+        .list(2)
+        .list(1)
+        .str("a")
+        .list(1)
+        .str("a")
+        .build();
+    let err = from_slice_with_config::<HashSet<Vec<String>>>(&input, &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::DuplicateElement);
+}
+
+#[test]
+fn list_length_exact_count_tests() {
+    assert_eq!(
+        ReaderConfig::default().list_length(),
+        ListLength::ZipperPlusOne
+    );
+    let config = ReaderConfig::builder()
+        .list_length(ListLength::ExactCount)
+        .build();
+
+    // the outer list uses the same convention
+    let input = BinBuilder::empty()
+        .i32(LIST)
+        .i32(1)
+        .i32(LIST)
+        .i32(2)
+        .int(1)
+        .int(2)
+        .build();
+    let v: Vec<i32> = from_slice_with_config(&input, &config).unwrap();
+    assert_eq!(v, vec![1, 2]);
+
+    let input = BinBuilder::empty()
+        .i32(LIST)
+        .i32(1)
+        .i32(LIST)
+        .i32(0)
+        .build();
+    let v: Vec<i32> = from_slice_with_config(&input, &config).unwrap();
+    assert!(v.is_empty());
+
+    // zipper data is misread with the wrong convention
+    let input = BinBuilder::root().list(1).int(1).build();
+    let err = from_slice_with_config::<Vec<i32>>(&input, &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::InvalidListLength);
+    assert_eq!(err.offset(), Some(4));
+
+    let input = BinBuilder::empty()
+        .i32(LIST)
+        .i32(1)
+        .i32(LIST)
+        .i32(-1)
+        .build();
+    let err = from_slice_with_config::<Vec<i32>>(&input, &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::InvalidListLength);
+    assert_eq!(err.offset(), Some(12));
+}
+
+#[test]
+fn infer_maps_tests() {
+    use serde_derive::Deserialize;
+    use serde_json::json;
+
+    let config = ReaderConfig::builder().infer_maps(true).build();
+    assert!(config.infer_maps());
+
+    let input = BinBuilder::root()
+        .list(4)
+        .str("a")
+        .int(1)
+        .str("b")
+        .int(2)
+        .build();
+    let v: serde_json::Value = from_slice_with_config(&input, &config).unwrap();
+    assert_eq!(v, json!({ "a": 1, "b": 2 }));
+
+    // values may be nested lists
+    let input = BinBuilder::root()
+        .list(4)
+        .str("a")
+        .list(2)
+        .str("b")
+        .list(2)
+        .int(1)
+        .int(2)
+        .str("c")
+        .list(0)
+        .build();
+    let v: serde_json::Value = from_slice_with_config(&input, &config).unwrap();
+    assert_eq!(v, json!({ "a": { "b": [1, 2] }, "c": [] }));
+
+    // lists that can't be maps are still sequences
+    let input = BinBuilder::root().list(0).build();
+    let v: serde_json::Value = from_slice_with_config(&input, &config).unwrap();
+    assert_eq!(v, json!([]));
+    let input = BinBuilder::root().list(3).str("a").int(1).str("b").build();
+    let v: serde_json::Value = from_slice_with_config(&input, &config).unwrap();
+    assert_eq!(v, json!(["a", 1, "b"]));
+    let input = BinBuilder::root().list(2).int(1).str("a").build();
+    let v: serde_json::Value = from_slice_with_config(&input, &config).unwrap();
+    assert_eq!(v, json!([1, "a"]));
+
+    // maps are also inferred for untagged enums
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(untagged)]
+    enum Untagged {
+        Map(HashMap<String, i32>),
+        Seq(Vec<i32>),
+    }
+    let input = BinBuilder::root()
+        .list(4)
+        .str("a")
+        .int(1)
+        .str("b")
+        .int(2)
+        .build();
+    let v: Untagged = from_slice_with_config(&input, &config).unwrap();
+    let expected: HashMap<String, i32> = [("a".to_owned(), 1), ("b".to_owned(), 2)].into();
+    assert_eq!(v, Untagged::Map(expected));
+
+    // disabled by default
+    let v: serde_json::Value = from_slice_with_config(&input, ReaderConfig::default()).unwrap();
+    assert_eq!(v, json!(["a", 1, "b", 2]));
+}
@@ -0,0 +1,67 @@
+use super::bin_builder::BinBuilder;
+use assert_matches::assert_matches;
+use zlisp_bin::{
+    from_reader, from_slice, from_slice_with_config, ErrorCode, RawDocument, ReaderConfig,
+};
+use zlisp_value::Value;
+
+fn nested(depth: usize) -> Vec<u8> {
+    (0..depth)
+        .fold(BinBuilder::root(), |builder, _| builder.list(1))
+        .int(0)
+        .build()
+}
+
+#[test]
+fn max_depth_tests() {
+    assert_eq!(ReaderConfig::default().max_depth(), 128);
+    let config = ReaderConfig::builder().max_depth(2).build();
+    assert_eq!(config.max_depth(), 2);
+}
+
+#[test]
+fn within_limit_tests() {
+    let bin = nested(128);
+    from_slice::<Value>(&bin).unwrap();
+    from_slice::<serde_json::Value>(&bin).unwrap();
+    from_reader::<_, Value>(bin.as_slice()).unwrap();
+    RawDocument::from_slice(&bin).unwrap();
+}
+
+#[test]
+fn deeply_nested_tests() {
+    let bin = nested(10_000);
+    // the outer list header, and 129 list headers. the elements of the last
+    // list exceed the depth.
+    let offset = Some(8 + 129 * 8);
+
+    let err = from_slice::<Value>(&bin).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::RecursionLimitExceeded);
+    assert_eq!(err.offset(), offset);
+
+    let err = from_slice::<serde_json::Value>(&bin).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::RecursionLimitExceeded);
+
+    let err = from_reader::<_, Value>(bin.as_slice()).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::RecursionLimitExceeded);
+    assert_eq!(err.offset(), offset);
+
+    let err = RawDocument::from_slice(&bin).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::RecursionLimitExceeded);
+    assert_eq!(err.offset(), offset);
+}
+
+#[test]
+fn typed_tests() {
+    let config = ReaderConfig::builder().max_depth(2).build();
+    let bin = BinBuilder::root().list(1).list(1).int(0).build();
+    let v: Vec<Vec<i32>> = from_slice_with_config(&bin, &config).unwrap();
+    assert_eq!(v, vec![vec![0]]);
+    let v: Option<Option<i32>> = from_slice_with_config(&bin, &config).unwrap();
+    assert_eq!(v, Some(Some(0)));
+
+    let bin = BinBuilder::root().list(1).list(1).list(1).int(0).build();
+    let err = from_slice_with_config::<Vec<Vec<Vec<i32>>>>(&bin, &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::RecursionLimitExceeded);
+    assert_eq!(err.offset(), Some(32));
+}
@@ -1,3 +1,4 @@
+use super::bin_builder::BinBuilder;
 use super::map;
 use serde_derive::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -21,6 +22,23 @@ fn signed_tests() {
     round_trip!(i32, i32::MAX);
 }
 
+#[test]
+fn top_level_scalar_tests() {
+    // scalars are wrapped in the outer list by `to_vec`, and unwrapped by
+    // `from_slice`, the same as any other value
+    let bin = to_vec(&1i32).unwrap();
+    assert_eq!(bin, BinBuilder::root().int(1).build());
+    assert_eq!(from_slice::<i32>(&bin).unwrap(), 1);
+
+    let bin = to_vec(&1.5f32).unwrap();
+    assert_eq!(bin, BinBuilder::root().float(1.5).build());
+    assert_eq!(from_slice::<f32>(&bin).unwrap(), 1.5);
+
+    let bin = to_vec("foo").unwrap();
+    assert_eq!(bin, BinBuilder::root().str("foo").build());
+    assert_eq!(from_slice::<&str>(&bin).unwrap(), "foo");
+}
+
 #[test]
 fn float_tests() {
     round_trip!(f32, 0.0);
@@ -34,6 +52,32 @@ fn float_tests() {
 fn string_tests() {
     round_trip!(&str, "foo");
     round_trip!(String, String::from("foo"));
+    round_trip!(&str, "");
+    round_trip!(String, String::new());
+}
+
+#[test]
+fn empty_string_tests() {
+    // an empty string is distinct from an absent value
+    round_trip!(Option<String>, Some(String::new()));
+    round_trip!(Option<String>, None);
+    round_trip!(Vec<String>, vec![String::new(), String::new()]);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Struct {
+        a: String,
+        b: Option<String>,
+        c: String,
+    }
+
+    round_trip!(
+        Struct,
+        Struct {
+            a: String::new(),
+            b: Some(String::new()),
+            c: String::from("foo"),
+        }
+    );
 }
 
 #[test]
@@ -93,6 +137,39 @@ fn map_tests() {
     round_trip!(HashMap<&str, i32>, map!["a" => -1, "b" => -2]);
 }
 
+#[test]
+fn map_field_tests() {
+    // the map is framed by a list, so the int keys and values can't be
+    // confused with the surrounding int fields
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct MapField {
+        a: i32,
+        map: HashMap<i32, i32>,
+        b: i32,
+    }
+
+    round_trip!(
+        MapField,
+        MapField {
+            a: 1,
+            map: map![2 => 3, 4 => 5, 6 => 7],
+            b: 8,
+        }
+    );
+    round_trip!(
+        MapField,
+        MapField {
+            a: 1,
+            map: map![],
+            b: 2,
+        }
+    );
+    round_trip!(
+        Vec<HashMap<i32, i32>>,
+        vec![map![1 => 2], map![], map![3 => 4]]
+    );
+}
+
 #[test]
 fn struct_tests() {
     #[derive(Debug, PartialEq, Serialize, Deserialize)]
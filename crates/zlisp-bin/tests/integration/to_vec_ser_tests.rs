@@ -1,6 +1,7 @@
-use super::bin_builder::MAX_LIST_LEN;
+use super::bin_builder::{BinBuilder, LIST, MAX_LIST_LEN, STRING};
 use assert_matches::assert_matches;
-use zlisp_bin::{to_vec, ErrorCode};
+use serde_derive::Serialize;
+use zlisp_bin::{list_header, to_vec, ErrorCode};
 
 macro_rules! assert_unsupported {
     ($type:ty, $value:expr) => {
@@ -61,7 +62,30 @@ fn string_tests() {
     let _ = to_vec(&max_len).unwrap();
 
     let over_len = " ".repeat(256);
-    assert_err!(&str, &over_len, ErrorCode::StringTooLong);
+    assert_err!(
+        &str,
+        &over_len,
+        ErrorCode::StringTooLong { len: 256, max: 255 }
+    );
+
+    let over_len = " ".repeat(300);
+    assert_err!(
+        &str,
+        &over_len,
+        ErrorCode::StringTooLong { len: 300, max: 255 }
+    );
+}
+
+#[test]
+fn empty_string_tests() {
+    let mut expected = Vec::new();
+    expected.extend(LIST.to_le_bytes());
+    expected.extend(2i32.to_le_bytes());
+    expected.extend(STRING.to_le_bytes());
+    expected.extend(0i32.to_le_bytes());
+    assert_eq!(to_vec(&"").unwrap(), expected);
+    assert_eq!(to_vec(&String::new()).unwrap(), expected);
+    assert_eq!(expected, BinBuilder::root().str("").build());
 }
 
 #[test]
@@ -73,12 +97,63 @@ fn seq_tests() {
     assert_err!(Vec<i32>, over_len, ErrorCode::SequenceTooLong);
 }
 
+#[test]
+fn struct_field_order_tests() {
+    // fields are always written in declaration order, not sorted
+    #[derive(Serialize)]
+    struct FieldOrder {
+        z: i32,
+        a: i32,
+        m: i32,
+    }
+
+    let v = FieldOrder { z: 1, a: 2, m: 3 };
+    let expected = BinBuilder::root()
+        .list(6)
+        .str("z")
+        .int(1)
+        .str("a")
+        .int(2)
+        .str("m")
+        .int(3)
+        .build();
+    assert_eq!(to_vec(&v).unwrap(), expected);
+}
+
+#[test]
+fn map_field_tests() {
+    #[derive(Serialize)]
+    struct MapField {
+        a: i32,
+        map: std::collections::BTreeMap<i32, i32>,
+        b: i32,
+    }
+
+    let v = MapField {
+        a: 1,
+        map: [(2, 3), (4, 5)].into(),
+        b: 6,
+    };
+    let expected = BinBuilder::root()
+        .list(6)
+        .str("a")
+        .int(1)
+        .str("map")
+        .list(4)
+        .int(2)
+        .int(3)
+        .int(4)
+        .int(5)
+        .str("b")
+        .int(6)
+        .build();
+    assert_eq!(to_vec(&v).unwrap(), expected);
+}
+
 #[test]
 fn bytes_tests() {
     // normal byte arrays don't work: https://github.com/serde-rs/serde/issues/518
     // this will instead be serialized as a sequence.
-    // assert_unsupported!(&[u8], b"");
-
     pub struct Bytes<'a>(&'a [u8]);
 
     impl<'a> serde::ser::Serialize for Bytes<'a> {
@@ -90,5 +165,57 @@ fn bytes_tests() {
         }
     }
 
-    assert_unsupported!(Bytes, Bytes(b""));
+    let expected = BinBuilder::root().bytes(b"\x00\xFF").build();
+    assert_eq!(to_vec(&Bytes(b"\x00\xFF")).unwrap(), expected);
+}
+
+#[test]
+fn error_source_tests() {
+    use std::error::Error as _;
+
+    struct FailingWriter;
+
+    impl std::io::Write for FailingWriter {
+        fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("disk full"))
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let err = zlisp_bin::to_writer(FailingWriter, &1i32).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::IO(_));
+    assert_eq!(err.to_string(), "I/O error");
+    let source = err.source().unwrap();
+    assert!(source.is::<std::io::Error>());
+    assert_eq!(source.to_string(), "disk full");
+
+    // I/O errors can be cloned and compared
+    let cloned = err.clone();
+    assert_eq!(cloned, err);
+    assert!(cloned.source().unwrap().is::<std::io::Error>());
+    let other = zlisp_bin::to_writer(FailingWriter, &2i32).unwrap_err();
+    assert_eq!(other, err);
+    match err.code() {
+        ErrorCode::IO(e) => assert_eq!(e.kind(), std::io::ErrorKind::Other),
+        code => panic!("unexpected error code: {:?}", code),
+    }
+
+    // other errors are described without a source
+    let err = to_vec(&"\0").unwrap_err();
+    assert_eq!(err.to_string(), "string contains a null");
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn list_header_tests() {
+    // the header is the same as the start of a written list
+    for len in [0, 1, MAX_LIST_LEN as usize] {
+        let bin = to_vec(&vec![0i32; len]).unwrap();
+        assert_eq!(list_header(len).unwrap(), bin[8..16], "{}", len);
+    }
+    let err = list_header(MAX_LIST_LEN as usize + 1).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::SequenceTooLong);
 }
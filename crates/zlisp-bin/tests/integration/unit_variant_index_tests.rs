@@ -0,0 +1,69 @@
+use super::bin_builder::BinBuilder;
+use assert_matches::assert_matches;
+use serde_derive::{Deserialize, Serialize};
+use zlisp_bin::{
+    from_slice, from_slice_with_config, to_vec, to_vec_with_config, ErrorCode, ReaderConfig,
+    WriterConfig,
+};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+enum Mixed {
+    Foo,
+    Bar,
+    Baz(i32),
+}
+
+const WRITER: WriterConfig = WriterConfig::builder().unit_variant_index(true).build();
+const READER: ReaderConfig = ReaderConfig::builder().unit_variant_index(true).build();
+
+#[test]
+fn config_tests() {
+    assert!(!WriterConfig::default().unit_variant_index());
+    assert!(!ReaderConfig::default().unit_variant_index());
+    assert!(WRITER.unit_variant_index());
+    assert!(READER.unit_variant_index());
+
+    // by name by default
+    let bin = to_vec(&Mixed::Bar).unwrap();
+    assert_eq!(bin, BinBuilder::root().str("Bar").build());
+}
+
+#[test]
+fn round_trip_tests() {
+    let v = vec![Mixed::Foo, Mixed::Bar, Mixed::Baz(42)];
+    let bin = to_vec_with_config(&v, &WRITER).unwrap();
+    let expected = BinBuilder::root()
+        .list(3)
+        .int(0)
+        .int(1)
+        .str("Baz")
+        .list(1)
+        .int(42)
+        .build();
+    assert_eq!(bin, expected);
+
+    let actual: Vec<Mixed> = from_slice_with_config(&bin, &READER).unwrap();
+    assert_eq!(actual, v);
+}
+
+#[test]
+fn read_tests() {
+    // variants by name can still be read
+    let bin = to_vec(&Mixed::Bar).unwrap();
+    let actual: Mixed = from_slice_with_config(&bin, &READER).unwrap();
+    assert_eq!(actual, Mixed::Bar);
+
+    // indices are only read when enabled
+    let bin = BinBuilder::root().int(1).build();
+    let err = from_slice::<Mixed>(&bin).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ExpectedToken { .. });
+
+    let bin = BinBuilder::root().int(3).build();
+    let err = from_slice_with_config::<Mixed>(&bin, &READER).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::Custom(_));
+    assert_eq!(err.offset(), Some(8));
+
+    let bin = BinBuilder::root().int(-1).build();
+    let err = from_slice_with_config::<Mixed>(&bin, &READER).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::Custom(_));
+}
@@ -0,0 +1,93 @@
+use super::map;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::HashMap;
+use zlisp_bin::{from_slice, to_vec, value_to_vec};
+use zlisp_value::Value;
+
+macro_rules! round_trip {
+    ($type:ty, $value:expr) => {
+        let expected: $type = $value;
+        let bin = to_vec(&expected).unwrap();
+        let actual: $type = from_slice(&bin).unwrap();
+        assert_eq!(actual, expected);
+    };
+}
+
+fn values() -> Vec<Value> {
+    vec![
+        Value::Int(-1),
+        Value::Float(1.5),
+        Value::from("foo"),
+        Value::from(""),
+        Value::from("0"),
+        Value::List(vec![]),
+        Value::List(vec![Value::Int(1), Value::List(vec![Value::from("a b")])]),
+    ]
+}
+
+#[test]
+fn value_seq_tests() {
+    round_trip!(Vec<Value>, vec![]);
+    round_trip!(Vec<Value>, values());
+}
+
+#[test]
+fn value_map_tests() {
+    round_trip!(HashMap<String, Value>, map![]);
+    let m = values()
+        .into_iter()
+        .enumerate()
+        .map(|(i, v)| (format!("k{}", i), v))
+        .collect();
+    round_trip!(HashMap<String, Value>, m);
+}
+
+#[test]
+fn value_struct_tests() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Partial {
+        name: String,
+        value: Value,
+        values: Vec<Value>,
+        extras: HashMap<String, Value>,
+        after: i32,
+    }
+
+    round_trip!(
+        Partial,
+        Partial {
+            name: String::from("foo"),
+            value: Value::List(values()),
+            values: values(),
+            extras: map![
+                String::from("a") => Value::Int(1),
+                String::from("b") => Value::List(values()),
+            ],
+            after: -1,
+        }
+    );
+}
+
+#[test]
+fn value_to_vec_tests() {
+    let values = vec![
+        Value::Int(-1),
+        Value::Float(1.5),
+        Value::from("foo"),
+        Value::List(vec![]),
+        Value::List(values()),
+    ];
+    for value in values {
+        let expected = to_vec(&value).unwrap();
+        let actual = value_to_vec(&value).unwrap();
+        assert_eq!(actual, expected, "{:?}", value);
+    }
+}
+
+#[test]
+fn value_to_vec_err_tests() {
+    let value = Value::from("a\"b");
+    let expected = to_vec(&value).unwrap_err();
+    let actual = value_to_vec(&value).unwrap_err();
+    assert_eq!(actual.to_string(), expected.to_string());
+}
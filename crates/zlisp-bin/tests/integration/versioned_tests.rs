@@ -0,0 +1,93 @@
+use super::bin_builder::BinBuilder;
+use assert_matches::assert_matches;
+use serde_derive::{Deserialize, Serialize};
+use zlisp_bin::{from_slice, from_slice_versioned, to_vec, to_vec_versioned, ErrorCode};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    name: String,
+    values: Vec<i32>,
+}
+
+fn record() -> Record {
+    Record {
+        name: "foo".to_owned(),
+        values: vec![1, 2, 3],
+    }
+}
+
+#[test]
+fn round_trip_tests() {
+    let expected = record();
+    let data = to_vec_versioned(&expected, 3).unwrap();
+    let actual: Record = from_slice_versioned(&data, 3).unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn header_tests() {
+    let data = to_vec_versioned(&1i32, 0x01020304).unwrap();
+    assert_eq!(&data[..8], b"ZLSP\x04\x03\x02\x01");
+    // the header is followed by the same data as unversioned data
+    assert_eq!(&data[8..], to_vec(&1i32).unwrap());
+    assert_eq!(&data[8..], BinBuilder::root().int(1).build());
+}
+
+#[test]
+fn version_mismatch_tests() {
+    let data = to_vec_versioned(&record(), 2).unwrap();
+    let err = from_slice_versioned::<Record>(&data, 3).unwrap_err();
+    assert_matches!(
+        err.code(),
+        ErrorCode::UnsupportedVersion {
+            expected: 3,
+            found: 2
+        }
+    );
+    assert_eq!(err.offset(), Some(4));
+    assert_eq!(
+        err.to_string(),
+        "unsupported version 2 (expected 3) (at offset: 4)"
+    );
+}
+
+#[test]
+fn bad_magic_tests() {
+    // unversioned data is rejected
+    let data = to_vec(&record()).unwrap();
+    let err = from_slice_versioned::<Record>(&data, 1).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::BadMagic);
+    assert_eq!(err.offset(), Some(0));
+
+    // and versioned data is not read as unversioned data
+    let data = to_vec_versioned(&record(), 1).unwrap();
+    from_slice::<Record>(&data).unwrap_err();
+
+    let err = from_slice_versioned::<Record>(b"ZLS", 1).unwrap_err();
+    assert_matches!(
+        err.code(),
+        ErrorCode::InsufficientData {
+            expected: 4,
+            available: 3
+        }
+    );
+    let err = from_slice_versioned::<Record>(b"ZLSP\x01", 1).unwrap_err();
+    assert_matches!(
+        err.code(),
+        ErrorCode::InsufficientData {
+            expected: 4,
+            available: 1
+        }
+    );
+    assert_eq!(err.offset(), Some(4));
+}
+
+#[test]
+fn offset_tests() {
+    // offsets include the header
+    let mut data = to_vec_versioned(&1i32, 1).unwrap();
+    data.push(0);
+    let err = from_slice_versioned::<i32>(&data, 1).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::TrailingData);
+    assert_eq!(err.offset(), Some(8 + 16));
+}
@@ -4,6 +4,10 @@
 //! For binary formats, the value is serialized/deserialized as an `i32`, which
 //! may not be negative. For text formats, the value is serialized/deserialized
 //! as a string in hexadecimal format.
+//!
+//! The [`HexU32`] newtype supports the full unsigned 32-bit range. For binary
+//! formats, the value is serialized/deserialized as an `i32` with the same bits.
+//! For text formats, it is identical to [`Hex`].
 #![warn(
     missing_docs,
     future_incompatible,
@@ -13,9 +17,10 @@
 )]
 use serde::{de, ser};
 use std::fmt;
+use std::str::FromStr;
 
 /// Represents a hexadecimal zlisp value.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct Hex(i32);
 
@@ -24,6 +29,67 @@ impl Hex {
     pub const fn get(&self) -> i32 {
         self.0
     }
+
+    /// Add a value, returning `None` if the result overflows or is negative.
+    pub const fn checked_add(self, rhs: i32) -> Option<Hex> {
+        match self.0.checked_add(rhs) {
+            Some(v) if v >= 0 => Some(Self(v)),
+            _ => None,
+        }
+    }
+
+    /// Add a value, wrapping around within the non-negative range.
+    ///
+    /// In other words, the result is the sum modulo `2^31`.
+    pub const fn wrapping_add(self, rhs: i32) -> Hex {
+        Self(self.0.wrapping_add(rhs) & i32::MAX)
+    }
+
+    /// Serialize the value with uppercase hexadecimal digits.
+    ///
+    /// For text formats, the value is serialized as e.g. `0xDEAD` instead of
+    /// `0xdead`. The prefix is always lowercase. For binary formats, this has
+    /// no effect. Either case can be deserialized as [`Hex`].
+    pub const fn upper(self) -> HexUpper {
+        HexUpper(self)
+    }
+}
+
+/// A [`Hex`] value that serializes with uppercase hexadecimal digits.
+///
+/// This cannot be constructed, use [`Hex::upper`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HexUpper(Hex);
+
+impl HexUpper {
+    /// Get the underlying value.
+    pub const fn get(&self) -> Hex {
+        self.0
+    }
+}
+
+impl PartialEq<i32> for Hex {
+    fn eq(&self, other: &i32) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Hex> for i32 {
+    fn eq(&self, other: &Hex) -> bool {
+        *self == other.0
+    }
+}
+
+impl PartialOrd<i32> for Hex {
+    fn partial_cmp(&self, other: &i32) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl PartialOrd<Hex> for i32 {
+    fn partial_cmp(&self, other: &Hex) -> Option<std::cmp::Ordering> {
+        self.partial_cmp(&other.0)
+    }
 }
 
 impl From<Hex> for i32 {
@@ -50,6 +116,24 @@ impl From<&Hex> for String {
     }
 }
 
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+impl fmt::LowerHex for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl fmt::UpperHex for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
 impl TryFrom<i32> for Hex {
     type Error = ();
 
@@ -74,6 +158,18 @@ pub enum HexConversionError {
     NegativeValue,
 }
 
+impl fmt::Display for HexConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingPrefix => f.write_str("missing 0x prefix"),
+            Self::Invalid => f.write_str("invalid hexadecimal value"),
+            Self::NegativeValue => f.write_str("negative value"),
+        }
+    }
+}
+
+impl std::error::Error for HexConversionError {}
+
 impl TryFrom<&str> for Hex {
     type Error = HexConversionError;
 
@@ -88,6 +184,109 @@ impl TryFrom<&str> for Hex {
     }
 }
 
+impl FromStr for Hex {
+    type Err = HexConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+/// Represents an unsigned hexadecimal zlisp value.
+///
+/// Unlike [`Hex`], this covers the full unsigned 32-bit range, which is useful
+/// for flags or bitmasks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct HexU32(u32);
+
+impl HexU32 {
+    /// Create a new value.
+    pub const fn new(value: u32) -> Self {
+        Self(value)
+    }
+
+    /// Get the underlying value.
+    pub const fn get(&self) -> u32 {
+        self.0
+    }
+}
+
+impl PartialEq<u32> for HexU32 {
+    fn eq(&self, other: &u32) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<HexU32> for u32 {
+    fn eq(&self, other: &HexU32) -> bool {
+        *self == other.0
+    }
+}
+
+impl From<u32> for HexU32 {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<HexU32> for u32 {
+    fn from(value: HexU32) -> Self {
+        value.0
+    }
+}
+
+impl From<HexU32> for String {
+    fn from(value: HexU32) -> Self {
+        format!("{:#x}", value.0)
+    }
+}
+
+impl From<&HexU32> for String {
+    fn from(value: &HexU32) -> Self {
+        format!("{:#x}", value.0)
+    }
+}
+
+impl fmt::Display for HexU32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+impl fmt::LowerHex for HexU32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
+impl fmt::UpperHex for HexU32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.0, f)
+    }
+}
+
+impl TryFrom<&str> for HexU32 {
+    type Error = HexConversionError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let src = value
+            .strip_prefix("0x")
+            .ok_or(HexConversionError::MissingPrefix)?;
+        u32::from_str_radix(src, 16)
+            .map(Self)
+            .map_err(|_e| HexConversionError::Invalid)
+    }
+}
+
+impl FromStr for HexU32 {
+    type Err = HexConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
 struct BinHexVisitor;
 
 impl<'de> de::Visitor<'de> for BinHexVisitor {
@@ -120,11 +319,9 @@ impl<'de> de::Visitor<'de> for TextHexVisitor {
     where
         E: de::Error,
     {
-        value.try_into().map_err(|e| match e {
-            HexConversionError::MissingPrefix => E::custom(format!("missing prefix: {}", value)),
-            HexConversionError::Invalid => E::custom(format!("invalid: {}", value)),
-            HexConversionError::NegativeValue => E::custom(format!("negative value: {}", value)),
-        })
+        value
+            .try_into()
+            .map_err(|e: HexConversionError| E::custom(format!("{}: {}", e, value)))
     }
 
     fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
@@ -141,7 +338,8 @@ impl<'de> de::Deserialize<'de> for Hex {
         D: de::Deserializer<'de>,
     {
         if deserializer.is_human_readable() {
-            deserializer.deserialize_str(TextHexVisitor)
+            // the text format only supports owned strings
+            deserializer.deserialize_string(TextHexVisitor)
         } else {
             deserializer.deserialize_i32(BinHexVisitor)
         }
@@ -161,3 +359,88 @@ impl ser::Serialize for Hex {
         }
     }
 }
+
+struct BinHexU32Visitor;
+
+impl<'de> de::Visitor<'de> for BinHexU32Visitor {
+    type Value = HexU32;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a 32-bit integer")
+    }
+
+    fn visit_i32<E>(self, value: i32) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(HexU32(value as u32))
+    }
+}
+
+struct TextHexU32Visitor;
+
+impl<'de> de::Visitor<'de> for TextHexU32Visitor {
+    type Value = HexU32;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an unsigned, hexadecimal string")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        value
+            .try_into()
+            .map_err(|e: HexConversionError| E::custom(format!("{}: {}", e, value)))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(&value)
+    }
+}
+
+impl<'de> de::Deserialize<'de> for HexU32 {
+    fn deserialize<D>(deserializer: D) -> Result<HexU32, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            // the text format only supports owned strings
+            deserializer.deserialize_string(TextHexU32Visitor)
+        } else {
+            deserializer.deserialize_i32(BinHexU32Visitor)
+        }
+    }
+}
+
+impl ser::Serialize for HexU32 {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        if serializer.is_human_readable() {
+            let repr: String = self.into();
+            serializer.serialize_str(&repr)
+        } else {
+            serializer.serialize_i32(self.0 as i32)
+        }
+    }
+}
+
+impl ser::Serialize for HexUpper {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        if serializer.is_human_readable() {
+            let repr = format!("0x{:X}", self.0 .0);
+            serializer.serialize_str(&repr)
+        } else {
+            serializer.serialize_i32(self.0 .0)
+        }
+    }
+}
@@ -1,5 +1,6 @@
-use serde_test::{assert_tokens, Configure as _, Token};
-use zlisp_hex::{Hex, HexConversionError};
+use serde_test::{assert_ser_tokens, assert_tokens, Configure as _, Token};
+use std::collections::HashSet;
+use zlisp_hex::{Hex, HexConversionError, HexU32, HexUpper};
 
 macro_rules! conv_i32_ok {
     ($input:expr) => {
@@ -70,3 +71,133 @@ fn serde_conv() {
     assert_tokens(&value.readable(), &[Token::Str("0x1")]);
     assert_tokens(&value.readable(), &[Token::String("0x1")]);
 }
+
+#[test]
+fn error_display() {
+    assert_eq!(
+        HexConversionError::MissingPrefix.to_string(),
+        "missing 0x prefix"
+    );
+    assert_eq!(
+        HexConversionError::Invalid.to_string(),
+        "invalid hexadecimal value"
+    );
+    assert_eq!(
+        HexConversionError::NegativeValue.to_string(),
+        "negative value"
+    );
+
+    fn parse(value: &str) -> Result<Hex, Box<dyn std::error::Error>> {
+        Ok(Hex::try_from(value)?)
+    }
+    let err = parse("0xz").unwrap_err();
+    assert_eq!(err.to_string(), "invalid hexadecimal value");
+}
+
+#[test]
+fn serde_upper() {
+    let value: Hex = 0xdead.try_into().unwrap();
+    assert_ser_tokens(&value.upper().compact(), &[Token::I32(0xdead)]);
+    assert_ser_tokens(&value.upper().readable(), &[Token::Str("0xDEAD")]);
+    // uppercase digits can be deserialized
+    assert_tokens(&value.readable(), &[Token::Str("0xdead")]);
+    let parsed: Hex = "0xDEAD".try_into().unwrap();
+    assert_eq!(parsed, value);
+    let upper: HexUpper = value.upper();
+    assert_eq!(upper.get(), value);
+}
+
+#[test]
+fn arithmetic() {
+    let base: Hex = 0x1000.try_into().unwrap();
+    assert_eq!(base.checked_add(0x10), Some(0x1010.try_into().unwrap()));
+    assert_eq!(base.checked_add(-0x1000), Some(0.try_into().unwrap()));
+    assert_eq!(base.checked_add(-0x1001), None);
+    assert_eq!(base.checked_add(i32::MAX), None);
+
+    assert_eq!(base.wrapping_add(0x10), 0x1010);
+    let max: Hex = i32::MAX.try_into().unwrap();
+    assert_eq!(max.wrapping_add(1), 0);
+    assert_eq!(base.wrapping_add(-0x1001), i32::MAX);
+}
+
+#[test]
+fn i32_cmp() {
+    let value: Hex = 0x10.try_into().unwrap();
+    assert!(value == 0x10);
+    assert!(0x10 == value);
+    assert!(value != 0x11);
+    assert!(value < 0x11);
+    assert!(value > -1);
+    assert!(0x11 > value);
+    assert!(-1 < value);
+}
+
+#[test]
+fn fmt_and_parse() {
+    let value: Hex = 0xdead.try_into().unwrap();
+    assert_eq!(value.to_string(), "0xdead");
+    assert_eq!(format!("{}", value), String::from(value));
+    assert_eq!(format!("{:x}", value), "dead");
+    assert_eq!(format!("{:#x}", value), "0xdead");
+    assert_eq!(format!("{:X}", value), "DEAD");
+    assert_eq!(format!("{:#010X}", value), "0x0000DEAD");
+
+    assert_eq!("0xdead".parse::<Hex>(), Ok(value));
+    assert_eq!("0xDEAD".parse::<Hex>(), Ok(value));
+    assert_eq!(
+        "dead".parse::<Hex>(),
+        Err(HexConversionError::MissingPrefix)
+    );
+    assert_eq!("0xz".parse::<Hex>(), Err(HexConversionError::Invalid));
+    assert_eq!(
+        "0x-1".parse::<Hex>(),
+        Err(HexConversionError::NegativeValue)
+    );
+}
+
+#[test]
+fn hash() {
+    let a: Hex = 0xdead.try_into().unwrap();
+    let b: Hex = 0xbeef.try_into().unwrap();
+    let set: HashSet<Hex> = [a, b, a].into();
+    assert_eq!(set.len(), 2);
+    let set: HashSet<HexUpper> = [a.upper(), b.upper(), a.upper()].into();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn u32_conv() {
+    let value = HexU32::new(0xFFFFFFFF);
+    assert_eq!(value.get(), u32::MAX);
+    assert_eq!(u32::from(value), u32::MAX);
+    assert_eq!(HexU32::from(0x10), 0x10);
+    assert_eq!(value.to_string(), "0xffffffff");
+    assert_eq!(String::from(value), "0xffffffff");
+    assert_eq!(format!("{:X}", value), "FFFFFFFF");
+
+    assert_eq!("0xffffffff".parse::<HexU32>(), Ok(value));
+    assert_eq!("0xFFFFFFFF".parse::<HexU32>(), Ok(value));
+    assert_eq!("0x0".parse::<HexU32>(), Ok(HexU32::new(0)));
+    assert_eq!(
+        "ffffffff".parse::<HexU32>(),
+        Err(HexConversionError::MissingPrefix)
+    );
+    assert_eq!(
+        "0x100000000".parse::<HexU32>(),
+        Err(HexConversionError::Invalid)
+    );
+    assert_eq!("0x-1".parse::<HexU32>(), Err(HexConversionError::Invalid));
+}
+
+#[test]
+fn serde_u32() {
+    let value = HexU32::new(0xFFFFFFFF);
+    assert_tokens(&value.compact(), &[Token::I32(-1)]);
+    assert_tokens(&value.readable(), &[Token::Str("0xffffffff")]);
+    assert_tokens(&value.readable(), &[Token::String("0xffffffff")]);
+
+    let value = HexU32::new(0x80000000);
+    assert_tokens(&value.compact(), &[Token::I32(i32::MIN)]);
+    assert_tokens(&value.readable(), &[Token::Str("0x80000000")]);
+}
@@ -1,18 +1,28 @@
 use crate::constants::MAX_STRING_LEN;
 use crate::error::{Error, ErrorCode, Location, Result};
+use crate::reader::is_number;
+use crate::writer::WhitespaceConfig;
 
-pub fn from_raw<'a>(s: &'a str, loc: Location) -> Result<()> {
+/// Validate text read from text data.
+///
+/// Quotes can only be read if they were escaped, so the caller decides if
+/// they are allowed.
+pub fn from_raw(s: &str, loc: Location, allow_quotes: bool) -> Result<()> {
     let v = s.as_bytes();
     // SAFETY: MAX_STRING_LEN < i32::MAX, usize::MIN > i32::MIN
     if v.len() > MAX_STRING_LEN {
-        return Err(Error::new(ErrorCode::StringTooLong, Some(loc)));
+        let code = ErrorCode::StringTooLong {
+            len: v.len(),
+            max: MAX_STRING_LEN,
+        };
+        return Err(Error::new(code, Some(loc)));
     }
 
     for b in v.iter().copied() {
         if b == 0 {
             return Err(Error::new(ErrorCode::StringContainsNull, Some(loc)));
         }
-        if b == b'"' {
+        if b == b'"' && !allow_quotes {
             return Err(Error::new(ErrorCode::StringContainsQuote, Some(loc)));
         }
         if b & 0x80 != 0 {
@@ -23,29 +33,85 @@ pub fn from_raw<'a>(s: &'a str, loc: Location) -> Result<()> {
     Ok(())
 }
 
-pub fn to_raw<'a>(s: &'a str) -> Result<bool> {
+/// Why a string must be quoted when written.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Quoting {
+    /// The string can be written as-is.
+    None,
+    /// The string contains parentheses, but nothing else requiring quotes.
+    Parens,
+    /// The string could be a number, but nothing else requires quotes.
+    Number,
+    /// The string must always be quoted.
+    Always,
+    /// The string contains quotes, so it must be quoted and escaped.
+    Escape,
+}
+
+impl Quoting {
+    pub fn needs_quoting(self, s: &str, config: &WhitespaceConfig<'_>) -> bool {
+        match self {
+            Self::None => false,
+            Self::Parens => !config.paren_escape,
+            Self::Number if config.quote_numbers_strict => config.quote_numbers && is_number(s),
+            Self::Number => config.quote_numbers,
+            Self::Always | Self::Escape => true,
+        }
+    }
+
+    /// Check the string can be written with the configuration.
+    ///
+    /// Quotes can only be written if escapes are enabled.
+    pub fn check(self, config: &WhitespaceConfig<'_>) -> Result<()> {
+        if self == Self::Escape && !config.quote_escape {
+            Err(Error::new(ErrorCode::StringContainsQuote, None))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+pub fn to_raw(s: &str) -> Result<Quoting> {
     // empty strings must always be quoted, otherwise they will disappear
     if s.is_empty() {
-        return Ok(true);
+        return Ok(Quoting::Always);
     }
 
     let v = s.as_bytes();
     // SAFETY: MAX_STRING_LEN < i32::MAX, usize::MIN > i32::MIN
     if v.len() > MAX_STRING_LEN {
-        return Err(Error::new(ErrorCode::StringTooLong, None));
+        let code = ErrorCode::StringTooLong {
+            len: v.len(),
+            max: MAX_STRING_LEN,
+        };
+        return Err(Error::new(code, None));
     }
 
     let mut needs_quoting = false;
+    let mut has_quotes = false;
+    let mut has_parens = false;
     let mut possible_number = true;
     for b in v.iter().copied() {
         match b {
             b'\0' => Err(Error::new(ErrorCode::StringContainsNull, None)),
-            b'"' => Err(Error::new(ErrorCode::StringContainsQuote, None)),
-            b' ' | b'\t' | b'\r' | b'\n' | b'(' | b')' => {
+            b'"' => {
+                // whether quotes can be written depends on the configuration,
+                // which is checked when writing
+                possible_number = false;
+                has_quotes = true;
+                Ok(())
+            }
+            // a semicolon starts a comment, if the reader allows comments
+            b' ' | b'\t' | b'\r' | b'\n' | b';' => {
                 possible_number = false;
                 needs_quoting = true;
                 Ok(())
             }
+            b'(' | b')' => {
+                possible_number = false;
+                has_parens = true;
+                Ok(())
+            }
             b'-' | b'+' | b'.' | b'0'..=b'9' => {
                 // possible number remains true
                 Ok(())
@@ -62,6 +128,73 @@ pub fn to_raw<'a>(s: &'a str) -> Result<bool> {
     // number, we quote it regardless. this avoids actually needing to parse
     // the string to an integer or a float, which is expensive. the downside is
     // there may be false positives, but worst case is a string is quoted when
-    // it didn't need to be.
-    Ok(needs_quoting || possible_number)
+    // it didn't need to be. the strict mode parses the string when writing.
+    if has_quotes {
+        Ok(Quoting::Escape)
+    } else if needs_quoting {
+        Ok(Quoting::Always)
+    } else if possible_number {
+        Ok(Quoting::Number)
+    } else if has_parens {
+        Ok(Quoting::Parens)
+    } else {
+        Ok(Quoting::None)
+    }
+}
+
+/// A buffer that text can be written to.
+pub trait Buffer {
+    fn push(&mut self, ch: char);
+    fn push_str(&mut self, string: &str);
+}
+
+impl Buffer for String {
+    #[inline]
+    fn push(&mut self, ch: char) {
+        String::push(self, ch)
+    }
+
+    #[inline]
+    fn push_str(&mut self, string: &str) {
+        String::push_str(self, string)
+    }
+}
+
+/// Write a string validated by [`to_raw`], quoting or escaping as required.
+///
+/// Strings that contain quotes must also have been checked with
+/// [`Quoting::check`].
+pub fn push_raw<B: Buffer>(
+    buffer: &mut B,
+    s: &str,
+    quoting: Quoting,
+    config: &WhitespaceConfig<'_>,
+) {
+    let needs_quoting = quoting.needs_quoting(s, config);
+    if needs_quoting && config.quote_escape {
+        buffer.push('"');
+        for c in s.chars() {
+            match c {
+                '"' => buffer.push_str("\\\""),
+                '\\' => buffer.push_str("\\\\"),
+                '\n' => buffer.push_str("\\n"),
+                '\t' => buffer.push_str("\\t"),
+                _ => buffer.push(c),
+            }
+        }
+        buffer.push('"');
+    } else if needs_quoting {
+        buffer.push('"');
+        buffer.push_str(s);
+        buffer.push('"');
+    } else if quoting == Quoting::Parens {
+        for c in s.chars() {
+            if c == '(' || c == ')' {
+                buffer.push('\\');
+            }
+            buffer.push(c);
+        }
+    } else {
+        buffer.push_str(s);
+    }
 }
@@ -1,5 +1,6 @@
 use serde::{de, ser};
 use std::fmt;
+use std::sync::Arc;
 
 /// A high-level description of a token.
 #[derive(Debug, Clone, PartialEq)]
@@ -28,8 +29,45 @@ impl fmt::Display for TokenType {
     }
 }
 
+/// An I/O error.
+///
+/// Unlike [`std::io::Error`], this can be cloned and compared. Errors are equal
+/// if they have the same kind and message.
+#[derive(Debug, Clone)]
+pub struct IoError(Arc<std::io::Error>);
+
+impl IoError {
+    /// The kind of the I/O error.
+    pub fn kind(&self) -> std::io::ErrorKind {
+        self.0.kind()
+    }
+
+    /// The underlying I/O error.
+    pub fn get_ref(&self) -> &std::io::Error {
+        &self.0
+    }
+}
+
+impl From<std::io::Error> for IoError {
+    fn from(e: std::io::Error) -> Self {
+        Self(Arc::new(e))
+    }
+}
+
+impl PartialEq for IoError {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.kind() == other.0.kind() && self.0.to_string() == other.0.to_string()
+    }
+}
+
+impl fmt::Display for IoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
 /// The detailed cause of an error.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 #[non_exhaustive]
 pub enum ErrorCode {
     // --- General ---
@@ -37,11 +75,18 @@ pub enum ErrorCode {
     ///
     /// This is how serde errors are reported.
     Custom(String),
+    /// An error occurred during an I/O operation.
+    IO(IoError),
     /// The data type is not supported by the serializer or deserializer.
     UnsupportedType,
     // --- Tokenizer ---
     /// An opening quote was found, but no closing quote.
     EofWhileParsingQuote,
+    /// A quoted string contains an invalid escape sequence.
+    ///
+    /// This is only checked if escapes are enabled by the reader
+    /// configuration.
+    InvalidEscape,
     // --- Parser ---
     /// A token was expected, but an incompatible token was found.
     ExpectedToken {
@@ -49,6 +94,16 @@ pub enum ErrorCode {
         expected: TokenType,
         /// The actual token type.
         found: TokenType,
+        /// The text of the actual token, if it was a text token.
+        text: Option<String>,
+    },
+    /// A token was expected, but the end of the data was reached.
+    ///
+    /// This is distinct from [`ErrorCode::ExpectedToken`], so that reaching
+    /// the end can be told apart from other mismatches.
+    UnexpectedEof {
+        /// The expected token type.
+        expected: TokenType,
     },
     /// An integer could not be parsed from a text token.
     ParseIntError {
@@ -64,20 +119,58 @@ pub enum ErrorCode {
         /// The entire text token.
         s: String,
     },
-    /// A quoted string may not be converted to an int or float.
+    /// A boolean could not be parsed from a text token.
+    ///
+    /// Booleans are only read if enabled by the reader configuration.
+    ParseBoolError {
+        /// The entire text token.
+        s: String,
+    },
+    /// A character could not be parsed from a text token.
+    ///
+    /// Characters are only read if enabled by the reader configuration.
+    ParseCharError {
+        /// The entire text token.
+        s: String,
+    },
+    /// A quoted string may not be converted to an int, float, or boolean.
     QuotedString,
 
+    // --- Readers ---
+    /// The configured maximum number of elements was exceeded.
+    ElementLimitExceeded,
+    /// A sequence contained the same element more than once.
+    ///
+    /// This is only checked if duplicates are denied by the reader
+    /// configuration.
+    DuplicateElement,
+    /// The configured maximum nesting depth of lists was exceeded.
+    RecursionLimitExceeded,
+
     // --- Writers ---
     /// A sequence is too long to serialize.
     SequenceTooLong,
     /// A sequence must have a length to be serialized.
     SequenceMustHaveLength,
+    /// The whitespace configuration contains invalid whitespace.
+    ///
+    /// This is only checked by
+    /// [`WhitespaceConfigBuilder::try_build`](crate::WhitespaceConfigBuilder::try_build).
+    InvalidWhitespace {
+        /// The name of the invalid field.
+        field: &'static str,
+    },
 
     // --- Strings ---
     /// A string is too long.
     ///
     /// Strings may not be longer than 255 bytes.
-    StringTooLong,
+    StringTooLong {
+        /// The length of the string.
+        len: usize,
+        /// The maximum length of the string.
+        max: usize,
+    },
     /// A string contains a null character.
     StringContainsNull,
     /// A string contains a quote character.
@@ -91,32 +184,52 @@ impl fmt::Display for ErrorCode {
         match self {
             // General
             ErrorCode::Custom(s) => write!(f, "{}", s),
+            // the I/O error is the source, and so isn't repeated here
+            ErrorCode::IO(_) => f.write_str("I/O error"),
             ErrorCode::UnsupportedType => f.write_str("unsupported type"),
             // Tokenizer
             ErrorCode::EofWhileParsingQuote => {
                 f.write_str("end of file while parsing a quoted string")
             }
+            ErrorCode::InvalidEscape => f.write_str("invalid escape sequence"),
             // Parser
-            ErrorCode::ExpectedToken { expected, found } => {
-                write!(f, "expected {}, found {}", expected, found)
+            ErrorCode::ExpectedToken {
+                expected,
+                found,
+                text: Some(text),
+            } => {
+                write!(f, "expected {}, found {} `{}`", expected, found, text)
             }
-            ErrorCode::ParseIntError { e, s } => {
-                // PIE Empty: "cannot parse integer from empty string"
-                // PIE Invalid: "invalid digit found in string"
-                // PIE Overflow: "number too large to fit in target type"
-                write!(f, "{}: `{}`", e, s)
+            ErrorCode::ExpectedToken {
+                expected,
+                found,
+                text: None,
+            } => {
+                write!(f, "expected {}, found {}", expected, found)
             }
-            ErrorCode::ParseFloatError { e, s } => {
-                // PFE Empty: "cannot parse float from empty string"
-                // PFE Invalid: "invalid float literal"
-                write!(f, "{}: `{}`", e, s)
+            ErrorCode::UnexpectedEof { expected } => {
+                write!(f, "expected {}, found end of file", expected)
             }
+            // the parse errors are the source, and so aren't repeated here
+            ErrorCode::ParseIntError { e: _, s } => write!(f, "invalid integer `{}`", s),
+            ErrorCode::ParseFloatError { e: _, s } => write!(f, "invalid float `{}`", s),
+            ErrorCode::ParseBoolError { s } => write!(f, "invalid boolean `{}`", s),
+            ErrorCode::ParseCharError { s } => write!(f, "invalid character `{}`", s),
             ErrorCode::QuotedString => f.write_str("a quoted string may not be converted"),
+            // Readers
+            ErrorCode::ElementLimitExceeded => f.write_str("element limit exceeded"),
+            ErrorCode::DuplicateElement => f.write_str("duplicate element"),
+            ErrorCode::RecursionLimitExceeded => f.write_str("recursion limit exceeded"),
             // Writers
             ErrorCode::SequenceTooLong => f.write_str("sequence is too long"),
             ErrorCode::SequenceMustHaveLength => f.write_str("sequence must have a known length"),
+            ErrorCode::InvalidWhitespace { field } => {
+                write!(f, "invalid whitespace for `{}`", field)
+            }
             // Strings
-            ErrorCode::StringTooLong => f.write_str("string is too long"),
+            ErrorCode::StringTooLong { len, max } => {
+                write!(f, "string is too long ({} > {})", len, max)
+            }
             ErrorCode::StringContainsNull => f.write_str("string contains a null"),
             ErrorCode::StringContainsQuote => f.write_str("string contains a quote"),
             ErrorCode::StringContainsInvalidChar => {
@@ -135,7 +248,6 @@ pub struct Location {
 
 impl Location {
     /// Construct a new location.
-
     pub const fn new(line: usize, col: usize) -> Self {
         Self { line, col }
     }
@@ -143,25 +255,24 @@ impl Location {
     /// The line in the text data.
     ///
     /// The first line in the text is `1`.
-
     pub fn line(&self) -> usize {
         self.line
     }
 
     /// The column in the text data.
     ///
-    /// The first character in a line is `1`, although the column may be `0`
-    /// immediately after a line break.
-
+    /// The first character in a line is `1`, including immediately after a
+    /// line break. Columns count characters, not bytes.
     pub fn column(&self) -> usize {
         self.col
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 struct ErrorContext {
     code: ErrorCode,
     location: Option<Location>,
+    snippet: Option<String>,
 }
 
 impl fmt::Display for ErrorContext {
@@ -171,15 +282,36 @@ impl fmt::Display for ErrorContext {
                 f,
                 "{} (at line: {}, column: {})",
                 self.code, loc.line, loc.col
-            ),
-            None => fmt::Display::fmt(&self.code, f),
+            )?,
+            None => fmt::Display::fmt(&self.code, f)?,
         }
+        match &self.snippet {
+            Some(snippet) => write!(f, "\n{}", snippet),
+            None => Ok(()),
+        }
+    }
+}
+
+fn render_snippet(source: &str, loc: &Location) -> Option<String> {
+    let line = source.split('\n').nth(loc.line.checked_sub(1)?)?;
+    let line = line.strip_suffix('\r').unwrap_or(line);
+    // the column may be one past the end of the line, e.g. for the end of file
+    let col = loc.col.checked_sub(1)?;
+    if col > line.chars().count() {
+        return None;
     }
+    // keep tabs, so the caret lines up regardless of the tab width
+    let indent: String = line
+        .chars()
+        .take(col)
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    Some(format!("{}\n{}^", line, indent))
 }
 
 /// This type represents all possible errors that can occur when serializing or
 /// deserializing text zlisp data.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Error(Box<ErrorContext>);
 
 /// A specialized [Result](std::result::Result) type for serialization or
@@ -190,11 +322,14 @@ impl Error {
     /// Construct a new error.
     #[cold]
     pub fn new(code: ErrorCode, location: Option<Location>) -> Self {
-        Self(Box::new(ErrorContext { code, location }))
+        Self(Box::new(ErrorContext {
+            code,
+            location,
+            snippet: None,
+        }))
     }
 
     /// The error code.
-
     pub const fn code(&self) -> &ErrorCode {
         &self.0.code
     }
@@ -203,11 +338,24 @@ impl Error {
     ///
     /// For deserialization, this is the line and column in the data. For
     /// serialization, likely `None`.
-
     pub const fn location(&self) -> Option<&Location> {
         self.0.location.as_ref()
     }
 
+    /// Render the line of the source text at the error location, with a
+    /// caret under the column.
+    ///
+    /// The source must be the text data the error occurred in. This is `None`
+    /// if the error has no location, or the location isn't in the source.
+    pub fn snippet(&self, source: &str) -> Option<String> {
+        render_snippet(source, self.0.location.as_ref()?)
+    }
+
+    pub(crate) fn attach_snippet(mut self, source: &str) -> Self {
+        self.0.snippet = self.snippet(source);
+        self
+    }
+
     pub(crate) fn attach_location(mut self, loc: Location) -> Self {
         if self.0.location.is_none() {
             self.0.location = Some(loc)
@@ -245,9 +393,16 @@ impl de::Error for Error {
 impl de::StdError for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match &self.0.code {
+            ErrorCode::IO(e) => Some(e.get_ref()),
             ErrorCode::ParseIntError { e, s: _ } => Some(e),
             ErrorCode::ParseFloatError { e, s: _ } => Some(e),
             _ => None,
         }
     }
 }
+
+// errors must be usable across threads, e.g. in async tasks
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Error>();
+};
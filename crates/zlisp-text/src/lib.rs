@@ -11,8 +11,18 @@ mod ascii;
 mod constants;
 mod error;
 mod reader;
+mod spanned;
 mod writer;
 
-pub use error::{Error, ErrorCode, Location, Result, TokenType};
-pub use reader::from_str;
-pub use writer::{to_pretty, to_string, WhitespaceConfig, WhitespaceConfigBuilder};
+pub use error::{Error, ErrorCode, IoError, Location, Result, TokenType};
+pub use reader::{
+    events, events_with_config, from_str, from_str_many, from_str_verbose, from_str_with_config,
+    tokenize, tokenize_with_config, Deserializer, Event, Events, NonFinitePolicy, Reader,
+    ReaderConfig, ReaderConfigBuilder, StreamDeserializer, Token, Tokens,
+};
+pub use spanned::Spanned;
+pub use writer::{
+    serialized_len, to_pretty, to_pretty_many, to_string, to_string_canonical, to_writer,
+    to_writer_pretty, value_to_string, write_element, Element, WhitespaceConfig,
+    WhitespaceConfigBuilder,
+};
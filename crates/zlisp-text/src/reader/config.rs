@@ -0,0 +1,393 @@
+const DEFAULT_MAX_DEPTH: usize = 128;
+
+/// How to read non-finite floats, i.e. `inf`, `-inf`, and `NaN`.
+///
+/// Rust's float parsing also turns out-of-range values into infinities, so
+/// these are treated as non-finite as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonFinitePolicy {
+    /// Reject non-finite floats, and the non-finite literals.
+    Reject,
+    /// Read infinities as `f32::MAX` or `f32::MIN`, respectively.
+    ///
+    /// `NaN` has no nearest finite value, and is still rejected.
+    Saturate,
+    /// Read any non-finite float as `0.0`.
+    Zero,
+}
+
+/// A builder of reader configuration.
+///
+/// This cannot be constructed, use [`ReaderConfig::builder`].
+#[derive(Debug, Clone)]
+pub struct ReaderConfigBuilder {
+    max_elements: Option<usize>,
+    paren_escape: bool,
+    quote_escape: bool,
+    lenient_whitespace: Option<char>,
+    deny_duplicates: bool,
+    saturate_ints: bool,
+    hex_ints: bool,
+    bools: bool,
+    chars: bool,
+    infer_maps: bool,
+    non_finite: NonFinitePolicy,
+    line_comments: bool,
+    max_depth: usize,
+}
+
+impl ReaderConfigBuilder {
+    /// The maximum number of elements to read.
+    ///
+    /// Every scalar and every list counts as one element. This bounds the
+    /// memory used when reading untrusted data. The default is no limit.
+    #[inline]
+    pub const fn max_elements(mut self, max_elements: usize) -> Self {
+        self.max_elements = Some(max_elements);
+        self
+    }
+
+    /// Decode escaped parentheses in unquoted strings.
+    ///
+    /// When enabled, `\(` and `\)` in unquoted text are read as `(` and `)`,
+    /// as written by [`WhitespaceConfigBuilder::paren_escape`](crate::WhitespaceConfigBuilder::paren_escape).
+    /// The default is `false`.
+    #[inline]
+    pub const fn paren_escape(mut self, paren_escape: bool) -> Self {
+        self.paren_escape = paren_escape;
+        self
+    }
+
+    /// Decode escape sequences in quoted strings.
+    ///
+    /// When enabled, `\"`, `\\`, `\n`, and `\t` in quoted text are read as a
+    /// quote, backslash, newline, and tab, as written by
+    /// [`WhitespaceConfigBuilder::quote_escape`](crate::WhitespaceConfigBuilder::quote_escape).
+    /// Any other escape sequence is an error. The default is `false`.
+    #[inline]
+    pub const fn quote_escape(mut self, quote_escape: bool) -> Self {
+        self.quote_escape = quote_escape;
+        self
+    }
+
+    /// Ignore an extra separator character between tokens.
+    ///
+    /// Runs of whitespace are always treated uniformly. In lenient mode, the
+    /// separator (e.g. a stray `,`) is additionally treated like whitespace
+    /// outside of quotes, so it also ends unquoted text. Strings containing
+    /// the separator must be quoted to be read correctly. The separator should
+    /// not be a quote or parenthesis. The default is strict, i.e. no
+    /// separator.
+    #[inline]
+    pub const fn lenient_whitespace(mut self, separator: char) -> Self {
+        self.lenient_whitespace = Some(separator);
+        self
+    }
+
+    /// Error if a sequence contains the same element more than once.
+    ///
+    /// Serde does not distinguish sets from other sequences, so this applies
+    /// to all sequences, e.g. a `Vec` as well as a `HashSet`. Otherwise,
+    /// duplicate elements silently collapse when reading into a set. Elements
+    /// are compared by their deserialized values, so e.g. `1`, `01`, and `+1`
+    /// are the same integer, regardless of whitespace and quoting. The default
+    /// is `false`.
+    #[inline]
+    pub const fn deny_duplicates(mut self, deny_duplicates: bool) -> Self {
+        self.deny_duplicates = deny_duplicates;
+        self
+    }
+
+    /// Clamp out-of-range integers instead of failing.
+    ///
+    /// When enabled, integer literals that are too large or too small for an
+    /// `i32` are read as `i32::MAX` or `i32::MIN`, respectively. This only
+    /// applies when an integer is expected; when reading untyped data, e.g.
+    /// into a `Value`, such literals are read as floats regardless. The
+    /// default is `false`.
+    #[inline]
+    pub const fn saturate_ints(mut self, saturate_ints: bool) -> Self {
+        self.saturate_ints = saturate_ints;
+        self
+    }
+
+    /// Read unquoted `0x`-prefixed text as integers when reading untyped data.
+    ///
+    /// When enabled, text such as `0xff` is read as the integer `255` when the
+    /// type is not known, e.g. when reading into a `Value`. Otherwise, such
+    /// text is read as a string. Typed reads are not affected. The default is
+    /// `false`.
+    #[inline]
+    pub const fn hex_ints(mut self, hex_ints: bool) -> Self {
+        self.hex_ints = hex_ints;
+        self
+    }
+
+    /// Read unquoted `true` and `false` as booleans, when a boolean is expected.
+    ///
+    /// The zlisp data formats have no booleans, so by default reading a `bool`
+    /// is unsupported. When enabled, the text `true` or `false` (ignoring
+    /// case) can be read into a `bool`, which is convenient for hand-written
+    /// data. Untyped reads are not affected. The default is `false`.
+    #[inline]
+    pub const fn bools(mut self, bools: bool) -> Self {
+        self.bools = bools;
+        self
+    }
+
+    /// Read single-character text as characters, when a character is
+    /// expected.
+    ///
+    /// By default, reading a `char` is unsupported. When enabled, text that is
+    /// exactly one character long, quoted or not, can be read into a `char`.
+    /// Untyped reads are not affected. The default is `false`.
+    #[inline]
+    pub const fn chars(mut self, chars: bool) -> Self {
+        self.chars = chars;
+        self
+    }
+
+    /// Read lists of alternating string keys and values as maps, when reading
+    /// untyped data.
+    ///
+    /// When enabled, a non-empty, even-length list where every key (i.e. the
+    /// first, third, ... element) is a string is visited as a map instead of
+    /// a sequence when the type is not known, e.g. when reading into a
+    /// `serde_json::Value`. This requires buffering each such list while
+    /// reading it. Typed reads are not affected. The default is `false`, as
+    /// such lists may also be sequences.
+    #[inline]
+    pub const fn infer_maps(mut self, infer_maps: bool) -> Self {
+        self.infer_maps = infer_maps;
+        self
+    }
+
+    /// How to read non-finite floats.
+    ///
+    /// Unless the policy is [`NonFinitePolicy::Reject`], the literals `inf`,
+    /// `infinity`, and `NaN` (ignoring case, optionally signed) are accepted
+    /// when a float is expected, as are values too large for an `f32`. These
+    /// are then mapped to finite floats according to the policy. When reading
+    /// untyped data, e.g. into a `Value`, such literals are read as strings
+    /// regardless. The default is [`NonFinitePolicy::Reject`].
+    #[inline]
+    pub const fn non_finite(mut self, non_finite: NonFinitePolicy) -> Self {
+        self.non_finite = non_finite;
+        self
+    }
+
+    /// Ignore `;`-prefixed line comments.
+    ///
+    /// When enabled, a `;` outside of quotes starts a comment, which runs to
+    /// the end of the line. The comment is ignored like whitespace, so it also
+    /// ends unquoted text. Strings containing a `;` must be quoted to be read
+    /// correctly. The default is `false`.
+    #[inline]
+    pub const fn line_comments(mut self, line_comments: bool) -> Self {
+        self.line_comments = line_comments;
+        self
+    }
+
+    /// The maximum nesting depth of lists to read.
+    ///
+    /// Reading nested lists recurses, so deeply nested data could otherwise
+    /// overflow the stack. Exceeding the depth is an error. The default is
+    /// 128.
+    #[inline]
+    pub const fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Construct a new reader configuration.
+    #[inline]
+    pub const fn build(self) -> ReaderConfig {
+        ReaderConfig {
+            max_elements: self.max_elements,
+            paren_escape: self.paren_escape,
+            quote_escape: self.quote_escape,
+            lenient_whitespace: self.lenient_whitespace,
+            deny_duplicates: self.deny_duplicates,
+            saturate_ints: self.saturate_ints,
+            hex_ints: self.hex_ints,
+            bools: self.bools,
+            chars: self.chars,
+            infer_maps: self.infer_maps,
+            non_finite: self.non_finite,
+            line_comments: self.line_comments,
+            max_depth: self.max_depth,
+        }
+    }
+}
+
+/// Configuration for text readers.
+#[derive(Debug, Clone)]
+pub struct ReaderConfig {
+    /// The maximum number of elements to read.
+    pub(crate) max_elements: Option<usize>,
+    /// Decode escaped parentheses in unquoted strings.
+    pub(crate) paren_escape: bool,
+    /// Decode escape sequences in quoted strings.
+    pub(crate) quote_escape: bool,
+    /// An extra separator character to ignore between tokens.
+    pub(crate) lenient_whitespace: Option<char>,
+    /// Error if a sequence contains the same element more than once.
+    pub(crate) deny_duplicates: bool,
+    /// Clamp out-of-range integers instead of failing.
+    pub(crate) saturate_ints: bool,
+    /// Read unquoted `0x`-prefixed text as integers when reading untyped data.
+    pub(crate) hex_ints: bool,
+    /// Read unquoted `true` and `false` as booleans, when a boolean is expected.
+    pub(crate) bools: bool,
+    /// Read single-character text as characters, when a character is
+    /// expected.
+    pub(crate) chars: bool,
+    /// Read lists of alternating string keys and values as maps, when reading
+    /// untyped data.
+    pub(crate) infer_maps: bool,
+    /// How to read non-finite floats.
+    pub(crate) non_finite: NonFinitePolicy,
+    /// Ignore `;`-prefixed line comments.
+    pub(crate) line_comments: bool,
+    /// The maximum nesting depth of lists to read.
+    pub(crate) max_depth: usize,
+}
+
+impl ReaderConfig {
+    /// The default reader configuration.
+    ///
+    /// This does not limit the number of elements, does not decode escaped
+    /// parentheses or quotes, is strict about separators, allows duplicates,
+    /// and rejects out-of-range integers and non-finite floats, does not read
+    /// booleans or characters, does not allow comments, and limits the nesting
+    /// depth to 128.
+    pub const DEFAULT: Self = {
+        Self {
+            max_elements: None,
+            paren_escape: false,
+            quote_escape: false,
+            lenient_whitespace: None,
+            deny_duplicates: false,
+            saturate_ints: false,
+            hex_ints: false,
+            bools: false,
+            chars: false,
+            infer_maps: false,
+            non_finite: NonFinitePolicy::Reject,
+            line_comments: false,
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    };
+
+    /// The default reader configuration.
+    ///
+    /// This does not limit the number of elements, does not decode escaped
+    /// parentheses or quotes, is strict about separators, allows duplicates,
+    /// and rejects out-of-range integers and non-finite floats, does not read
+    /// booleans or characters, does not allow comments, and limits the nesting
+    /// depth to 128.
+    #[inline(always)]
+    pub const fn default() -> &'static Self {
+        &Self::DEFAULT
+    }
+
+    /// Construct a builder for a reader configuration.
+    #[inline]
+    pub const fn builder() -> ReaderConfigBuilder {
+        ReaderConfigBuilder {
+            max_elements: None,
+            paren_escape: false,
+            quote_escape: false,
+            lenient_whitespace: None,
+            deny_duplicates: false,
+            saturate_ints: false,
+            hex_ints: false,
+            bools: false,
+            chars: false,
+            infer_maps: false,
+            non_finite: NonFinitePolicy::Reject,
+            line_comments: false,
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+
+    /// The maximum number of elements to read.
+    #[inline(always)]
+    pub const fn max_elements(&self) -> Option<usize> {
+        self.max_elements
+    }
+
+    /// Decode escaped parentheses in unquoted strings.
+    #[inline(always)]
+    pub const fn paren_escape(&self) -> bool {
+        self.paren_escape
+    }
+
+    /// Decode escape sequences in quoted strings.
+    #[inline(always)]
+    pub const fn quote_escape(&self) -> bool {
+        self.quote_escape
+    }
+
+    /// An extra separator character to ignore between tokens.
+    #[inline(always)]
+    pub const fn lenient_whitespace(&self) -> Option<char> {
+        self.lenient_whitespace
+    }
+
+    /// Error if a sequence contains the same element more than once.
+    #[inline(always)]
+    pub const fn deny_duplicates(&self) -> bool {
+        self.deny_duplicates
+    }
+
+    /// Clamp out-of-range integers instead of failing.
+    #[inline(always)]
+    pub const fn saturate_ints(&self) -> bool {
+        self.saturate_ints
+    }
+
+    /// Read unquoted `0x`-prefixed text as integers when reading untyped data.
+    #[inline(always)]
+    pub const fn hex_ints(&self) -> bool {
+        self.hex_ints
+    }
+
+    /// Read unquoted `true` and `false` as booleans, when a boolean is expected.
+    #[inline(always)]
+    pub const fn bools(&self) -> bool {
+        self.bools
+    }
+
+    /// Read single-character text as characters, when a character is
+    /// expected.
+    #[inline(always)]
+    pub const fn chars(&self) -> bool {
+        self.chars
+    }
+
+    /// Read lists of alternating string keys and values as maps, when reading
+    /// untyped data.
+    #[inline(always)]
+    pub const fn infer_maps(&self) -> bool {
+        self.infer_maps
+    }
+
+    /// How to read non-finite floats.
+    #[inline(always)]
+    pub const fn non_finite(&self) -> NonFinitePolicy {
+        self.non_finite
+    }
+
+    /// Ignore `;`-prefixed line comments.
+    #[inline(always)]
+    pub const fn line_comments(&self) -> bool {
+        self.line_comments
+    }
+
+    /// The maximum nesting depth of lists to read.
+    #[inline(always)]
+    pub const fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+}
@@ -0,0 +1,94 @@
+use super::config::ReaderConfig;
+use super::parse::Any;
+use super::str_reader::StrReader;
+use super::tokenizer::Token;
+use crate::error::Result;
+
+/// An event produced while reading text zlisp data.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// The start of a list.
+    ListStart,
+    /// The end of a list.
+    ListEnd,
+    /// An integer.
+    Int(i32),
+    /// A float.
+    Float(f32),
+    /// A string.
+    Str(String),
+}
+
+/// An iterator over the events of text zlisp data.
+///
+/// This cannot be constructed, use [`events`] or [`events_with_config`].
+#[derive(Debug, Clone)]
+pub struct Events<'a> {
+    /// The reader, or `None` after the end of the data or an error.
+    reader: Option<StrReader<'a>>,
+    depth: usize,
+    started: bool,
+}
+
+impl<'a> Events<'a> {
+    fn advance(&mut self, reader: &mut StrReader<'a>) -> Result<Option<Event>> {
+        if self.depth > 0 {
+            if let Token::ListEnd = reader.peek()?.token {
+                reader.read_list_end()?;
+                self.depth -= 1;
+                return Ok(Some(Event::ListEnd));
+            }
+        } else if self.started {
+            return Ok(None);
+        }
+        self.started = true;
+        let event = match reader.read_any()? {
+            Any::Int(v) => Event::Int(v),
+            Any::Float(v) => Event::Float(v),
+            Any::String(v) => Event::Str(v),
+            Any::ListStart => {
+                self.depth += 1;
+                Event::ListStart
+            }
+        };
+        Ok(Some(event))
+    }
+}
+
+impl<'a> Iterator for Events<'a> {
+    type Item = Result<Event>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut reader = self.reader.take()?;
+        match self.advance(&mut reader) {
+            Ok(Some(event)) => {
+                self.reader = Some(reader);
+                Some(Ok(event))
+            }
+            Ok(None) => reader.finish().err().map(Err),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// Read events from text zlisp data, without building the value.
+///
+/// Unquoted text is reported as an integer or float if it can be parsed as
+/// one, and as a string otherwise. After the first error, the iterator is
+/// exhausted.
+pub fn events(s: &str) -> Events<'_> {
+    events_with_config(s, ReaderConfig::default())
+}
+
+/// Read events from text zlisp data, using the reader configuration.
+///
+/// Options that require the value, like
+/// [`deny_duplicates`](crate::ReaderConfigBuilder::deny_duplicates), are
+/// ignored.
+pub fn events_with_config<'a>(s: &'a str, config: &ReaderConfig) -> Events<'a> {
+    Events {
+        reader: Some(StrReader::with_config(s, config.clone())),
+        depth: 0,
+        started: false,
+    }
+}
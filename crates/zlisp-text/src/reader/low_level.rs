@@ -0,0 +1,94 @@
+use super::config::ReaderConfig;
+use super::str_reader::StrReader;
+use super::tokenizer::Token;
+use crate::error::{Location, Result, TokenType};
+
+/// A low-level reader for text zlisp data, for hand-written parsers.
+///
+/// This is useful for formats where the structure depends on earlier values,
+/// which can't be expressed with serde.
+#[derive(Debug, Clone)]
+pub struct Reader<'a> {
+    inner: StrReader<'a>,
+}
+
+impl<'a> Reader<'a> {
+    /// Construct a new reader over text zlisp data.
+    pub fn new(s: &'a str) -> Self {
+        Self::with_config(s, ReaderConfig::default())
+    }
+
+    /// Construct a new reader over text zlisp data, using the reader
+    /// configuration.
+    ///
+    /// Options that require the value, like
+    /// [`deny_duplicates`](crate::ReaderConfigBuilder::deny_duplicates), are
+    /// ignored.
+    pub fn with_config(s: &'a str, config: &ReaderConfig) -> Self {
+        Self {
+            inner: StrReader::with_config(s, config.clone()),
+        }
+    }
+
+    /// The type of the next token, without consuming it.
+    ///
+    /// This is never [`TokenType::TextOrListStart`].
+    pub fn peek(&mut self) -> Result<TokenType> {
+        let token_type = match self.inner.peek()?.token {
+            Token::Text(_) => TokenType::Text,
+            Token::ListStart => TokenType::ListStart,
+            Token::ListEnd => TokenType::ListEnd,
+            Token::Eof => TokenType::Eof,
+        };
+        Ok(token_type)
+    }
+
+    /// The location of the next token.
+    pub fn location(&self) -> Location {
+        self.inner.location()
+    }
+
+    /// Read an integer.
+    pub fn read_i32(&mut self) -> Result<i32> {
+        self.inner.read_i32()
+    }
+
+    /// Read a float.
+    pub fn read_f32(&mut self) -> Result<f32> {
+        self.inner.read_f32()
+    }
+
+    /// Read a string.
+    pub fn read_string(&mut self) -> Result<String> {
+        self.inner.read_string()
+    }
+
+    /// Read the start of a list.
+    pub fn read_list_start(&mut self) -> Result<()> {
+        self.inner.read_list_start()
+    }
+
+    /// Read the end of a list.
+    pub fn read_list_end(&mut self) -> Result<()> {
+        self.inner.read_list_end()
+    }
+
+    /// Read a list, with the closure reading the list's items.
+    ///
+    /// The closure must read all items, otherwise reading the end of the
+    /// list fails.
+    pub fn read_list<F, V>(&mut self, f: F) -> Result<V>
+    where
+        F: FnOnce(&mut Reader<'a>) -> Result<V>,
+    {
+        self.read_list_start()?;
+        let v = f(self)?;
+        self.read_list_end()?;
+        Ok(v)
+    }
+
+    /// Check the end of the data has been reached.
+    pub fn finish(self) -> Result<()> {
+        self.inner.finish()
+    }
+}
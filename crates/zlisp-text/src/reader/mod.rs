@@ -1,6 +1,18 @@
+mod config;
+mod events;
+mod low_level;
 mod parse;
 mod str_reader;
+mod stream;
 mod tokenizer;
+mod tokens;
+
+pub use config::{NonFinitePolicy, ReaderConfig, ReaderConfigBuilder};
+pub use events::{events, events_with_config, Event, Events};
+pub use low_level::Reader;
+pub(crate) use parse::is_number;
+pub use stream::{Deserializer, StreamDeserializer};
+pub use tokens::{tokenize, tokenize_with_config, Token, Tokens};
 
 use crate::error::Result;
 
@@ -9,8 +21,39 @@ pub fn from_str<'a, T>(s: &'a str) -> Result<T>
 where
     T: serde::Deserialize<'a>,
 {
-    let mut reader = str_reader::StrReader::new(s);
+    from_str_with_config(s, ReaderConfig::default())
+}
+
+/// Deserialize a value from text zlisp data, using the reader configuration.
+pub fn from_str_with_config<'a, T>(s: &'a str, config: &ReaderConfig) -> Result<T>
+where
+    T: serde::Deserialize<'a>,
+{
+    let mut reader = str_reader::StrReader::with_config(s, config.clone());
     let v = T::deserialize(&mut reader)?;
     reader.finish()?;
     Ok(v)
 }
+
+/// Deserialize a value from text zlisp data, with the source line in errors.
+///
+/// This is the same as [`from_str`], except errors also display the line of
+/// the data the error occurred in, with a caret under the column. See
+/// [`Error::snippet`](crate::Error::snippet).
+pub fn from_str_verbose<'a, T>(s: &'a str) -> Result<T>
+where
+    T: serde::Deserialize<'a>,
+{
+    from_str(s).map_err(|e| e.attach_snippet(s))
+}
+
+/// Deserialize multiple values from text zlisp data, as separate records.
+///
+/// The values can be separated by any whitespace, as written by
+/// [`to_pretty_many`](crate::to_pretty_many). Empty data has no values.
+pub fn from_str_many<'a, T>(s: &'a str) -> Result<Vec<T>>
+where
+    T: serde::Deserialize<'a>,
+{
+    Deserializer::from_str(s).into_iter().collect()
+}
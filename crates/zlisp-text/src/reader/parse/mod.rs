@@ -1,6 +1,8 @@
 use super::tokenizer::{Span, Text, Token};
 use crate::error::{Error, ErrorCode, Location, Result, TokenType};
-use std::num::ParseFloatError;
+use crate::reader::config::NonFinitePolicy;
+use std::num::{IntErrorKind, ParseFloatError};
+use zlisp_hex::Hex;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Any {
@@ -10,11 +12,11 @@ pub enum Any {
     ListStart,
 }
 
-fn parse_i32_inner<'a>(s: &'a str, loc: Location) -> Result<i32> {
-    // i32::from_str_radix does exactly what we want. it allows signs (- or +),
-    // it does not allow empty strings, or just the sign. and it only allows
+fn parse_i32_inner(s: &str, loc: Location) -> Result<i32> {
+    // parsing an i32 does exactly what we want. it allows signs (- or +), it
+    // does not allow empty strings, or just the sign. and it only allows
     // digits other than the sign.
-    i32::from_str_radix(s, 10).map_err(|e| {
+    s.parse::<i32>().map_err(|e| {
         let code = ErrorCode::ParseIntError {
             e,
             s: s.to_string(),
@@ -23,18 +25,35 @@ fn parse_i32_inner<'a>(s: &'a str, loc: Location) -> Result<i32> {
     })
 }
 
-/// hack to construct a new ParseFloatError
+fn parse_i32_saturating(s: &str, loc: Location) -> Result<i32> {
+    // only out-of-range integers are clamped, anything else is still invalid
+    parse_i32_inner(s, loc).or_else(|err| match err.code() {
+        ErrorCode::ParseIntError { e, .. } => match e.kind() {
+            IntErrorKind::PosOverflow => Ok(i32::MAX),
+            IntErrorKind::NegOverflow => Ok(i32::MIN),
+            _ => Err(err),
+        },
+        _ => Err(err),
+    })
+}
 
+/// hack to construct a new ParseFloatError
 fn pfe_invalid() -> ParseFloatError {
     "-".parse::<f32>().unwrap_err()
 }
 
-fn float_invalid<'a>(e: ParseFloatError, s: &'a str, loc: Location) -> Error {
+fn float_invalid(e: ParseFloatError, s: &str, loc: Location) -> Error {
     let code = ErrorCode::ParseFloatError { e, s: s.to_owned() };
     Error::new(code, Some(loc))
 }
 
-fn parse_f32_inner<'a>(s: &'a str, loc: Location) -> Result<f32> {
+fn is_non_finite_literal(v: &[u8]) -> bool {
+    v.eq_ignore_ascii_case(b"inf")
+        || v.eq_ignore_ascii_case(b"infinity")
+        || v.eq_ignore_ascii_case(b"nan")
+}
+
+fn parse_f32_inner(s: &str, loc: Location, non_finite: NonFinitePolicy) -> Result<f32> {
     // first, parsing floats is hard, see the core `dec2flt` module.
     // unfortunately, Rust's float parsing allows for exponent forms (e.g.
     // '2.5e10'), and non-finite values (e.g. 'inf', '-inf', '+infinity',
@@ -48,7 +67,8 @@ fn parse_f32_inner<'a>(s: &'a str, loc: Location) -> Result<f32> {
     // the strategy then is to first validate the input, before using Rust's
     // built-in parsing, and finally verifying the parsing.
 
-    // validate the input. this ensures we reject exponent and non-finite forms
+    // validate the input. this ensures we reject exponent forms, and
+    // non-finite forms unless the policy maps them to finite values
     let mut v = s.as_bytes();
     match v.first() {
         // skip the sign
@@ -58,14 +78,17 @@ fn parse_f32_inner<'a>(s: &'a str, loc: Location) -> Result<f32> {
         // don't care about an empty input, the float parsing handles this
         None => (),
     }
-    let mut seen_point = false;
-    for c in v.iter() {
-        match c {
-            // '.' can only appear once
-            b'.' if !seen_point => seen_point = true,
-            // digits can appear wherever
-            b'0'..=b'9' => (),
-            _ => return Err(float_invalid(pfe_invalid(), s, loc)),
+    let lenient = non_finite != NonFinitePolicy::Reject;
+    if !(lenient && is_non_finite_literal(v)) {
+        let mut seen_point = false;
+        for c in v.iter() {
+            match c {
+                // '.' can only appear once
+                b'.' if !seen_point => seen_point = true,
+                // digits can appear wherever
+                b'0'..=b'9' => (),
+                _ => return Err(float_invalid(pfe_invalid(), s, loc)),
+            }
         }
     }
 
@@ -74,65 +97,138 @@ fn parse_f32_inner<'a>(s: &'a str, loc: Location) -> Result<f32> {
             // annoyingly, parsing a float allows +inf, -inf, and NaN, which can happen
             // if the float is too big for f32
             if f.is_finite() {
-                Ok(f)
-            } else {
-                Err(pfe_invalid())
+                return Ok(f);
+            }
+            match non_finite {
+                NonFinitePolicy::Reject => Err(pfe_invalid()),
+                NonFinitePolicy::Saturate if f.is_nan() => Err(pfe_invalid()),
+                NonFinitePolicy::Saturate if f.is_sign_negative() => Ok(f32::MIN),
+                NonFinitePolicy::Saturate => Ok(f32::MAX),
+                NonFinitePolicy::Zero => Ok(0.0),
             }
         })
         .map_err(|e| float_invalid(e, s, loc))
 }
 
-fn parse_any_inner<'a>(s: &'a str, loc: Location) -> Result<Any> {
+/// Whether unquoted text would be read as a number, instead of a string.
+pub(crate) fn is_number(s: &str) -> bool {
+    let loc = Location::new(1, 1);
+    parse_i32_inner(s, loc.clone()).is_ok()
+        || parse_f32_inner(s, loc, NonFinitePolicy::Reject).is_ok()
+}
+
+fn parse_any_inner(s: &str, loc: Location, hex_ints: bool) -> Result<Any> {
     if let Ok(v) = parse_i32_inner(s, loc.clone()) {
         return Ok(Any::Int(v));
     }
-    if let Ok(v) = parse_f32_inner(s, loc) {
+    if hex_ints {
+        if let Ok(v) = Hex::try_from(s) {
+            return Ok(Any::Int(v.into()));
+        }
+    }
+    // non-finite literals are ambiguous with strings, so are never floats
+    if let Ok(v) = parse_f32_inner(s, loc, NonFinitePolicy::Reject) {
         return Ok(Any::Float(v));
     }
     Ok(Any::String(s.to_owned()))
 }
 
-pub fn parse_i32<'a>(span: Span<'a>) -> Result<i32> {
+pub fn parse_i32<'a>(span: Span<'a>, saturate: bool) -> Result<i32> {
+    let parse = if saturate {
+        parse_i32_saturating
+    } else {
+        parse_i32_inner
+    };
+    match span.token {
+        Token::Text(text) => match text {
+            Text::Quoted(_) => {
+                let code = ErrorCode::QuotedString;
+                Err(Error::new(code, Some(span.loc)))
+            }
+            Text::Unquoted(s) => parse(s, span.loc),
+            Text::Escaped(s) => parse(&s, span.loc),
+        },
+        _ => Err(span.expected(TokenType::Text)),
+    }
+}
+
+pub fn parse_f32<'a>(span: Span<'a>, non_finite: NonFinitePolicy) -> Result<f32> {
     match span.token {
         Token::Text(text) => match text {
             Text::Quoted(_) => {
                 let code = ErrorCode::QuotedString;
                 Err(Error::new(code, Some(span.loc)))
             }
-            Text::Unquoted(s) => parse_i32_inner(s, span.loc),
+            Text::Unquoted(s) => parse_f32_inner(s, span.loc, non_finite),
+            Text::Escaped(s) => parse_f32_inner(&s, span.loc, non_finite),
         },
         _ => Err(span.expected(TokenType::Text)),
     }
 }
 
-pub fn parse_f32<'a>(span: Span<'a>) -> Result<f32> {
+fn parse_bool_inner(s: &str, loc: Location) -> Result<bool> {
+    if s.eq_ignore_ascii_case("true") {
+        Ok(true)
+    } else if s.eq_ignore_ascii_case("false") {
+        Ok(false)
+    } else {
+        let code = ErrorCode::ParseBoolError { s: s.to_owned() };
+        Err(Error::new(code, Some(loc)))
+    }
+}
+
+pub fn parse_bool<'a>(span: Span<'a>) -> Result<bool> {
     match span.token {
         Token::Text(text) => match text {
             Text::Quoted(_) => {
                 let code = ErrorCode::QuotedString;
                 Err(Error::new(code, Some(span.loc)))
             }
-            Text::Unquoted(s) => parse_f32_inner(s, span.loc),
+            Text::Unquoted(s) => parse_bool_inner(s, span.loc),
+            Text::Escaped(s) => parse_bool_inner(&s, span.loc),
         },
         _ => Err(span.expected(TokenType::Text)),
     }
 }
 
+pub fn parse_char<'a>(span: Span<'a>) -> Result<char> {
+    let loc = span.loc.clone();
+    let s = parse_string(span)?;
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => {
+            let code = ErrorCode::ParseCharError { s };
+            Err(Error::new(code, Some(loc)))
+        }
+    }
+}
+
 pub fn parse_string<'a>(span: Span<'a>) -> Result<String> {
     match span.token {
         Token::Text(text) => match text {
             Text::Quoted(s) => Ok(s),
             Text::Unquoted(s) => Ok(s.to_owned()),
+            Text::Escaped(s) => Ok(s),
         },
         _ => Err(span.expected(TokenType::Text)),
     }
 }
 
-pub fn parse_any<'a>(span: Span<'a>) -> Result<Any> {
+pub fn parse_text<'a>(span: Span<'a>) -> Result<Text<'a>> {
+    match span.token {
+        Token::Text(text) => Ok(text),
+        _ => Err(span.expected(TokenType::Text)),
+    }
+}
+
+pub fn parse_any<'a>(span: Span<'a>, hex_ints: bool) -> Result<Any> {
     match span.token {
         Token::Text(text) => match text {
             Text::Quoted(s) => Ok(Any::String(s)),
-            Text::Unquoted(s) => parse_any_inner(s, span.loc),
+            Text::Unquoted(s) => parse_any_inner(s, span.loc, hex_ints),
+            // an escaped parenthesis can't be part of a number
+            Text::Escaped(s) => Ok(Any::String(s)),
         },
         Token::ListStart => Ok(Any::ListStart),
         _ => Err(span.expected(TokenType::TextOrListStart)),
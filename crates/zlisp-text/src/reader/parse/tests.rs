@@ -27,7 +27,7 @@ macro_rules! assert_i32_err {
 
 macro_rules! assert_f32_ok {
     ($s:expr, $expected:expr) => {
-        let actual = parse_f32_inner($s, Location::new(1, 1)).unwrap();
+        let actual = parse_f32_inner($s, Location::new(1, 1), NonFinitePolicy::Reject).unwrap();
         assert_eq!(actual, $expected);
     };
 }
@@ -35,7 +35,7 @@ macro_rules! assert_f32_ok {
 macro_rules! assert_f32_err {
     ($s:expr) => {
         let loc = Location::new(1, 1);
-        let err = parse_f32_inner($s, loc.clone()).unwrap_err();
+        let err = parse_f32_inner($s, loc.clone(), NonFinitePolicy::Reject).unwrap_err();
         assert_eq!(err.location(), Some(loc).as_ref());
         assert_matches!(err.code(), ErrorCode::ParseFloatError {
             e: _,
@@ -74,6 +74,40 @@ fn i32_tests() {
     assert_i32_err!(&under_s);
 }
 
+#[test]
+fn i32_saturating_tests() {
+    let loc = Location::new(1, 1);
+    let max_s = format!("{}", i32::MAX);
+    assert_eq!(parse_i32_saturating(&max_s, loc.clone()).unwrap(), i32::MAX);
+    let min_s = format!("{}", i32::MIN);
+    assert_eq!(parse_i32_saturating(&min_s, loc.clone()).unwrap(), i32::MIN);
+
+    // overflow
+    let over_s = format!("{}", (i32::MAX as i64) + 1);
+    assert_eq!(
+        parse_i32_saturating(&over_s, loc.clone()).unwrap(),
+        i32::MAX
+    );
+    let under_s = format!("{}", (i32::MIN as i64) - 1);
+    assert_eq!(
+        parse_i32_saturating(&under_s, loc.clone()).unwrap(),
+        i32::MIN
+    );
+    assert_eq!(
+        parse_i32_saturating("99999999999", loc.clone()).unwrap(),
+        i32::MAX
+    );
+    assert_eq!(
+        parse_i32_saturating("-99999999999", loc.clone()).unwrap(),
+        i32::MIN
+    );
+
+    // empty and invalid are still errors
+    parse_i32_saturating("", loc.clone()).unwrap_err();
+    parse_i32_saturating("-", loc.clone()).unwrap_err();
+    parse_i32_saturating("a", loc).unwrap_err();
+}
+
 #[test]
 fn f32_tests() {
     assert_f32_ok!("0", 0.0);
@@ -143,3 +177,37 @@ fn f32_tests() {
     let under_s = format!("{:.1}", f64::MIN);
     assert_f32_err!(&under_s);
 }
+
+#[test]
+fn f32_non_finite_tests() {
+    let loc = Location::new(1, 1);
+    let over_s = format!("{:.1}", f64::MAX);
+    let under_s = format!("{:.1}", f64::MIN);
+
+    let policy = NonFinitePolicy::Saturate;
+    let parse = |s: &str| parse_f32_inner(s, loc.clone(), policy);
+    assert_eq!(parse("inf").unwrap(), f32::MAX);
+    assert_eq!(parse("+Infinity").unwrap(), f32::MAX);
+    assert_eq!(parse("-inf").unwrap(), f32::MIN);
+    assert_eq!(parse(&over_s).unwrap(), f32::MAX);
+    assert_eq!(parse(&under_s).unwrap(), f32::MIN);
+    parse("NaN").unwrap_err();
+
+    let policy = NonFinitePolicy::Zero;
+    let parse = |s: &str| parse_f32_inner(s, loc.clone(), policy);
+    assert_eq!(parse("inf").unwrap(), 0.0);
+    assert_eq!(parse("-INF").unwrap(), 0.0);
+    assert_eq!(parse("NaN").unwrap(), 0.0);
+    assert_eq!(parse(&over_s).unwrap(), 0.0);
+
+    // only the non-finite literals are accepted, not other forms
+    for policy in [NonFinitePolicy::Saturate, NonFinitePolicy::Zero] {
+        let parse = |s: &str| parse_f32_inner(s, loc.clone(), policy);
+        parse("1.0").unwrap();
+        parse("in").unwrap_err();
+        parse("infinit").unwrap_err();
+        parse("--inf").unwrap_err();
+        parse("1e5").unwrap_err();
+        parse("").unwrap_err();
+    }
+}
@@ -0,0 +1,128 @@
+use crate::error::{Error, Location, Result};
+use serde::de::{self, Visitor};
+
+/// A value buffered while reading a list, so the list can be inspected
+/// before it is visited.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Content {
+    Int(i32),
+    Float(f32),
+    String(String),
+    List(Vec<Element>),
+}
+
+/// A buffered element of a list, and where it was read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Element {
+    pub loc: Location,
+    pub content: Content,
+}
+
+/// Whether the elements are alternating string keys and values.
+///
+/// Empty lists are ambiguous, and never considered keyed.
+fn is_string_keyed(elements: &[Element]) -> bool {
+    !elements.is_empty()
+        && elements.len().is_multiple_of(2)
+        && elements
+            .iter()
+            .step_by(2)
+            .all(|element| matches!(element.content, Content::String(_)))
+}
+
+/// Visit the elements of a list as a map if they are keyed, otherwise as a
+/// sequence.
+pub fn visit_list<'de, V>(elements: Vec<Element>, visitor: V) -> Result<V::Value>
+where
+    V: Visitor<'de>,
+{
+    if is_string_keyed(&elements) {
+        visitor.visit_map(ElementAccess {
+            elements: elements.into_iter(),
+        })
+    } else {
+        visitor.visit_seq(ElementAccess {
+            elements: elements.into_iter(),
+        })
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Content {
+    type Error = Error;
+
+    fn is_human_readable(&self) -> bool {
+        true
+    }
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self {
+            Self::Int(v) => visitor.visit_i32(v),
+            Self::Float(v) => visitor.visit_f32(v),
+            Self::String(v) => visitor.visit_string(v),
+            Self::List(elements) => visit_list(elements, visitor),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct ElementAccess {
+    elements: std::vec::IntoIter<Element>,
+}
+
+impl<'de> de::SeqAccess<'de> for ElementAccess {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        match self.elements.next() {
+            Some(Element { loc, content }) => seed
+                .deserialize(content)
+                .map(Some)
+                .map_err(|e| e.attach_location(loc)),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.elements.len())
+    }
+}
+
+impl<'de> de::MapAccess<'de> for ElementAccess {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        // the elements were checked to be keyed before visiting
+        de::SeqAccess::next_element_seed(self, seed)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        match self.elements.next() {
+            Some(Element { loc, content }) => seed
+                .deserialize(content)
+                .map_err(|e| e.attach_location(loc)),
+            // the elements were checked to be of even length before visiting
+            None => Err(de::Error::custom("expected a value")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.elements.len() / 2)
+    }
+}
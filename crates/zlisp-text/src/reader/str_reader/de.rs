@@ -1,8 +1,11 @@
-use super::StrReader;
-use crate::error::{Error, ErrorCode, Result};
+use super::content::visit_list;
+use super::{RawToken, StrReader};
+use crate::error::{Error, ErrorCode, Location, Result};
 use crate::reader::parse::Any;
-use crate::reader::tokenizer::Token;
-use serde::de::{self, Deserializer as _, Visitor};
+use crate::reader::tokenizer::{Text, Token};
+use crate::spanned;
+use serde::de::{self, Deserializer as _, IntoDeserializer as _, Visitor};
+use std::collections::HashSet;
 
 macro_rules! unsupported {
     ($method:ident) => {
@@ -25,7 +28,6 @@ impl<'a, 'de: 'a> de::Deserializer<'de> for &'a mut StrReader<'de> {
         true
     }
 
-    unsupported!(deserialize_bool);
     unsupported!(deserialize_i8);
     unsupported!(deserialize_i16);
     unsupported!(deserialize_i64);
@@ -34,10 +36,8 @@ impl<'a, 'de: 'a> de::Deserializer<'de> for &'a mut StrReader<'de> {
     unsupported!(deserialize_u32);
     unsupported!(deserialize_u64);
     unsupported!(deserialize_f64);
-    unsupported!(deserialize_char);
     unsupported!(deserialize_bytes);
     unsupported!(deserialize_byte_buf);
-    unsupported!(deserialize_str);
 
     fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
     where
@@ -47,6 +47,9 @@ impl<'a, 'de: 'a> de::Deserializer<'de> for &'a mut StrReader<'de> {
             Any::Int(v) => visitor.visit_i32(v),
             Any::Float(v) => visitor.visit_f32(v),
             Any::String(v) => visitor.visit_string(v),
+            // whether a list is a map depends on all its elements, so they
+            // are buffered before visiting
+            Any::ListStart if self.infer_maps() => visit_list(self.read_elements()?, visitor),
             Any::ListStart => {
                 let v = visitor.visit_seq(UnsizedSeqAccess { deserializer: self })?;
                 self.read_list_end()?;
@@ -55,6 +58,13 @@ impl<'a, 'de: 'a> de::Deserializer<'de> for &'a mut StrReader<'de> {
         }
     }
 
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_bool(self.read_bool()?)
+    }
+
     fn deserialize_i32<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
@@ -69,6 +79,25 @@ impl<'a, 'de: 'a> de::Deserializer<'de> for &'a mut StrReader<'de> {
         visitor.visit_f32(self.read_f32()?)
     }
 
+    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_char(self.read_char()?)
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        // only unquoted text can be borrowed, since quoted or escaped text is
+        // not contiguous in the input.
+        match self.read_text()? {
+            Text::Unquoted(v) => visitor.visit_borrowed_str(v),
+            Text::Quoted(v) | Text::Escaped(v) => visitor.visit_string(v),
+        }
+    }
+
     fn deserialize_string<V>(self, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
@@ -116,7 +145,16 @@ impl<'a, 'de: 'a> de::Deserializer<'de> for &'a mut StrReader<'de> {
     where
         V: Visitor<'de>,
     {
-        self.read_list(|deserializer| visitor.visit_seq(UnsizedSeqAccess { deserializer }))
+        if self.deny_duplicates() {
+            self.read_list(|deserializer| {
+                visitor.visit_seq(UniqueSeqAccess {
+                    deserializer,
+                    seen: HashSet::new(),
+                })
+            })
+        } else {
+            self.read_list(|deserializer| visitor.visit_seq(UnsizedSeqAccess { deserializer }))
+        }
     }
 
     fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value>
@@ -147,13 +185,23 @@ impl<'a, 'de: 'a> de::Deserializer<'de> for &'a mut StrReader<'de> {
 
     fn deserialize_struct<V>(
         self,
-        _name: &'static str,
-        _fields: &'static [&'static str],
+        name: &'static str,
+        fields: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value>
     where
         V: Visitor<'de>,
     {
+        if name == spanned::NAME && fields == spanned::FIELDS {
+            // peek to skip any whitespace before the value
+            let _ = self.peek()?;
+            let start = self.location();
+            return visitor.visit_map(SpannedAccess {
+                deserializer: self,
+                start,
+                index: 0,
+            });
+        }
         self.deserialize_map(visitor)
     }
 
@@ -245,6 +293,43 @@ impl<'a, 'de: 'a> de::SeqAccess<'de> for UnsizedSeqAccess<'a, 'de> {
     }
 }
 
+struct UniqueSeqAccess<'a, 'de> {
+    deserializer: &'a mut StrReader<'de>,
+    seen: HashSet<Vec<RawToken>>,
+}
+
+impl<'a, 'de: 'a> de::SeqAccess<'de> for UniqueSeqAccess<'a, 'de> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        let span = self.deserializer.peek()?;
+        match span.token {
+            // list start could be part of the interior type
+            Token::Text(_) | Token::ListStart => {
+                let loc = self.deserializer.location();
+                let (v, tokens) = self
+                    .deserializer
+                    .recorded(|de| seed.deserialize(de))
+                    .map_err(|e| e.attach_location(loc.clone()))?;
+                if self.seen.insert(tokens) {
+                    Ok(Some(v))
+                } else {
+                    Err(Error::new(ErrorCode::DuplicateElement, Some(loc)))
+                }
+            }
+            // see UnsizedSeqAccess
+            Token::ListEnd | Token::Eof => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        None
+    }
+}
+
 impl<'a, 'de: 'a> de::MapAccess<'de> for UnsizedSeqAccess<'a, 'de> {
     type Error = Error;
 
@@ -282,6 +367,54 @@ impl<'a, 'de: 'a> de::MapAccess<'de> for UnsizedSeqAccess<'a, 'de> {
     }
 }
 
+struct SpannedAccess<'a, 'de> {
+    deserializer: &'a mut StrReader<'de>,
+    start: Location,
+    index: usize,
+}
+
+impl<'a, 'de: 'a> de::MapAccess<'de> for SpannedAccess<'a, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        match spanned::FIELDS.get(self.index) {
+            Some(field) => seed
+                .deserialize(de::value::BorrowedStrDeserializer::new(field))
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        // the fields are always visited in order, so the end location is
+        // known once the value has been deserialized.
+        let index = self.index;
+        self.index += 1;
+        match index {
+            0 => seed.deserialize(self.start.line().into_deserializer()),
+            1 => seed.deserialize(self.start.column().into_deserializer()),
+            2 => {
+                let loc = self.start.clone();
+                seed.deserialize(&mut *self.deserializer)
+                    .map_err(|e| e.attach_location(loc))
+            }
+            3 => seed.deserialize(self.deserializer.end_location().line().into_deserializer()),
+            _ => seed.deserialize(
+                self.deserializer
+                    .end_location()
+                    .column()
+                    .into_deserializer(),
+            ),
+        }
+    }
+}
+
 impl<'a, 'de: 'a> de::EnumAccess<'de> for &'a mut StrReader<'de> {
     type Error = Error;
     type Variant = Self;
@@ -1,4 +1,5 @@
+mod content;
 mod de;
 mod private;
 
-pub use private::StrReader;
+pub use private::{RawToken, StrReader};
@@ -1,67 +1,244 @@
-use crate::error::{Location, Result, TokenType};
-use crate::reader::parse::{parse_any, parse_f32, parse_i32, parse_string, Any};
-use crate::reader::tokenizer::{Span, Token, Tokenizer};
+use super::content::{Content, Element};
+use crate::error::{Error, ErrorCode, Location, Result, TokenType};
+use crate::reader::config::ReaderConfig;
+use crate::reader::parse::{
+    parse_any, parse_bool, parse_char, parse_f32, parse_i32, parse_string, parse_text, Any,
+};
+use crate::reader::tokenizer::{Span, Text, Token, Tokenizer};
+
+/// A token as it was deserialized, to compare elements by value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RawToken {
+    Int(i32),
+    /// The bits of the float.
+    Float(u32),
+    Bool(bool),
+    Text(String),
+    ListStart,
+    ListEnd,
+}
 
 #[derive(Debug, Clone)]
 pub struct StrReader<'a> {
     inner: Tokenizer<'a>,
-    buffer: Option<Span<'a>>,
+    /// A peeked span, and the location after it.
+    buffer: Option<(Span<'a>, Location)>,
+    /// The location after the last consumed span.
+    end: Location,
+    config: ReaderConfig,
+    elements: usize,
+    /// The nesting depth of lists after the last consumed span.
+    depth: usize,
+    /// The tokens deserialized while recording.
+    recording: Option<Vec<RawToken>>,
 }
 
 impl<'a> StrReader<'a> {
+    #[cfg(test)]
     pub const fn new(input: &'a str) -> Self {
+        Self::with_config(input, ReaderConfig::DEFAULT)
+    }
+
+    pub const fn with_config(input: &'a str, config: ReaderConfig) -> Self {
         Self {
-            inner: Tokenizer::new(input),
+            inner: Tokenizer::new(input, &config),
             buffer: None,
+            end: Location::new(1, 1),
+            config,
+            elements: 0,
+            depth: 0,
+            recording: None,
         }
     }
 
     fn next_span(&mut self) -> Result<Span<'a>> {
-        if let Some(span) = self.buffer.take() {
-            Ok(span)
+        let (span, end) = if let Some(buffered) = self.buffer.take() {
+            buffered
         } else {
-            self.inner.read_token()
+            let span = self.inner.read_token()?;
+            (span, self.inner.location())
+        };
+        self.end = end;
+        // peeking does not count, only consuming scalars or list starts does
+        match span.token {
+            Token::Text(_) => self.count_element(&span)?,
+            Token::ListStart => {
+                self.count_element(&span)?;
+                self.enter_list(&span)?;
+            }
+            // unbalanced list ends are reported by the caller
+            Token::ListEnd => self.depth = self.depth.saturating_sub(1),
+            Token::Eof => {}
+        }
+        Ok(span)
+    }
+
+    fn enter_list(&mut self, span: &Span<'a>) -> Result<()> {
+        self.depth += 1;
+        if self.depth > self.config.max_depth {
+            Err(Error::new(
+                ErrorCode::RecursionLimitExceeded,
+                Some(span.loc.clone()),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn count_element(&mut self, span: &Span<'a>) -> Result<()> {
+        self.elements += 1;
+        match self.config.max_elements {
+            Some(max) if self.elements > max => Err(Error::new(
+                ErrorCode::ElementLimitExceeded,
+                Some(span.loc.clone()),
+            )),
+            _ => Ok(()),
         }
     }
 
     pub fn peek(&mut self) -> Result<Span<'a>> {
-        if let Some(span) = self.buffer.as_ref() {
+        if let Some((span, _end)) = self.buffer.as_ref() {
             Ok(span.clone())
         } else {
             let span = self.inner.read_token()?;
-            self.buffer = Some(span.clone());
+            let end = self.inner.location();
+            self.buffer = Some((span.clone(), end));
             Ok(span)
         }
     }
 
     pub fn location(&self) -> Location {
-        if let Some(span) = self.buffer.as_ref() {
+        if let Some((span, _end)) = self.buffer.as_ref() {
             span.loc.clone()
         } else {
             self.inner.location()
         }
     }
 
+    /// The location after the last consumed token.
+    pub fn end_location(&self) -> Location {
+        self.end.clone()
+    }
+
+    /// Whether sequences may not contain duplicate elements.
+    pub fn deny_duplicates(&self) -> bool {
+        self.config.deny_duplicates
+    }
+
+    /// Record a token, if recording.
+    fn record<F>(&mut self, token: F)
+    where
+        F: FnOnce() -> RawToken,
+    {
+        if let Some(recording) = self.recording.as_mut() {
+            recording.push(token());
+        }
+    }
+
+    /// Read an element, and return the tokens as they were deserialized.
+    ///
+    /// Scalars are recorded after parsing, so elements which deserialize to
+    /// the same value compare equal, regardless of whitespace, quoting, or
+    /// how numbers are written (e.g. `1`, `01`, and `+1`).
+    pub fn recorded<T, F>(&mut self, f: F) -> Result<(T, Vec<RawToken>)>
+    where
+        F: FnOnce(&mut Self) -> Result<T>,
+    {
+        // nested elements are recorded as part of the outer element
+        let start = self.recording.as_ref().map(Vec::len);
+        if start.is_none() {
+            self.recording = Some(Vec::new());
+        }
+        let result = f(self);
+        let tokens = match start {
+            Some(start) => self
+                .recording
+                .as_ref()
+                .map_or_else(Vec::new, |r| r[start..].to_vec()),
+            None => self.recording.take().unwrap_or_default(),
+        };
+        result.map(|v| (v, tokens))
+    }
+
     pub fn read_i32(&mut self) -> Result<i32> {
-        self.next_span().and_then(parse_i32)
+        let saturate = self.config.saturate_ints;
+        let v = self
+            .next_span()
+            .and_then(|span| parse_i32(span, saturate))?;
+        self.record(|| RawToken::Int(v));
+        Ok(v)
     }
 
     pub fn read_f32(&mut self) -> Result<f32> {
-        self.next_span().and_then(parse_f32)
+        let non_finite = self.config.non_finite;
+        let v = self
+            .next_span()
+            .and_then(|span| parse_f32(span, non_finite))?;
+        self.record(|| RawToken::Float(v.to_bits()));
+        Ok(v)
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool> {
+        if !self.config.bools {
+            return Err(Error::new(
+                ErrorCode::UnsupportedType,
+                Some(self.location()),
+            ));
+        }
+        let v = self.next_span().and_then(parse_bool)?;
+        self.record(|| RawToken::Bool(v));
+        Ok(v)
+    }
+
+    pub fn read_char(&mut self) -> Result<char> {
+        if !self.config.chars {
+            return Err(Error::new(
+                ErrorCode::UnsupportedType,
+                Some(self.location()),
+            ));
+        }
+        let v = self.next_span().and_then(parse_char)?;
+        self.record(|| RawToken::Text(v.to_string()));
+        Ok(v)
     }
 
     pub fn read_string(&mut self) -> Result<String> {
-        self.next_span().and_then(parse_string)
+        let v = self.next_span().and_then(parse_string)?;
+        self.record(|| RawToken::Text(v.clone()));
+        Ok(v)
+    }
+
+    /// Read text, borrowing it from the input if possible.
+    pub fn read_text(&mut self) -> Result<Text<'a>> {
+        let v = self.next_span().and_then(parse_text)?;
+        self.record(|| match &v {
+            Text::Unquoted(s) => RawToken::Text((*s).to_owned()),
+            Text::Quoted(s) | Text::Escaped(s) => RawToken::Text(s.clone()),
+        });
+        Ok(v)
     }
 
     pub fn read_any(&mut self) -> Result<Any> {
-        self.next_span().and_then(parse_any)
+        let hex_ints = self.config.hex_ints;
+        let v = self
+            .next_span()
+            .and_then(|span| parse_any(span, hex_ints))?;
+        self.record(|| match &v {
+            Any::Int(v) => RawToken::Int(*v),
+            Any::Float(v) => RawToken::Float(v.to_bits()),
+            Any::String(v) => RawToken::Text(v.clone()),
+            Any::ListStart => RawToken::ListStart,
+        });
+        Ok(v)
     }
 
     pub fn read_list_start(&mut self) -> Result<()> {
         let span = self.next_span()?;
         match span.token {
-            Token::ListStart => Ok(()),
+            Token::ListStart => {
+                self.record(|| RawToken::ListStart);
+                Ok(())
+            }
             _ => Err(span.expected(TokenType::ListStart)),
         }
     }
@@ -69,11 +246,38 @@ impl<'a> StrReader<'a> {
     pub fn read_list_end(&mut self) -> Result<()> {
         let span = self.next_span()?;
         match span.token {
-            Token::ListEnd => Ok(()),
+            Token::ListEnd => {
+                self.record(|| RawToken::ListEnd);
+                Ok(())
+            }
             _ => Err(span.expected(TokenType::ListEnd)),
         }
     }
 
+    /// Whether reading lists of string keys and values as maps is enabled.
+    pub fn infer_maps(&self) -> bool {
+        self.config.infer_maps
+    }
+
+    /// Read the rest of a list after the list start, buffering the elements
+    /// so the list can be inspected before it is visited.
+    pub fn read_elements(&mut self) -> Result<Vec<Element>> {
+        let mut elements = Vec::new();
+        // eof is reported when looking for the list end
+        while !matches!(self.peek()?.token, Token::ListEnd | Token::Eof) {
+            let loc = self.location();
+            let content = match self.read_any()? {
+                Any::Int(v) => Content::Int(v),
+                Any::Float(v) => Content::Float(v),
+                Any::String(v) => Content::String(v),
+                Any::ListStart => Content::List(self.read_elements()?),
+            };
+            elements.push(Element { loc, content });
+        }
+        self.read_list_end()?;
+        Ok(elements)
+    }
+
     pub fn read_list<F, V>(&mut self, f: F) -> Result<V>
     where
         F: FnOnce(&mut StrReader<'a>) -> Result<V>,
@@ -67,14 +67,16 @@ fn peek_does_not_modify_finish() {
             after.code(),
             ErrorCode::ExpectedToken {
                 expected: TokenType::Eof,
-                found: TokenType::ListStart
+                found: TokenType::ListStart,
+                text: None
             }
         );
         assert_matches!(
             before.code(),
             ErrorCode::ExpectedToken {
                 expected: TokenType::Eof,
-                found: TokenType::ListStart
+                found: TokenType::ListStart,
+                text: None
             }
         );
         assert_eq!(after.location(), before.location());
@@ -92,14 +94,16 @@ fn peek_does_not_modify_finish() {
             after.code(),
             ErrorCode::ExpectedToken {
                 expected: TokenType::Eof,
-                found: TokenType::ListEnd
+                found: TokenType::ListEnd,
+                text: None
             }
         );
         assert_matches!(
             before.code(),
             ErrorCode::ExpectedToken {
                 expected: TokenType::Eof,
-                found: TokenType::ListEnd
+                found: TokenType::ListEnd,
+                text: None
             }
         );
         assert_eq!(after.location(), before.location());
@@ -0,0 +1,101 @@
+use super::config::ReaderConfig;
+use super::str_reader::StrReader;
+use super::tokenizer::Token;
+use crate::error::{Location, Result};
+use std::marker::PhantomData;
+
+/// A deserializer for multiple consecutive values in text zlisp data.
+///
+/// This is useful for data with one record per line, for example. Use
+/// [`Deserializer::into_iter`] to read the values.
+#[derive(Debug, Clone)]
+pub struct Deserializer<'a> {
+    inner: StrReader<'a>,
+}
+
+impl<'a> Deserializer<'a> {
+    /// Construct a new deserializer over text zlisp data.
+    // named like `serde_json::Deserializer::from_str`
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &'a str) -> Self {
+        Self::with_config(s, ReaderConfig::default())
+    }
+
+    /// Construct a new deserializer over text zlisp data, using the reader
+    /// configuration.
+    pub fn with_config(s: &'a str, config: &ReaderConfig) -> Self {
+        Self {
+            inner: StrReader::with_config(s, config.clone()),
+        }
+    }
+
+    /// Turn the deserializer into an iterator over values of type `T`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn into_iter<T>(self) -> StreamDeserializer<'a, T>
+    where
+        T: serde::Deserialize<'a>,
+    {
+        StreamDeserializer {
+            inner: self.inner,
+            location: None,
+            failed: false,
+            output: PhantomData,
+        }
+    }
+}
+
+/// An iterator over consecutive values in text zlisp data.
+///
+/// The values can be separated by any whitespace. The iterator ends at the end
+/// of the data, or after the first error.
+///
+/// This cannot be constructed, use [`Deserializer::into_iter`].
+#[derive(Debug, Clone)]
+pub struct StreamDeserializer<'a, T> {
+    inner: StrReader<'a>,
+    location: Option<Location>,
+    failed: bool,
+    output: PhantomData<T>,
+}
+
+impl<'a, T> StreamDeserializer<'a, T> {
+    /// The location of the first token of the last value read.
+    ///
+    /// This is `None` before the first value is read.
+    pub const fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+
+    fn read(&mut self) -> Option<Result<T>>
+    where
+        T: serde::Deserialize<'a>,
+    {
+        let span = match self.inner.peek() {
+            Ok(span) => span,
+            Err(e) => return Some(Err(e)),
+        };
+        if matches!(span.token, Token::Eof) {
+            return None;
+        }
+        self.location = Some(span.loc);
+        Some(T::deserialize(&mut self.inner))
+    }
+}
+
+impl<'a, T> Iterator for StreamDeserializer<'a, T>
+where
+    T: serde::Deserialize<'a>,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let item = self.read();
+        if matches!(item, Some(Err(_))) {
+            self.failed = true;
+        }
+        item
+    }
+}
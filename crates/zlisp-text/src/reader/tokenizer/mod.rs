@@ -1,5 +1,6 @@
 use crate::ascii::from_raw;
 use crate::error::{Error, ErrorCode, Location, Result, TokenType};
+use crate::reader::config::ReaderConfig;
 
 /// A tokenizer for text zlisp data.
 ///
@@ -10,12 +11,26 @@ pub struct Tokenizer<'a> {
     input: &'a str,
     line: usize,
     col: usize,
+    paren_escape: bool,
+    quote_escape: bool,
+    separator: Option<char>,
+    line_comments: bool,
 }
 
 #[derive(Debug, Clone)]
 pub enum Text<'a> {
     Quoted(String),
     Unquoted(&'a str),
+    Escaped(String),
+}
+
+impl<'a> Text<'a> {
+    fn into_string(self) -> String {
+        match self {
+            Self::Quoted(s) | Self::Escaped(s) => s,
+            Self::Unquoted(s) => s.to_owned(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -38,26 +53,61 @@ impl<'a> Span<'a> {
     }
 
     pub fn expected(self, expected: TokenType) -> Error {
-        let found = match &self.token {
-            Token::Text(_) => TokenType::Text,
-            Token::ListStart => TokenType::ListStart,
-            Token::ListEnd => TokenType::ListEnd,
-            Token::Eof => TokenType::Eof,
+        let (found, text) = match self.token {
+            Token::Text(text) => (TokenType::Text, Some(text.into_string())),
+            Token::ListStart => (TokenType::ListStart, None),
+            Token::ListEnd => (TokenType::ListEnd, None),
+            Token::Eof => {
+                let code = ErrorCode::UnexpectedEof { expected };
+                return Error::new(code, Some(self.loc));
+            }
+        };
+        let code = ErrorCode::ExpectedToken {
+            expected,
+            found,
+            text,
         };
-        let code = ErrorCode::ExpectedToken { expected, found };
         Error::new(code, Some(self.loc))
     }
 }
 
+fn owned<'a>(buffer: String, quoted: bool) -> Text<'a> {
+    if quoted {
+        Text::Quoted(buffer)
+    } else {
+        Text::Escaped(buffer)
+    }
+}
+
 impl<'a> Tokenizer<'a> {
-    pub const fn new(input: &'a str) -> Self {
+    pub const fn new(input: &'a str, config: &ReaderConfig) -> Self {
         Self {
             input,
             line: 1,
-            col: 0,
+            col: 1,
+            paren_escape: config.paren_escape,
+            quote_escape: config.quote_escape,
+            separator: config.lenient_whitespace,
+            line_comments: config.line_comments,
         }
     }
 
+    fn is_separator(&self, c: char) -> bool {
+        self.separator == Some(c)
+    }
+
+    /// Whether the character ends unquoted text, i.e. whitespace, a
+    /// parenthesis, the lenient separator, or the start of a comment.
+    fn is_delimiter(&self, c: char) -> bool {
+        matches!(c, ' ' | '\t' | '\r' | '\n' | '(' | ')')
+            || self.is_separator(c)
+            || (self.line_comments && c == ';')
+    }
+
+    fn is_paren_escape(&self, rest: &str) -> bool {
+        self.paren_escape && (rest.starts_with("\\(") || rest.starts_with("\\)"))
+    }
+
     pub fn location(&self) -> Location {
         Location::new(self.line, self.col)
     }
@@ -65,10 +115,19 @@ impl<'a> Tokenizer<'a> {
     fn read_quoted_text(&mut self, start: &'a str) -> Result<(Text<'a>, &'a str)> {
         let str_loc = self.location();
         let mut buffer = String::new();
+        let mut quoted = false;
         let mut iter = start.char_indices();
         while let Some((o, c)) = iter.next() {
             match c {
+                // an escaped parenthesis outside a quote
+                '\\' if self.is_paren_escape(&start[o..]) => {
+                    // PANIC: the escape is followed by a parenthesis
+                    let (_o, c) = iter.next().unwrap();
+                    buffer.push(c);
+                    self.col += 2;
+                }
                 '"' => {
+                    quoted = true;
                     self.col += 1;
                     // inside a quote
                     loop {
@@ -83,16 +142,36 @@ impl<'a> Tokenizer<'a> {
                                 self.col += 1;
                                 break;
                             }
+                            '\\' if self.quote_escape => {
+                                let loc = self.location();
+                                let (_o, c) = iter.next().ok_or_else(|| {
+                                    Error::new(ErrorCode::EofWhileParsingQuote, Some(loc.clone()))
+                                })?;
+                                let c = match c {
+                                    '"' | '\\' => c,
+                                    'n' => '\n',
+                                    't' => '\t',
+                                    _ => {
+                                        return Err(Error::new(ErrorCode::InvalidEscape, Some(loc)))
+                                    }
+                                };
+                                self.col += 2;
+                                buffer.push(c);
+                                continue;
+                            }
                             '\0' => {
                                 return Err(Error::new(
                                     ErrorCode::StringContainsNull,
                                     Some(self.location()),
                                 ))
                             }
-                            // a newline is a possibility inside a quote
+                            // a newline is a possibility inside a quote. like
+                            // outside of quotes, the `\r` of a Windows newline
+                            // is counted as a column, which the `\n` resets.
+                            // both are kept verbatim.
                             '\n' => {
                                 self.line += 1;
-                                self.col = 0;
+                                self.col = 1;
                             }
                             _ if c.is_ascii() => self.col += 1,
                             _ => {
@@ -106,10 +185,11 @@ impl<'a> Tokenizer<'a> {
                         buffer.push(c);
                     }
                 }
-                // found a delimiter
-                ' ' | '\t' | '\r' | '\n' | '(' | ')' => {
+                // found a delimiter (or the lenient separator, or a comment)
+                c if self.is_delimiter(c) => {
                     let (_value, remaining) = start.split_at(o);
-                    return from_raw(&buffer, str_loc).map(|()| (Text::Quoted(buffer), remaining));
+                    return from_raw(&buffer, str_loc, self.quote_escape)
+                        .map(|()| (owned(buffer, quoted), remaining));
                 }
                 '\0' => {
                     return Err(Error::new(
@@ -130,21 +210,28 @@ impl<'a> Tokenizer<'a> {
             }
         }
         // consumed all of the input
-        from_raw(&buffer, str_loc).map(|()| (Text::Quoted(buffer), ""))
+        from_raw(&buffer, str_loc, self.quote_escape).map(|()| (owned(buffer, quoted), ""))
     }
 
     fn read_text(&mut self, start: &'a str) -> Result<(Text<'a>, &'a str)> {
         let str_loc = self.location();
         for (o, c) in start.char_indices() {
             match c {
+                // found an escaped parenthesis. the value can't be borrowed,
+                // so start over from the beginning of the text.
+                '\\' if self.is_paren_escape(&start[o..]) => {
+                    self.col = str_loc.col;
+                    return self.read_quoted_text(start);
+                }
                 // found a quote. the value can't be borrowed. quoting is rare,
                 // so a performance hit of starting over/backtracking is
                 // acceptable.
                 '"' => return self.read_quoted_text(start),
-                // found a delimiter
-                ' ' | '\t' | '\r' | '\n' | '(' | ')' => {
+                // found a delimiter (or the lenient separator, or a comment)
+                c if self.is_delimiter(c) => {
                     let (value, remaining) = start.split_at(o);
-                    return from_raw(&value, str_loc).map(|()| (Text::Unquoted(value), remaining));
+                    return from_raw(value, str_loc, false)
+                        .map(|()| (Text::Unquoted(value), remaining));
                 }
                 '\0' => {
                     return Err(Error::new(
@@ -162,12 +249,22 @@ impl<'a> Tokenizer<'a> {
             }
         }
         // consumed all of the input
-        from_raw(start, str_loc).map(|()| (Text::Unquoted(start), ""))
+        from_raw(start, str_loc, false).map(|()| (Text::Unquoted(start), ""))
     }
 
     pub fn read_token(&mut self) -> Result<Span<'a>> {
+        let mut comment = false;
         for (o, c) in self.input.char_indices() {
             match c {
+                // a comment runs to the end of the line, and may contain
+                // anything. the newline is handled as whitespace.
+                _ if comment && c != '\n' => {
+                    self.col += 1;
+                }
+                ';' if self.line_comments => {
+                    comment = true;
+                    self.col += 1;
+                }
                 '(' => {
                     // PANIC/SAFETY: '(' is one byte in UTF-8, so o + 1 is okay.
                     let (_discard, input) = self.input.split_at(o + 1);
@@ -185,12 +282,17 @@ impl<'a> Tokenizer<'a> {
                     return Ok(span);
                 }
                 '\n' => {
+                    comment = false;
                     self.line += 1;
-                    self.col = 0;
+                    self.col = 1;
                 }
                 ' ' | '\t' | '\r' => {
                     self.col += 1;
                 }
+                // in lenient mode, the separator is treated like whitespace
+                c if self.is_separator(c) => {
+                    self.col += 1;
+                }
                 _ => {
                     let (_discard, start) = self.input.split_at(o);
                     let loc = self.location();
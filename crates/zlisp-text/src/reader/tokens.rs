@@ -0,0 +1,68 @@
+use super::config::ReaderConfig;
+use super::tokenizer::{self, Text, Tokenizer};
+use crate::error::{Location, Result};
+
+/// A token of text zlisp data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    /// The start of a list, i.e. `(`.
+    ListStart,
+    /// The end of a list, i.e. `)`.
+    ListEnd,
+    /// Unquoted text, with any escaped parentheses decoded.
+    Unquoted(String),
+    /// Quoted text, without the quotes.
+    Quoted(String),
+}
+
+/// An iterator over the tokens of text zlisp data, and their locations.
+///
+/// This cannot be constructed, use [`tokenize`] or [`tokenize_with_config`].
+#[derive(Debug, Clone)]
+pub struct Tokens<'a> {
+    /// The tokenizer, or `None` after the end of the data or an error.
+    inner: Option<Tokenizer<'a>>,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Result<(Token, Location)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut tokenizer = self.inner.take()?;
+        let span = match tokenizer.read_token() {
+            Ok(span) => span,
+            Err(e) => return Some(Err(e)),
+        };
+        let token = match span.token {
+            tokenizer::Token::ListStart => Token::ListStart,
+            tokenizer::Token::ListEnd => Token::ListEnd,
+            tokenizer::Token::Text(Text::Quoted(s)) => Token::Quoted(s),
+            tokenizer::Token::Text(Text::Unquoted(s)) => Token::Unquoted(s.to_owned()),
+            tokenizer::Token::Text(Text::Escaped(s)) => Token::Unquoted(s),
+            tokenizer::Token::Eof => return None,
+        };
+        self.inner = Some(tokenizer);
+        Some(Ok((token, span.loc)))
+    }
+}
+
+/// Read the tokens of text zlisp data.
+///
+/// Tokens are not parsed into scalars, and lists are not checked to be
+/// balanced. The location is where the token starts. After the end of the
+/// data or the first error, the iterator is exhausted.
+pub fn tokenize(s: &str) -> Tokens<'_> {
+    tokenize_with_config(s, ReaderConfig::default())
+}
+
+/// Read the tokens of text zlisp data, using the reader configuration.
+///
+/// Only options that affect tokenization, like
+/// [`paren_escape`](crate::ReaderConfigBuilder::paren_escape) and
+/// [`lenient_whitespace`](crate::ReaderConfigBuilder::lenient_whitespace),
+/// are used.
+pub fn tokenize_with_config<'a>(s: &'a str, config: &ReaderConfig) -> Tokens<'a> {
+    Tokens {
+        inner: Some(Tokenizer::new(s, config)),
+    }
+}
@@ -0,0 +1,119 @@
+use crate::error::Location;
+use serde::{de, ser};
+use std::fmt;
+
+// the text reader recognises this struct name and these fields, and
+// deserializes the start and end locations around the value.
+pub(crate) const NAME: &str = "$__zlisp_private_Spanned";
+pub(crate) const START_LINE: &str = "$__zlisp_private_start_line";
+pub(crate) const START_COL: &str = "$__zlisp_private_start_col";
+pub(crate) const VALUE: &str = "$__zlisp_private_value";
+pub(crate) const END_LINE: &str = "$__zlisp_private_end_line";
+pub(crate) const END_COL: &str = "$__zlisp_private_end_col";
+pub(crate) const FIELDS: &[&str] = &[START_LINE, START_COL, VALUE, END_LINE, END_COL];
+
+/// A value, and the locations in the text data it was read from.
+///
+/// This is only supported by the text reader. The start location is the
+/// location of the first token of the value, and the end location is the
+/// location immediately after the last token of the value.
+///
+/// When serialized, only the value is written.
+#[derive(Debug, Clone)]
+pub struct Spanned<T> {
+    start: Location,
+    end: Location,
+    value: T,
+}
+
+impl<T> Spanned<T> {
+    /// The location of the first token of the value.
+    pub const fn start(&self) -> &Location {
+        &self.start
+    }
+
+    /// The location immediately after the last token of the value.
+    pub const fn end(&self) -> &Location {
+        &self.end
+    }
+
+    /// A reference to the value.
+    pub const fn get_ref(&self) -> &T {
+        &self.value
+    }
+
+    /// A mutable reference to the value.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
+    /// Consume the wrapper, returning the value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<'de, T> de::Deserialize<'de> for Spanned<T>
+where
+    T: de::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct SpannedVisitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T> de::Visitor<'de> for SpannedVisitor<T>
+        where
+            T: de::Deserialize<'de>,
+        {
+            type Value = Spanned<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a spanned value")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> std::result::Result<Self::Value, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                fn field<'de, A, V>(map: &mut A, name: &'static str) -> Result<V, A::Error>
+                where
+                    A: de::MapAccess<'de>,
+                    V: de::Deserialize<'de>,
+                {
+                    match map.next_key::<&str>()? {
+                        Some(key) if key == name => map.next_value(),
+                        _ => Err(de::Error::missing_field(name)),
+                    }
+                }
+
+                let start_line = field(&mut map, START_LINE)?;
+                let start_col = field(&mut map, START_COL)?;
+                let value = field(&mut map, VALUE)?;
+                let end_line = field(&mut map, END_LINE)?;
+                let end_col = field(&mut map, END_COL)?;
+                Ok(Spanned {
+                    start: Location::new(start_line, start_col),
+                    end: Location::new(end_line, end_col),
+                    value,
+                })
+            }
+        }
+
+        let visitor = SpannedVisitor(std::marker::PhantomData);
+        deserializer.deserialize_struct(NAME, FIELDS, visitor)
+    }
+}
+
+impl<T> ser::Serialize for Spanned<T>
+where
+    T: ser::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
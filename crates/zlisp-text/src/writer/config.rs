@@ -1,3 +1,5 @@
+use crate::error::{Error, ErrorCode, Result};
+
 const DEFAULT_INDENT: &str = "\t";
 const DEFAULT_NEWLINE: &str = "\r\n";
 const DEFAULT_DELIM: &str = "\t";
@@ -10,11 +12,22 @@ pub struct WhitespaceConfigBuilder<'a> {
     indent: &'a str,
     newline: &'a str,
     delimiter: &'a str,
+    paren_escape: bool,
+    quote_escape: bool,
+    quote_numbers: bool,
+    quote_numbers_strict: bool,
+    float_quantize: Option<f32>,
+    float_precision: Option<usize>,
+    max_line_width: Option<usize>,
+    record_separator: &'a str,
+    trailing_newline: bool,
 }
 
 impl<'a> WhitespaceConfigBuilder<'a> {
     /// The indent to output when writing text.
     ///
+    /// The indent must only contain whitespace, but may be empty.
+    ///
     /// The default is `\t`/tab.
     #[inline]
     pub const fn indent(mut self, indent: &'a str) -> Self {
@@ -24,6 +37,9 @@ impl<'a> WhitespaceConfigBuilder<'a> {
 
     /// The newline to output when writing text.
     ///
+    /// The newline must only contain whitespace, but may be empty. Without
+    /// newlines, items are separated by the delimiter instead.
+    ///
     /// The default is `\r\n`/a Windows newline.
     #[inline]
     pub const fn newline(mut self, newline: &'a str) -> Self {
@@ -33,6 +49,9 @@ impl<'a> WhitespaceConfigBuilder<'a> {
 
     /// The delimiter to output when writing text.
     ///
+    /// The delimiter must only contain whitespace, and may not be empty, as
+    /// it separates items on the same line.
+    ///
     /// The default is `\t`/tab.
     #[inline]
     pub const fn delimiter(mut self, delimiter: &'a str) -> Self {
@@ -40,13 +59,174 @@ impl<'a> WhitespaceConfigBuilder<'a> {
         self
     }
 
+    /// Escape parentheses in strings instead of quoting them.
+    ///
+    /// When enabled, a string such as `a(b)` is written as `a\(b\)` instead
+    /// of `"a(b)"`. Strings that require quoting for other reasons are still
+    /// quoted. Reading this data requires [`ReaderConfigBuilder::paren_escape`](crate::ReaderConfigBuilder::paren_escape).
+    ///
+    /// The default is `false`.
+    #[inline]
+    pub const fn paren_escape(mut self, paren_escape: bool) -> Self {
+        self.paren_escape = paren_escape;
+        self
+    }
+
+    /// Escape quotes and other special characters in quoted strings.
+    ///
+    /// When enabled, strings may contain quotes. In quoted strings, `"`, `\`,
+    /// newlines, and tabs are written as `\"`, `\\`, `\n`, and `\t`. Otherwise,
+    /// strings containing quotes can't be written. Reading this data requires
+    /// [`ReaderConfigBuilder::quote_escape`](crate::ReaderConfigBuilder::quote_escape).
+    ///
+    /// The default is `false`.
+    #[inline]
+    pub const fn quote_escape(mut self, quote_escape: bool) -> Self {
+        self.quote_escape = quote_escape;
+        self
+    }
+
+    /// Quote strings that could be mistaken for numbers.
+    ///
+    /// Any string that only consists of digits, signs, and decimal points is
+    /// quoted, so that it isn't read back as a number. Disabling this trusts
+    /// the caller that strings are never read as numbers, e.g. because the
+    /// data is only read into string fields.
+    ///
+    /// The default is `true`.
+    #[inline]
+    pub const fn quote_numbers(mut self, quote_numbers: bool) -> Self {
+        self.quote_numbers = quote_numbers;
+        self
+    }
+
+    /// Only quote strings that would actually be read back as numbers.
+    ///
+    /// By default, any string that only consists of digits, signs, and decimal
+    /// points is quoted, since this avoids parsing the string. So `007` is
+    /// quoted, but so is `1.2.3`, which can't be a number. When enabled, these
+    /// strings are parsed in the same way as when reading, and only quoted if
+    /// they are numbers. This has no effect if
+    /// [`quote_numbers`](Self::quote_numbers) is disabled.
+    ///
+    /// The default is `false`.
+    #[inline]
+    pub const fn quote_numbers_strict(mut self, quote_numbers_strict: bool) -> Self {
+        self.quote_numbers_strict = quote_numbers_strict;
+        self
+    }
+
+    /// Round floats to the nearest multiple of a step before writing them.
+    ///
+    /// This makes the output deterministic across platforms, where tiny
+    /// floating-point differences would otherwise show up in the written
+    /// digits. Steps that are not positive and finite are ignored.
+    ///
+    /// The default is `None`.
+    #[inline]
+    pub const fn float_quantize(mut self, float_quantize: Option<f32>) -> Self {
+        self.float_quantize = float_quantize;
+        self
+    }
+
+    /// The number of decimal places to write floats with.
+    ///
+    /// By default, floats are written with the shortest representation that
+    /// reads back as the same value, e.g. `0.1` or `1.0`. A fixed precision,
+    /// e.g. `Some(6)` as written by Zipper, may lose precision. Floats are
+    /// always written with at least one decimal place, so they aren't read
+    /// back as integers.
+    ///
+    /// The default is `None`.
+    #[inline]
+    pub const fn float_precision(mut self, float_precision: Option<usize>) -> Self {
+        self.float_precision = float_precision;
+        self
+    }
+
+    /// The maximum width of lines written by the pretty writer.
+    ///
+    /// Without a width, lists are written compactly on a single line if they
+    /// only contain scalars or other compact lists, and have fewer than seven
+    /// items. With a width, the number of items doesn't matter. Instead, lists
+    /// are written compactly unless they would exceed the width. The width is
+    /// measured in bytes, including indents. Scalars can still exceed the
+    /// width, as they can't be split. This has no effect on
+    /// [`to_string`](crate::to_string).
+    ///
+    /// The default is `None`.
+    #[inline]
+    pub const fn max_line_width(mut self, max_line_width: Option<usize>) -> Self {
+        self.max_line_width = max_line_width;
+        self
+    }
+
+    /// The separator to output between records, when writing multiple values.
+    ///
+    /// Each record already ends with a newline, so the default separator
+    /// leaves an empty line between records. This has no effect when writing
+    /// a single value.
+    ///
+    /// The default is `\r\n`/a Windows newline.
+    #[inline]
+    pub const fn record_separator(mut self, record_separator: &'a str) -> Self {
+        self.record_separator = record_separator;
+        self
+    }
+
+    /// End the output with a newline.
+    ///
+    /// Some tools require text to end with a newline, others reject it. This
+    /// applies to [`to_string`](crate::to_string) and
+    /// [`to_pretty`](crate::to_pretty), and the writers based on them.
+    ///
+    /// The default is `true`.
+    #[inline]
+    pub const fn trailing_newline(mut self, trailing_newline: bool) -> Self {
+        self.trailing_newline = trailing_newline;
+        self
+    }
+
+    /// Construct a new whitespace configuration, after validating it.
+    ///
+    /// The indent, newline, delimiter, and record separator may only contain
+    /// whitespace, i.e. spaces, tabs, carriage returns, and line feeds.
+    /// Anything else could be read back as part of the data. The delimiter
+    /// also may not be empty, otherwise adjacent items would merge.
+    pub fn try_build(self) -> Result<WhitespaceConfig<'a>> {
+        let fields = [
+            ("indent", self.indent),
+            ("newline", self.newline),
+            ("delimiter", self.delimiter),
+            ("record_separator", self.record_separator),
+        ];
+        for (field, value) in fields {
+            let is_whitespace = value.chars().all(|c| matches!(c, ' ' | '\t' | '\r' | '\n'));
+            if !is_whitespace || (field == "delimiter" && value.is_empty()) {
+                return Err(Error::new(ErrorCode::InvalidWhitespace { field }, None));
+            }
+        }
+        Ok(self.build())
+    }
+
     /// Construct a new whitespace configuration.
+    ///
+    /// The configuration isn't validated, see [`try_build`](Self::try_build).
     #[inline]
     pub const fn build(self) -> WhitespaceConfig<'a> {
         WhitespaceConfig {
             indent: self.indent,
             newline: self.newline,
             delimiter: self.delimiter,
+            paren_escape: self.paren_escape,
+            quote_escape: self.quote_escape,
+            quote_numbers: self.quote_numbers,
+            quote_numbers_strict: self.quote_numbers_strict,
+            float_quantize: self.float_quantize,
+            float_precision: self.float_precision,
+            max_line_width: self.max_line_width,
+            record_separator: self.record_separator,
+            trailing_newline: self.trailing_newline,
         }
     }
 }
@@ -66,6 +246,42 @@ pub struct WhitespaceConfig<'a> {
     ///
     /// Canonically, this is `\t`/tab.
     pub(crate) delimiter: &'a str,
+    /// Escape parentheses in strings instead of quoting them.
+    ///
+    /// Canonically, this is `false`.
+    pub(crate) paren_escape: bool,
+    /// Escape quotes and other special characters in quoted strings.
+    ///
+    /// Canonically, this is `false`.
+    pub(crate) quote_escape: bool,
+    /// Quote strings that could be mistaken for numbers.
+    ///
+    /// Canonically, this is `true`.
+    pub(crate) quote_numbers: bool,
+    /// Only quote strings that would actually be read back as numbers.
+    ///
+    /// Canonically, this is `false`.
+    pub(crate) quote_numbers_strict: bool,
+    /// Round floats to the nearest multiple of a step before writing them.
+    ///
+    /// Canonically, this is `None`.
+    pub(crate) float_quantize: Option<f32>,
+    /// The number of decimal places to write floats with.
+    ///
+    /// Canonically, this is `None`, i.e. the shortest representation.
+    pub(crate) float_precision: Option<usize>,
+    /// The maximum width of lines written by the pretty writer.
+    ///
+    /// Canonically, this is `None`.
+    pub(crate) max_line_width: Option<usize>,
+    /// The separator to output between records, when writing multiple values.
+    ///
+    /// Canonically, this is `\r\n`/a Windows newline.
+    pub(crate) record_separator: &'a str,
+    /// End the output with a newline.
+    ///
+    /// Canonically, this is `true`.
+    pub(crate) trailing_newline: bool,
 }
 
 impl<'a> WhitespaceConfig<'a> {
@@ -77,6 +293,15 @@ impl<'a> WhitespaceConfig<'a> {
             indent: DEFAULT_INDENT,
             newline: DEFAULT_NEWLINE,
             delimiter: DEFAULT_DELIM,
+            paren_escape: false,
+            quote_escape: false,
+            quote_numbers: true,
+            quote_numbers_strict: false,
+            float_quantize: None,
+            float_precision: None,
+            max_line_width: None,
+            record_separator: DEFAULT_NEWLINE,
+            trailing_newline: true,
         }
     };
 
@@ -88,6 +313,35 @@ impl<'a> WhitespaceConfig<'a> {
         &Self::DEFAULT
     }
 
+    /// A compact whitespace configuration.
+    ///
+    /// This uses no indent or newlines, and spaces for delimiters, so the
+    /// pretty writer writes each value on a single line. Multiple records are
+    /// separated by Unix newlines.
+    pub const COMPACT: Self = {
+        Self {
+            indent: "",
+            newline: "",
+            delimiter: " ",
+            record_separator: "\n",
+            ..Self::DEFAULT
+        }
+    };
+
+    /// A whitespace configuration using spaces.
+    ///
+    /// This uses four spaces for indent, a space for delimiters, as well as
+    /// Unix newlines.
+    pub const SPACES: Self = {
+        Self {
+            indent: "    ",
+            newline: "\n",
+            delimiter: " ",
+            record_separator: "\n",
+            ..Self::DEFAULT
+        }
+    };
+
     #[inline]
     /// Construct a builder for a whitespace configuration.
     pub const fn builder() -> WhitespaceConfigBuilder<'a> {
@@ -95,6 +349,15 @@ impl<'a> WhitespaceConfig<'a> {
             indent: DEFAULT_INDENT,
             newline: DEFAULT_NEWLINE,
             delimiter: DEFAULT_DELIM,
+            paren_escape: false,
+            quote_escape: false,
+            quote_numbers: true,
+            quote_numbers_strict: false,
+            float_quantize: None,
+            float_precision: None,
+            max_line_width: None,
+            record_separator: DEFAULT_NEWLINE,
+            trailing_newline: true,
         }
     }
     /// The indent to output when writing text.
@@ -114,4 +377,58 @@ impl<'a> WhitespaceConfig<'a> {
     pub const fn delimiter(&self) -> &'a str {
         self.delimiter
     }
+
+    /// Escape parentheses in strings instead of quoting them.
+    #[inline(always)]
+    pub const fn paren_escape(&self) -> bool {
+        self.paren_escape
+    }
+
+    /// Escape quotes and other special characters in quoted strings.
+    #[inline(always)]
+    pub const fn quote_escape(&self) -> bool {
+        self.quote_escape
+    }
+
+    /// Quote strings that could be mistaken for numbers.
+    #[inline(always)]
+    pub const fn quote_numbers(&self) -> bool {
+        self.quote_numbers
+    }
+
+    /// Only quote strings that would actually be read back as numbers.
+    #[inline(always)]
+    pub const fn quote_numbers_strict(&self) -> bool {
+        self.quote_numbers_strict
+    }
+
+    /// Round floats to the nearest multiple of a step before writing them.
+    #[inline(always)]
+    pub const fn float_quantize(&self) -> Option<f32> {
+        self.float_quantize
+    }
+
+    /// The number of decimal places to write floats with.
+    #[inline(always)]
+    pub const fn float_precision(&self) -> Option<usize> {
+        self.float_precision
+    }
+
+    /// The maximum width of lines written by the pretty writer.
+    #[inline(always)]
+    pub const fn max_line_width(&self) -> Option<usize> {
+        self.max_line_width
+    }
+
+    /// The separator to output between records, when writing multiple values.
+    #[inline(always)]
+    pub const fn record_separator(&self) -> &'a str {
+        self.record_separator
+    }
+
+    /// End the output with a newline.
+    #[inline(always)]
+    pub const fn trailing_newline(&self) -> bool {
+        self.trailing_newline
+    }
 }
@@ -0,0 +1,45 @@
+use crate::error::Result;
+use crate::writer::config::WhitespaceConfig;
+use crate::writer::pretty_writer;
+use crate::writer::ser_common::validate_len;
+
+/// A document element, for building text output without serde.
+///
+/// Elements can only be constructed from valid values, so writing an element
+/// cannot fail. The layout follows the same rules as [`to_pretty`](crate::to_pretty).
+#[derive(Debug, Clone)]
+pub struct Element(pretty_writer::Element);
+
+impl Element {
+    /// Construct an int element.
+    pub fn int(v: i32) -> Self {
+        Self(pretty_writer::Element::int(v))
+    }
+
+    /// Construct a float element.
+    pub fn float(v: f32) -> Self {
+        Self(pretty_writer::Element::float(v))
+    }
+
+    /// Construct a string element.
+    ///
+    /// The string is quoted if required. Since the element can be written with
+    /// any configuration, strings containing quotes are not supported.
+    pub fn string(v: &str) -> Result<Self> {
+        let element = pretty_writer::Element::string(v)?;
+        element.check(WhitespaceConfig::default())?;
+        Ok(Self(element))
+    }
+
+    /// Construct a list element.
+    pub fn list(items: Vec<Element>) -> Result<Self> {
+        validate_len(items.len())?;
+        let items = items.into_iter().map(|item| item.0).collect();
+        Ok(Self(pretty_writer::Element::seq(items)))
+    }
+}
+
+/// Write an element to text zlisp data.
+pub fn write_element(element: Element, config: &WhitespaceConfig<'_>) -> String {
+    pretty_writer::write(element.0, config)
+}
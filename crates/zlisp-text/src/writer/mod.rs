@@ -1,9 +1,13 @@
 mod config;
+mod element;
 mod pretty_writer;
 mod ser_common;
 mod string_writer;
+mod value;
 
 pub use config::{WhitespaceConfig, WhitespaceConfigBuilder};
+pub use element::{write_element, Element};
+pub use value::value_to_string;
 
 use crate::error::Result;
 
@@ -17,11 +21,99 @@ where
     serializer.finish()
 }
 
+/// Serialize a value to text zlisp data, written to an I/O sink.
+///
+/// The output is the same as [`to_string`], but it is written incrementally,
+/// without building the text in memory. Writes are not buffered, so wrapping
+/// the sink in a [`BufWriter`](std::io::BufWriter) is recommended. After an
+/// I/O error, the rest of the output is discarded, and the error is returned.
+pub fn to_writer<W, T>(writer: W, value: &T, config: &WhitespaceConfig<'_>) -> Result<()>
+where
+    T: ?Sized + serde::Serialize,
+    W: std::io::Write,
+{
+    let output = pretty_writer::IoOutput::new(writer, config.newline);
+    let mut serializer = string_writer::StringWriter::with_buffer(config, output);
+    value.serialize(&mut serializer)?;
+    serializer.into_buffer().finish()
+}
+
+/// Serialize a value to canonical text zlisp data.
+///
+/// The output is deterministic, and suitable for hashing or diffing: map
+/// entries are sorted by key, floats use the shortest representation that
+/// round-trips, and the value is written on a single line with single spaces
+/// between items and no trailing newline.
+///
+/// Only maps are normalized. Sequences keep their order, since it is usually
+/// significant, so unordered collections like a `HashSet` are written in
+/// their iteration order. Use an ordered collection like a `BTreeSet` to get
+/// the same output for equal sets.
+pub fn to_string_canonical<T>(value: &T) -> Result<String>
+where
+    T: ?Sized + serde::Serialize,
+{
+    let element = value.serialize(pretty_writer::Gather)?;
+    element.check(WhitespaceConfig::default())?;
+    Ok(pretty_writer::write_canonical(element))
+}
+
 /// Serialize a value to text zlisp data.
 pub fn to_pretty<T>(value: &T, config: &WhitespaceConfig<'_>) -> Result<String>
 where
     T: ?Sized + serde::Serialize,
 {
     let element = value.serialize(pretty_writer::Gather)?;
+    element.check(config)?;
     Ok(pretty_writer::write(element, config))
 }
+
+/// Serialize a value to text zlisp data, written to an I/O sink.
+///
+/// The output is the same as [`to_pretty`]. Deciding the layout requires the
+/// value to be gathered first, but the text is written incrementally, without
+/// building it in memory. Writes are not buffered, so wrapping the sink in a
+/// [`BufWriter`](std::io::BufWriter) is recommended.
+pub fn to_writer_pretty<W, T>(writer: W, value: &T, config: &WhitespaceConfig<'_>) -> Result<()>
+where
+    T: ?Sized + serde::Serialize,
+    W: std::io::Write,
+{
+    let element = value.serialize(pretty_writer::Gather)?;
+    element.check(config)?;
+    pretty_writer::write_to(element, config, writer)
+}
+
+/// The length of the text zlisp data a value serializes to, in bytes.
+///
+/// This is exactly the length of the output of [`to_pretty`], but the output
+/// isn't built. This can be used to size buffers ahead of time.
+pub fn serialized_len<T>(value: &T, config: &WhitespaceConfig<'_>) -> Result<usize>
+where
+    T: ?Sized + serde::Serialize,
+{
+    let element = value.serialize(pretty_writer::Gather)?;
+    element.check(config)?;
+    Ok(pretty_writer::measure(element, config))
+}
+
+/// Serialize multiple values to text zlisp data, as separate records.
+///
+/// Each value is written as by [`to_pretty`], with the
+/// [record separator](crate::WhitespaceConfigBuilder::record_separator)
+/// between values. The records can be read by
+/// [`from_str_many`](crate::from_str_many).
+pub fn to_pretty_many<I, T>(values: I, config: &WhitespaceConfig<'_>) -> Result<String>
+where
+    I: IntoIterator<Item = T>,
+    T: serde::Serialize,
+{
+    let mut output = String::new();
+    for (index, value) in values.into_iter().enumerate() {
+        if index > 0 {
+            output.push_str(config.record_separator);
+        }
+        output.push_str(&to_pretty(&value, config)?);
+    }
+    Ok(output)
+}
@@ -0,0 +1,108 @@
+use super::{Element, Variant};
+use crate::ascii::push_raw;
+use crate::writer::config::WhitespaceConfig;
+use crate::writer::ser_common::format_f32_shortest;
+
+fn write_items<I>(buffer: &mut String, items: I)
+where
+    I: IntoIterator<Item = Element>,
+{
+    let mut iter = items.into_iter();
+    if let Some(element) = iter.next() {
+        write_element(buffer, element);
+    }
+    for element in iter {
+        buffer.push(' ');
+        write_element(buffer, element);
+    }
+}
+
+fn write_fields(buffer: &mut String, fields: Vec<(&'static str, Element)>) {
+    let mut first = true;
+    for (k, v) in fields {
+        if !first {
+            buffer.push(' ');
+        }
+        first = false;
+        buffer.push_str(k);
+        buffer.push(' ');
+        write_element(buffer, v);
+    }
+}
+
+fn render(element: Element) -> String {
+    let mut buffer = String::new();
+    write_element(&mut buffer, element);
+    buffer
+}
+
+/// Write an element on a single line, separated by single spaces.
+///
+/// Map entries are sorted by their written keys, so the output does not
+/// depend on the iteration order of the map. Sequences are written in order,
+/// as they can't be told apart from sets.
+pub fn write_element(buffer: &mut String, element: Element) {
+    match element {
+        Element::Unit => buffer.push_str("()"),
+        Element::Scalar(string) => buffer.push_str(&string),
+        Element::Float(v) => buffer.push_str(&format_f32_shortest(v)),
+        Element::Str(string, quoting) => {
+            push_raw(buffer, &string, quoting, WhitespaceConfig::default())
+        }
+        Element::Some(inner) => {
+            buffer.push('(');
+            write_element(buffer, *inner);
+            buffer.push(')');
+        }
+        Element::Seq(seq, _is_compact) => {
+            buffer.push('(');
+            write_items(buffer, seq);
+            buffer.push(')');
+        }
+        Element::Map(inner) => {
+            let mut entries: Vec<(String, String)> = inner
+                .into_iter()
+                .map(|(k, v)| (render(k), render(v)))
+                .collect();
+            entries.sort();
+            buffer.push('(');
+            let mut first = true;
+            for (k, v) in entries {
+                if !first {
+                    buffer.push(' ');
+                }
+                first = false;
+                buffer.push_str(&k);
+                buffer.push(' ');
+                buffer.push_str(&v);
+            }
+            buffer.push(')');
+        }
+        Element::Struct(fields, _is_compact) => {
+            buffer.push('(');
+            write_fields(buffer, fields);
+            buffer.push(')');
+        }
+        Element::Enum(variant, inner, _is_compact) => {
+            buffer.push_str(variant);
+            match inner {
+                Variant::Unit => {}
+                Variant::NewType(element) => {
+                    buffer.push('(');
+                    write_element(buffer, *element);
+                    buffer.push(')');
+                }
+                Variant::Tuple(seq) => {
+                    buffer.push('(');
+                    write_items(buffer, seq);
+                    buffer.push(')');
+                }
+                Variant::Struct(fields) => {
+                    buffer.push('(');
+                    write_fields(buffer, fields);
+                    buffer.push(')');
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,11 @@
+mod canonical;
 mod private;
 mod ser;
 
+pub use private::IoOutput;
+
+use crate::ascii::{to_raw, Quoting};
+use crate::error::Result;
 use crate::writer::config::WhitespaceConfig;
 
 pub struct Gather;
@@ -17,6 +22,8 @@ pub enum Variant {
 pub enum Element {
     Unit,
     Scalar(String),
+    Float(f32),
+    Str(String, Quoting),
     Some(Box<Element>),
     Seq(Vec<Element>, bool),
     Map(Vec<(Element, Element)>),
@@ -24,10 +31,36 @@ pub enum Element {
     Enum(&'static str, Variant, bool),
 }
 
+fn compact(is_compact: bool, len: usize) -> bool {
+    is_compact && len < 7
+}
+
 impl Element {
+    pub fn int(v: i32) -> Self {
+        Self::Scalar(format!("{}", v))
+    }
+
+    pub fn float(v: f32) -> Self {
+        // how the float is written depends on the writer
+        Self::Float(v)
+    }
+
+    pub fn string(v: &str) -> Result<Self> {
+        // how the string is written depends on the config, which isn't known
+        // until the element is written.
+        let quoting = to_raw(v)?;
+        Ok(Self::Str(v.to_string(), quoting))
+    }
+
+    pub fn seq(seq: Vec<Element>) -> Self {
+        let is_compact = seq.iter().all(Element::is_compact);
+        let is_compact = compact(is_compact, seq.len());
+        Self::Seq(seq, is_compact)
+    }
+
     pub fn is_compact(&self) -> bool {
         match self {
-            Self::Scalar(_) | Self::Unit => true,
+            Self::Scalar(_) | Self::Float(_) | Self::Str(_, _) | Self::Unit => true,
             Self::Some(inner) => inner.is_compact(),
             Self::Seq(_, v) => *v,
             Self::Map(_) => false,
@@ -35,9 +68,74 @@ impl Element {
             Self::Enum(_, _, v) => *v,
         }
     }
+
+    /// Check any strings can be written with the configuration.
+    pub fn check(&self, config: &WhitespaceConfig<'_>) -> Result<()> {
+        fn check_all<'a>(
+            mut elements: impl Iterator<Item = &'a Element>,
+            config: &WhitespaceConfig<'_>,
+        ) -> Result<()> {
+            elements.try_for_each(|element| element.check(config))
+        }
+        match self {
+            Self::Scalar(_) | Self::Float(_) | Self::Unit => Ok(()),
+            Self::Str(_, quoting) => quoting.check(config),
+            Self::Some(inner) => inner.check(config),
+            Self::Seq(seq, _) => check_all(seq.iter(), config),
+            Self::Map(inner) => check_all(inner.iter().flat_map(|(k, v)| [k, v]), config),
+            Self::Struct(fields, _) => check_all(fields.iter().map(|(_, v)| v), config),
+            Self::Enum(_, inner, _) => match inner {
+                Variant::Unit => Ok(()),
+                Variant::NewType(element) => element.check(config),
+                Variant::Tuple(seq) => check_all(seq.iter(), config),
+                Variant::Struct(fields) => check_all(fields.iter().map(|(_, v)| v), config),
+            },
+        }
+    }
+
+    /// Whether the element can be written on a single line, regardless of the
+    /// number of items.
+    pub fn is_flat(&self) -> bool {
+        fn all_flat<'a>(mut elements: impl Iterator<Item = &'a Element>) -> bool {
+            elements.all(Element::is_flat)
+        }
+        match self {
+            Self::Scalar(_) | Self::Float(_) | Self::Str(_, _) | Self::Unit => true,
+            Self::Some(inner) => inner.is_flat(),
+            Self::Seq(seq, _) => all_flat(seq.iter()),
+            Self::Map(_) => false,
+            Self::Struct(fields, _) => all_flat(fields.iter().map(|(_, v)| v)),
+            Self::Enum(_, inner, _) => match inner {
+                Variant::Unit => true,
+                Variant::NewType(element) => element.is_flat(),
+                Variant::Tuple(seq) => all_flat(seq.iter()),
+                Variant::Struct(fields) => all_flat(fields.iter().map(|(_, v)| v)),
+            },
+        }
+    }
 }
 
 pub fn write(element: Element, config: &WhitespaceConfig<'_>) -> String {
     let writer = private::PrettyWriter::new(config);
     writer.write(element)
 }
+
+pub fn write_to<W: std::io::Write>(
+    element: Element,
+    config: &WhitespaceConfig<'_>,
+    writer: W,
+) -> Result<()> {
+    let writer = private::PrettyWriter::io(config, writer);
+    writer.write_to(element)
+}
+
+pub fn measure(element: Element, config: &WhitespaceConfig<'_>) -> usize {
+    let writer = private::PrettyWriter::counter(config);
+    writer.measure(element)
+}
+
+pub fn write_canonical(element: Element) -> String {
+    let mut buffer = String::new();
+    canonical::write_element(&mut buffer, element);
+    buffer
+}
@@ -1,26 +1,172 @@
 use super::{Element, Variant};
+use crate::ascii::{push_raw, Buffer};
+use crate::error::{Error, ErrorCode, Result};
 use crate::writer::config::WhitespaceConfig;
+use crate::writer::ser_common::format_f32;
+use std::io::Write;
 
+/// Where the pretty writer writes text to.
+pub trait Output: Buffer {
+    /// The width of the current line so far.
+    fn column(&self, newline: &str) -> usize;
+}
+
+impl Output for String {
+    fn column(&self, newline: &str) -> usize {
+        let start = self
+            .rfind(newline)
+            .map(|index| index + newline.len())
+            .unwrap_or(0);
+        self.len() - start
+    }
+}
+
+/// Counts the length of the text written, without storing it.
 #[derive(Debug, Clone)]
-pub struct PrettyWriter<'a, 'b> {
+pub struct Counter<'b> {
+    newline: &'b str,
+    len: usize,
+    /// The length at the start of the current line.
+    line_start: usize,
+}
+
+impl<'b> Counter<'b> {
+    pub const fn new(newline: &'b str) -> Self {
+        Self {
+            newline,
+            len: 0,
+            line_start: 0,
+        }
+    }
+}
+
+impl Buffer for Counter<'_> {
+    fn push(&mut self, ch: char) {
+        self.push_str(ch.encode_utf8(&mut [0; 4]))
+    }
+
+    fn push_str(&mut self, string: &str) {
+        if let Some(index) = string.rfind(self.newline) {
+            self.line_start = self.len + index + self.newline.len();
+        }
+        self.len += string.len();
+    }
+}
+
+impl Output for Counter<'_> {
+    fn column(&self, _newline: &str) -> usize {
+        self.len - self.line_start
+    }
+}
+
+/// Writes text to an I/O sink, without storing it.
+///
+/// Pushing text can't fail, so the first I/O error is kept, and any later
+/// text is discarded.
+#[derive(Debug)]
+pub struct IoOutput<'b, W> {
+    inner: W,
+    counter: Counter<'b>,
+    error: Option<std::io::Error>,
+}
+
+impl<'b, W: Write> IoOutput<'b, W> {
+    pub const fn new(inner: W, newline: &'b str) -> Self {
+        Self {
+            inner,
+            counter: Counter::new(newline),
+            error: None,
+        }
+    }
+
+    pub fn finish(self) -> Result<()> {
+        match self.error {
+            Some(e) => Err(Error::new(ErrorCode::IO(e.into()), None)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<W: Write> Buffer for IoOutput<'_, W> {
+    fn push(&mut self, ch: char) {
+        self.push_str(ch.encode_utf8(&mut [0; 4]))
+    }
+
+    fn push_str(&mut self, string: &str) {
+        if self.error.is_none() {
+            self.error = self.inner.write_all(string.as_bytes()).err();
+        }
+        self.counter.push_str(string);
+    }
+}
+
+impl<W: Write> Output for IoOutput<'_, W> {
+    fn column(&self, newline: &str) -> usize {
+        self.counter.column(newline)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PrettyWriter<'a, 'b, O = String> {
     config: &'a WhitespaceConfig<'b>,
-    buffer: String,
+    buffer: O,
+    /// The indent repeated for the deepest level seen so far.
+    indents: String,
+    /// Whether an enclosing element fits on the line.
+    fitted: bool,
 }
 
 impl<'a, 'b: 'a> PrettyWriter<'a, 'b> {
     pub fn new(config: &'a WhitespaceConfig<'b>) -> Self {
-        Self {
-            config,
-            buffer: String::new(),
-        }
+        Self::with_output(config, String::new())
     }
 
     pub fn write(mut self, value: Element) -> String {
         self.write_element(value, 0);
-
-        self.buffer.push_str(self.config.newline);
+        self.push_trailing_newline();
         self.buffer
     }
+}
+
+impl<'a, 'b: 'a> PrettyWriter<'a, 'b, Counter<'b>> {
+    pub fn counter(config: &'a WhitespaceConfig<'b>) -> Self {
+        Self::with_output(config, Counter::new(config.newline))
+    }
+
+    pub fn measure(mut self, value: Element) -> usize {
+        self.write_element(value, 0);
+        self.push_trailing_newline();
+        self.buffer.len
+    }
+}
+
+impl<'a, 'b: 'a, W: Write> PrettyWriter<'a, 'b, IoOutput<'b, W>> {
+    pub fn io(config: &'a WhitespaceConfig<'b>, inner: W) -> Self {
+        Self::with_output(config, IoOutput::new(inner, config.newline))
+    }
+
+    pub fn write_to(mut self, value: Element) -> Result<()> {
+        self.write_element(value, 0);
+        self.push_trailing_newline();
+        self.buffer.finish()
+    }
+}
+
+impl<'a, 'b: 'a, O: Output> PrettyWriter<'a, 'b, O> {
+    fn push_trailing_newline(&mut self) {
+        if self.config.trailing_newline {
+            self.buffer.push_str(self.config.newline);
+        }
+    }
+
+    const fn with_output(config: &'a WhitespaceConfig<'b>, buffer: O) -> Self {
+        Self {
+            config,
+            buffer,
+            indents: String::new(),
+            fitted: false,
+        }
+    }
 
     fn push_str(&mut self, string: &str) {
         self.buffer.push_str(string)
@@ -31,13 +177,68 @@ impl<'a, 'b: 'a> PrettyWriter<'a, 'b> {
     }
 
     fn push_indent(&mut self, level: usize) {
-        for _ in 0..level {
-            self.buffer.push_str(self.config.indent);
+        // build the indent for each level once, instead of pushing the indent
+        // level times for every line.
+        let len = self.config.indent.len() * level;
+        while self.indents.len() < len {
+            self.indents.push_str(self.config.indent);
         }
+        self.buffer.push_str(&self.indents[..len]);
+    }
+
+    /// The width of the current line so far.
+    fn column(&self) -> usize {
+        self.buffer.column(self.config.newline)
+    }
+
+    /// Decide if an element is compact, based on the maximum line width.
+    ///
+    /// Without a maximum line width, the element is unchanged, and lists with
+    /// many items are expanded. With one, any element that can be written on
+    /// a single line is compact, unless it would exceed the width.
+    fn fit(&self, value: Element, level: usize) -> Element {
+        let max = match self.config.max_line_width {
+            Some(max) => max,
+            None => return value,
+        };
+        let expandable = matches!(
+            value,
+            Element::Seq(_, _) | Element::Struct(_, _) | Element::Enum(_, _, _)
+        );
+        if !expandable {
+            return value;
+        }
+        // nested elements of an element that fits can't be wider, so they
+        // needn't be measured again.
+        let is_compact = self.fitted
+            || value.is_flat() && {
+                // measuring the width requires writing the element on a single
+                // line, which happens without newlines.
+                let config = WhitespaceConfig {
+                    max_line_width: None,
+                    newline: "",
+                    ..self.config.clone()
+                };
+                let mut writer = PrettyWriter::counter(&config);
+                writer.write_element(value.clone(), level);
+                self.column() + writer.buffer.len <= max
+            };
+        match value {
+            Element::Seq(seq, _) => Element::Seq(seq, is_compact),
+            Element::Struct(fields, _) => Element::Struct(fields, is_compact),
+            Element::Enum(variant, inner, _) => Element::Enum(variant, inner, is_compact),
+            other => other,
+        }
+    }
+
+    /// Without newlines, items can't be written on separate lines, so they
+    /// are always written compactly.
+    fn is_compact(&self, is_compact: bool) -> bool {
+        is_compact || self.config.newline.is_empty()
     }
 
     fn write_seq_items(&mut self, seq: Vec<Element>, is_compact: bool, level: usize) {
-        if is_compact {
+        if self.is_compact(is_compact) {
             let mut iter = seq.into_iter();
             if let Some(element) = iter.next() {
                 self.write_element(element, level + 1);
@@ -63,7 +264,7 @@ impl<'a, 'b: 'a> PrettyWriter<'a, 'b> {
         is_compact: bool,
         level: usize,
     ) {
-        if is_compact {
+        if self.is_compact(is_compact) {
             let mut iter = fields.into_iter();
             if let Some((k, v)) = iter.next() {
                 self.push_str(k);
@@ -89,12 +290,56 @@ impl<'a, 'b: 'a> PrettyWriter<'a, 'b> {
         }
     }
 
+    fn write_map_items(&mut self, inner: Vec<(Element, Element)>, level: usize) {
+        if self.is_compact(false) {
+            let mut iter = inner.into_iter();
+            if let Some((k, v)) = iter.next() {
+                self.write_element(k, level + 1);
+                self.push_str(self.config.delimiter);
+                self.write_element(v, level + 1);
+            }
+            for (k, v) in iter {
+                self.push_str(self.config.delimiter);
+                self.write_element(k, level + 1);
+                self.push_str(self.config.delimiter);
+                self.write_element(v, level + 1);
+            }
+        } else {
+            self.push_str(self.config.newline);
+            for (k, v) in inner {
+                self.push_indent(level + 1);
+                self.write_element(k, level + 1);
+                self.push_str(self.config.delimiter);
+                self.write_element(v, level + 1);
+                self.push_str(self.config.newline);
+            }
+            self.push_indent(level);
+        }
+    }
+
     fn write_element(&mut self, value: Element, level: usize) {
+        let value = self.fit(value, level);
+        // if the element fits, its nested elements fit too. this is only used
+        // with a maximum line width.
+        let fitted = self.fitted;
+        self.fitted |= matches!(
+            value,
+            Element::Seq(_, true) | Element::Struct(_, true) | Element::Enum(_, _, true)
+        );
+        self.write_fitted(value, level);
+        self.fitted = fitted;
+    }
+
+    fn write_fitted(&mut self, value: Element, level: usize) {
         // the outside structure is responsible for the starting indent and
         // the termination.
         match value {
             Element::Unit => self.push_str("()"),
             Element::Scalar(string) => self.push_str(&string),
+            Element::Float(v) => self.push_str(&format_f32(v, self.config)),
+            Element::Str(string, quoting) => {
+                push_raw(&mut self.buffer, &string, quoting, self.config)
+            }
             Element::Some(inner) => {
                 // this does not need to know if inner is compact, since it
                 // just wraps the inner value in "(...)".
@@ -109,15 +354,7 @@ impl<'a, 'b: 'a> PrettyWriter<'a, 'b> {
             }
             Element::Map(inner) => {
                 self.push_char('(');
-                self.push_str(self.config.newline);
-                for (k, v) in inner {
-                    self.push_indent(level + 1);
-                    self.write_element(k, level + 1);
-                    self.push_str(self.config.delimiter);
-                    self.write_element(v, level + 1);
-                    self.push_str(self.config.newline);
-                }
-                self.push_indent(level);
+                self.write_map_items(inner, level);
                 self.push_char(')');
             }
             Element::Struct(fields, is_compact) => {
@@ -1,13 +1,8 @@
-use super::{Element, Gather, Variant};
-use crate::ascii::to_raw;
+use super::{compact, Element, Gather, Variant};
 use crate::error::{Error, ErrorCode, Result};
 use crate::writer::ser_common::{map_len, require_len, struct_len, unsupported, validate_len};
 use serde::{ser, Serialize};
 
-fn compact(is_compact: bool, len: usize) -> bool {
-    is_compact && len < 7
-}
-
 impl ser::Serializer for Gather {
     type Ok = Element;
     type Error = Error;
@@ -22,6 +17,10 @@ impl ser::Serializer for Gather {
     type SerializeTupleVariant = TupleEnumGather;
     type SerializeStructVariant = StructEnumGather;
 
+    fn is_human_readable(&self) -> bool {
+        true
+    }
+
     unsupported!(serialize_bool, bool);
     unsupported!(serialize_i8, i8);
     unsupported!(serialize_i16, i16);
@@ -35,21 +34,15 @@ impl ser::Serializer for Gather {
     unsupported!(serialize_bytes, &[u8]);
 
     fn serialize_i32(self, v: i32) -> Result<Self::Ok> {
-        Ok(Element::Scalar(format!("{}", v)))
+        Ok(Element::int(v))
     }
 
     fn serialize_f32(self, v: f32) -> Result<Self::Ok> {
-        Ok(Element::Scalar(format!("{:.6}", v)))
+        Ok(Element::float(v))
     }
 
     fn serialize_str(self, v: &str) -> Result<Self::Ok> {
-        let needs_quoting = to_raw(v)?;
-        let value = if needs_quoting {
-            format!("\"{}\"", v)
-        } else {
-            format!("{}", v)
-        };
-        Ok(Element::Scalar(value))
+        Element::string(v)
     }
 
     fn serialize_some<T>(self, value: &T) -> Result<Self::Ok>
@@ -279,10 +272,10 @@ impl ser::SerializeMap for MapGather {
         Ok(())
     }
 
-    fn serialize_entry<K: ?Sized, V: ?Sized>(&mut self, key: &K, value: &V) -> Result<()>
+    fn serialize_entry<K, V>(&mut self, key: &K, value: &V) -> Result<()>
     where
-        K: Serialize,
-        V: Serialize,
+        K: ?Sized + Serialize,
+        V: ?Sized + Serialize,
     {
         let k = key.serialize(Gather)?;
         let v = value.serialize(Gather)?;
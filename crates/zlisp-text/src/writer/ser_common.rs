@@ -1,4 +1,5 @@
 use crate::error::{Error, ErrorCode, Result};
+use crate::writer::config::WhitespaceConfig;
 
 pub fn struct_len(len: usize) -> Result<usize> {
     len.checked_mul(2)
@@ -18,6 +19,45 @@ pub fn validate_len(len: usize) -> Result<i32> {
         .map_err(|_| Error::new(ErrorCode::SequenceTooLong, None))
 }
 
+/// Format a float using the shortest representation that round-trips.
+///
+/// Finite values always have a decimal point, even if the value is integral,
+/// so reading it back untyped (e.g. into a `Value`) produces a float, not an
+/// int. Rust never formats floats with an exponent.
+pub fn format_f32_shortest(v: f32) -> String {
+    let mut s = v.to_string();
+    if v.is_finite() && !s.contains('.') {
+        s.push_str(".0");
+    }
+    s
+}
+
+/// Format a float as configured.
+///
+/// By default, this uses the shortest representation that round-trips, but
+/// a fixed precision can be configured. Either way, the output always has a
+/// decimal point.
+pub fn format_f32(v: f32, config: &WhitespaceConfig<'_>) -> String {
+    let v = match config.float_quantize {
+        Some(step) if step > 0.0 && step.is_finite() => {
+            // adding zero turns a negative zero into a positive zero
+            let q = (v / step).round() * step + 0.0;
+            // large values may overflow when quantized, so keep them as-is
+            if q.is_finite() {
+                q
+            } else {
+                v
+            }
+        }
+        _ => v,
+    };
+    match config.float_precision {
+        // a precision of zero would drop the decimal point
+        Some(precision) => format!("{:.*}", precision.max(1), v),
+        None => format_f32_shortest(v),
+    }
+}
+
 macro_rules! unsupported {
     ($method:ident, $type:ty) => {
         fn $method(self, _value: $type) -> Result<Self::Ok> {
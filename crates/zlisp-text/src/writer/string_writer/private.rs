@@ -1,44 +1,85 @@
-use crate::ascii::to_raw;
+use crate::ascii::{push_raw, to_raw, Buffer};
 use crate::error::Result;
 use crate::writer::config::WhitespaceConfig;
+use crate::writer::ser_common::format_f32;
 
 #[derive(Debug, Clone)]
-pub struct StringWriter<'a, 'b> {
+pub struct StringWriter<'a, 'b, B = String> {
     config: &'a WhitespaceConfig<'b>,
-    inner: String,
+    inner: B,
     level: usize,
     last_write_was_string: bool,
+    /// Newlines are written lazily, so the last one can be omitted.
+    pending_newline: bool,
 }
 
 impl<'a, 'b: 'a> StringWriter<'a, 'b> {
     pub const fn new(config: &'a WhitespaceConfig<'b>) -> Self {
+        Self::with_buffer(config, String::new())
+    }
+
+    pub fn finish(self) -> Result<String> {
+        Ok(self.into_buffer())
+    }
+}
+
+impl<'a, 'b: 'a, B: Buffer> StringWriter<'a, 'b, B> {
+    pub const fn with_buffer(config: &'a WhitespaceConfig<'b>, inner: B) -> Self {
         Self {
             config,
-            inner: String::new(),
+            inner,
             level: 0,
             last_write_was_string: false,
+            pending_newline: false,
+        }
+    }
+
+    pub fn into_buffer(mut self) -> B {
+        if self.pending_newline && self.config.trailing_newline {
+            self.inner.push_str(self.config.newline);
+        }
+        self.inner
+    }
+
+    fn flush_newline(&mut self) {
+        if self.pending_newline {
+            self.pending_newline = false;
+            // without newlines, the delimiter keeps scalars apart
+            if self.config.newline.is_empty() {
+                self.inner.push_str(self.config.delimiter);
+            } else {
+                self.inner.push_str(self.config.newline);
+            }
         }
     }
 
     fn push_str(&mut self, s: &str) {
+        self.flush_newline();
         self.inner.push_str(s)
     }
 
     fn push_char(&mut self, c: char) {
+        self.flush_newline();
         self.inner.push(c)
     }
 
     fn push_indent(&mut self) {
+        self.flush_newline();
         for _ in 0..self.level {
             self.inner.push_str(self.config.indent);
         }
     }
 
     fn push_newline(&mut self) {
-        self.inner.push_str(self.config.newline);
+        self.flush_newline();
+        self.pending_newline = true;
     }
 
     fn push_delim(&mut self) {
+        if self.config.newline.is_empty() {
+            self.pending_newline = false;
+        }
+        self.flush_newline();
         self.inner.push_str(self.config.delimiter);
     }
 
@@ -52,21 +93,16 @@ impl<'a, 'b: 'a> StringWriter<'a, 'b> {
     pub fn write_f32(&mut self, v: f32) {
         self.last_write_was_string = false;
         self.push_indent();
-        self.push_str(&format!("{:.6}", v));
+        self.push_str(&format_f32(v, self.config));
         self.push_newline();
     }
 
     pub fn write_str(&mut self, v: &str) -> Result<()> {
-        let needs_quoting = to_raw(v)?;
+        let quoting = to_raw(v)?;
+        quoting.check(self.config)?;
         self.last_write_was_string = true;
         self.push_indent();
-        if needs_quoting {
-            self.push_char('"');
-            self.push_str(v);
-            self.push_char('"');
-        } else {
-            self.push_str(v);
-        }
+        push_raw(&mut self.inner, v, quoting, self.config);
         self.push_newline();
         Ok(())
     }
@@ -111,8 +147,4 @@ impl<'a, 'b: 'a> StringWriter<'a, 'b> {
         self.push_newline();
         self.last_write_was_string = false;
     }
-
-    pub fn finish(self) -> Result<String> {
-        Ok(self.inner)
-    }
 }
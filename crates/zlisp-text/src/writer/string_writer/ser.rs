@@ -1,9 +1,10 @@
 use super::StringWriter;
+use crate::ascii::Buffer;
 use crate::error::{Error, ErrorCode, Result};
 use crate::writer::ser_common::{map_len, require_len, struct_len, unsupported, validate_len};
 use serde::{ser, Serialize};
 
-impl<'a, 'b: 'a> ser::Serializer for &'a mut StringWriter<'b, 'b> {
+impl<'a, 'b: 'a, B: Buffer> ser::Serializer for &'a mut StringWriter<'b, 'b, B> {
     type Ok = ();
     type Error = Error;
 
@@ -15,6 +16,10 @@ impl<'a, 'b: 'a> ser::Serializer for &'a mut StringWriter<'b, 'b> {
     type SerializeStruct = Self;
     type SerializeStructVariant = Self;
 
+    fn is_human_readable(&self) -> bool {
+        true
+    }
+
     unsupported!(serialize_bool, bool);
     unsupported!(serialize_i8, i8);
     unsupported!(serialize_i16, i16);
@@ -157,7 +162,7 @@ impl<'a, 'b: 'a> ser::Serializer for &'a mut StringWriter<'b, 'b> {
     }
 }
 
-impl<'a, 'b: 'a> ser::SerializeSeq for &'a mut StringWriter<'b, 'b> {
+impl<'a, 'b: 'a, B: Buffer> ser::SerializeSeq for &'a mut StringWriter<'b, 'b, B> {
     type Ok = ();
     type Error = Error;
 
@@ -174,7 +179,7 @@ impl<'a, 'b: 'a> ser::SerializeSeq for &'a mut StringWriter<'b, 'b> {
     }
 }
 
-impl<'a, 'b: 'a> ser::SerializeTuple for &'a mut StringWriter<'b, 'b> {
+impl<'a, 'b: 'a, B: Buffer> ser::SerializeTuple for &'a mut StringWriter<'b, 'b, B> {
     type Ok = ();
     type Error = Error;
 
@@ -191,7 +196,7 @@ impl<'a, 'b: 'a> ser::SerializeTuple for &'a mut StringWriter<'b, 'b> {
     }
 }
 
-impl<'a, 'b: 'a> ser::SerializeTupleStruct for &'a mut StringWriter<'b, 'b> {
+impl<'a, 'b: 'a, B: Buffer> ser::SerializeTupleStruct for &'a mut StringWriter<'b, 'b, B> {
     type Ok = ();
     type Error = Error;
 
@@ -208,7 +213,7 @@ impl<'a, 'b: 'a> ser::SerializeTupleStruct for &'a mut StringWriter<'b, 'b> {
     }
 }
 
-impl<'a, 'b: 'a> ser::SerializeTupleVariant for &'a mut StringWriter<'b, 'b> {
+impl<'a, 'b: 'a, B: Buffer> ser::SerializeTupleVariant for &'a mut StringWriter<'b, 'b, B> {
     type Ok = ();
     type Error = Error;
 
@@ -225,7 +230,7 @@ impl<'a, 'b: 'a> ser::SerializeTupleVariant for &'a mut StringWriter<'b, 'b> {
     }
 }
 
-impl<'a, 'b: 'a> ser::SerializeMap for &'a mut StringWriter<'b, 'b> {
+impl<'a, 'b: 'a, B: Buffer> ser::SerializeMap for &'a mut StringWriter<'b, 'b, B> {
     type Ok = ();
     type Error = Error;
 
@@ -243,10 +248,10 @@ impl<'a, 'b: 'a> ser::SerializeMap for &'a mut StringWriter<'b, 'b> {
         value.serialize(&mut **self)
     }
 
-    fn serialize_entry<K: ?Sized, V: ?Sized>(&mut self, key: &K, value: &V) -> Result<()>
+    fn serialize_entry<K, V>(&mut self, key: &K, value: &V) -> Result<()>
     where
-        K: Serialize,
-        V: Serialize,
+        K: ?Sized + Serialize,
+        V: ?Sized + Serialize,
     {
         key.serialize(&mut **self)?;
         value.serialize(&mut **self)
@@ -258,7 +263,7 @@ impl<'a, 'b: 'a> ser::SerializeMap for &'a mut StringWriter<'b, 'b> {
     }
 }
 
-impl<'a, 'b: 'a> ser::SerializeStruct for &'a mut StringWriter<'b, 'b> {
+impl<'a, 'b: 'a, B: Buffer> ser::SerializeStruct for &'a mut StringWriter<'b, 'b, B> {
     type Ok = ();
     type Error = Error;
 
@@ -276,7 +281,7 @@ impl<'a, 'b: 'a> ser::SerializeStruct for &'a mut StringWriter<'b, 'b> {
     }
 }
 
-impl<'a, 'b: 'a> ser::SerializeStructVariant for &'a mut StringWriter<'b, 'b> {
+impl<'a, 'b: 'a, B: Buffer> ser::SerializeStructVariant for &'a mut StringWriter<'b, 'b, B> {
     type Ok = ();
     type Error = Error;
 
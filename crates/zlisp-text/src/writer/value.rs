@@ -0,0 +1,32 @@
+use super::config::WhitespaceConfig;
+use super::ser_common::validate_len;
+use super::string_writer::StringWriter;
+use crate::error::Result;
+use zlisp_value::Value;
+
+fn write_value(writer: &mut StringWriter<'_, '_>, value: &Value) -> Result<()> {
+    match value {
+        Value::Int(v) => writer.write_i32(*v),
+        Value::Float(v) => writer.write_f32(*v),
+        Value::String(v) => writer.write_str(v)?,
+        Value::List(items) => {
+            let count = validate_len(items.len())?;
+            writer.write_list_start(count)?;
+            for item in items {
+                write_value(writer, item)?;
+            }
+            writer.write_list_end();
+        }
+    }
+    Ok(())
+}
+
+/// Serialize a [`Value`] to text zlisp data.
+///
+/// The output is identical to [`to_string`](crate::to_string), but the value
+/// tree is written directly instead of going through serde.
+pub fn value_to_string(value: &Value, config: &WhitespaceConfig<'_>) -> Result<String> {
+    let mut writer = StringWriter::new(config);
+    write_value(&mut writer, value)?;
+    writer.finish()
+}
@@ -0,0 +1,76 @@
+use super::map;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use zlisp_text::{from_str, to_string_canonical};
+
+#[test]
+fn scalar_tests() {
+    assert_eq!(to_string_canonical(&-1i32).unwrap(), "-1");
+    assert_eq!(to_string_canonical(&1.5f32).unwrap(), "1.5");
+    assert_eq!(to_string_canonical(&1.0f32).unwrap(), "1.0");
+    assert_eq!(to_string_canonical(&0.1f32).unwrap(), "0.1");
+    assert_eq!(to_string_canonical(&-0.0f32).unwrap(), "-0.0");
+    assert_eq!(to_string_canonical("foo").unwrap(), "foo");
+    assert_eq!(to_string_canonical("1").unwrap(), "\"1\"");
+    assert_eq!(to_string_canonical(&()).unwrap(), "()");
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Inner {
+    values: Vec<f32>,
+    flag: Option<i32>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Outer {
+    name: String,
+    inner: Inner,
+    extras: HashMap<String, i32>,
+}
+
+fn outer() -> Outer {
+    Outer {
+        name: String::from("foo bar"),
+        inner: Inner {
+            values: vec![1.0, 2.5, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+            flag: Some(1),
+        },
+        extras: map![
+            String::from("d") => 4,
+            String::from("a") => 1,
+            String::from("c") => 3,
+            String::from("b") => 2,
+        ],
+    }
+}
+
+#[test]
+fn struct_tests() {
+    let expected = concat!(
+        "(name \"foo bar\" ",
+        "inner (values (1.0 2.5 3.0 4.0 5.0 6.0 7.0 8.0) flag (1)) ",
+        "extras (a 1 b 2 c 3 d 4))",
+    );
+    let s = to_string_canonical(&outer()).unwrap();
+    assert_eq!(s, expected);
+    let v: Outer = from_str(&s).unwrap();
+    assert_eq!(v, outer());
+}
+
+#[test]
+fn deterministic_tests() {
+    // hash maps iterate in a different order for different instances
+    let expected = to_string_canonical(&outer()).unwrap();
+    for _ in 0..10 {
+        assert_eq!(to_string_canonical(&outer()).unwrap(), expected);
+    }
+}
+
+#[test]
+fn seq_order_tests() {
+    // sequences are not sorted, since their order is significant
+    assert_eq!(to_string_canonical(&vec![2, 1, 3]).unwrap(), "(2 1 3)");
+    // ordered sets are written sorted
+    let set: BTreeSet<i32> = [2, 1, 3].into();
+    assert_eq!(to_string_canonical(&set).unwrap(), "(1 2 3)");
+}
@@ -0,0 +1,75 @@
+use assert_matches::assert_matches;
+use zlisp_text::{
+    events, events_with_config, ErrorCode, Event, Location, ReaderConfig, Result, TokenType,
+};
+
+#[test]
+fn events_tests() {
+    let v = events("(1 2.0 foo \"3\" ())")
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(
+        v,
+        vec![
+            Event::ListStart,
+            Event::Int(1),
+            Event::Float(2.0),
+            Event::Str("foo".to_owned()),
+            Event::Str("3".to_owned()),
+            Event::ListStart,
+            Event::ListEnd,
+            Event::ListEnd,
+        ]
+    );
+
+    let v = events(" 1 ").collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(v, vec![Event::Int(1)]);
+}
+
+#[test]
+fn events_aggregate_tests() {
+    let input = format!(
+        "({})",
+        (0..100)
+            .map(|i| format!("(a {})", i))
+            .collect::<Vec<_>>()
+            .join(" ")
+    );
+    let mut sum = 0;
+    for event in events(&input) {
+        if let Event::Int(v) = event.unwrap() {
+            sum += v;
+        }
+    }
+    assert_eq!(sum, 4950);
+}
+
+#[test]
+fn events_error_tests() {
+    let mut iter = events("(1");
+    assert_eq!(iter.next().unwrap().unwrap(), Event::ListStart);
+    assert_eq!(iter.next().unwrap().unwrap(), Event::Int(1));
+    let err = iter.next().unwrap().unwrap_err();
+    assert_matches!(
+        err.code(),
+        ErrorCode::UnexpectedEof {
+            expected: TokenType::TextOrListStart
+        }
+    );
+    assert_eq!(err.location(), Some(&Location::new(1, 3)));
+    // the iterator is exhausted after an error
+    assert!(iter.next().is_none());
+
+    let mut iter = events("1 2");
+    assert_eq!(iter.next().unwrap().unwrap(), Event::Int(1));
+    let err = iter.next().unwrap().unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ExpectedToken { .. });
+    assert!(iter.next().is_none());
+
+    let config = ReaderConfig::builder().max_elements(2).build();
+    let mut iter = events_with_config("(1 2)", &config);
+    assert_eq!(iter.next().unwrap().unwrap(), Event::ListStart);
+    assert_eq!(iter.next().unwrap().unwrap(), Event::Int(1));
+    let err = iter.next().unwrap().unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ElementLimitExceeded);
+}
@@ -0,0 +1,25 @@
+use serde_derive::Deserialize;
+use std::collections::HashMap;
+use zlisp_text::from_str;
+use zlisp_value::Value;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Known {
+    a: i32,
+    o: Option<i32>,
+    #[serde(flatten)]
+    extra: HashMap<String, Value>,
+}
+
+#[test]
+fn flatten_extra_tests() {
+    let v: Known = from_str("(b foo a 1 c (1 2.5) o (2))").unwrap();
+    assert_eq!(v.a, 1);
+    assert_eq!(v.o, Some(2));
+    assert_eq!(v.extra.len(), 2);
+    assert_eq!(v.extra["b"], Value::String("foo".to_owned()));
+    assert_eq!(
+        v.extra["c"],
+        Value::List(vec![Value::Int(1), Value::Float(2.5)])
+    );
+}
@@ -2,7 +2,7 @@ use super::map;
 use super::structs::*;
 use assert_matches::assert_matches;
 use std::collections::HashMap;
-use zlisp_text::{from_str, ErrorCode, Location, TokenType};
+use zlisp_text::{from_str, from_str_with_config, ErrorCode, Location, ReaderConfig, TokenType};
 
 macro_rules! assert_ok {
     ($type:ty, $input:expr, $value:expr) => {
@@ -72,8 +72,39 @@ fn char_tests() {
 
 #[test]
 fn string_tests() {
-    assert_unsupported!(&str);
     assert_ok!(String, "foo", "foo");
+    assert_ok!(String, "\"foo bar\"", "foo bar");
+}
+
+#[test]
+fn borrowed_str_tests() {
+    let input = "foo";
+    let v: &str = from_str(input).unwrap();
+    assert_eq!(v, "foo");
+    // the string is borrowed from the input
+    assert_eq!(v.as_ptr(), input.as_ptr());
+
+    assert_ok!(Vec<&str>, "(foo bar)", vec!["foo", "bar"]);
+    assert_ok!((&str, i32), "(foo 1)", ("foo", 1));
+
+    // quoted strings can't be borrowed
+    let err = from_str::<&str>("\"foo bar\"").unwrap_err();
+    assert_matches!(err.code(), ErrorCode::Custom(_));
+    // but can be read into types that don't require borrowing
+    let v: std::borrow::Cow<'_, str> = from_str("\"foo bar\"").unwrap();
+    assert_eq!(v, "foo bar");
+
+    assert_err!(
+        &str,
+        "(foo)",
+        1,
+        1,
+        ErrorCode::ExpectedToken {
+            expected: TokenType::Text,
+            found: TokenType::ListStart,
+            text: None,
+        }
+    );
 }
 
 #[test]
@@ -95,10 +126,11 @@ fn option_tests() {
         Value,
         "(-1 -2)",
         1,
-        "(-1 ".len(),
+        "(-1 ".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::ListEnd,
             found: TokenType::Text,
+            text: Some(_),
         }
     );
 }
@@ -113,10 +145,11 @@ fn unit_type_tests() {
         Value,
         "(-1)",
         1,
-        "(".len(),
+        "(".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::ListEnd,
             found: TokenType::Text,
+            text: Some(_),
         }
     );
 }
@@ -131,10 +164,11 @@ fn unit_struct_tests() {
         Value,
         "(-1)",
         1,
-        "(".len(),
+        "(".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::ListEnd,
             found: TokenType::Text,
+            text: Some(_),
         }
     );
 }
@@ -151,7 +185,7 @@ fn newtype_struct_tests() {
 fn seq_tests() {
     type Value = Vec<i32>;
 
-    assert_ok!(Value, "()", vec![]);
+    assert_ok!(Value, "()", Value::new());
     assert_ok!(Value, "(-1)", vec![-1]);
     assert_ok!(Value, "(-1 -2)", vec![-1, -2]);
 }
@@ -168,20 +202,22 @@ fn tuple_tests() {
         Value,
         "()",
         1,
-        "(".len(),
+        "(".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::Text,
             found: TokenType::ListEnd,
+            text: None,
         }
     );
     assert_err!(
         Value,
         "(-1 -2)",
         1,
-        "(-1 ".len(),
+        "(-1 ".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::ListEnd,
             found: TokenType::Text,
+            text: Some(_),
         }
     );
 }
@@ -196,30 +232,33 @@ fn tuple_struct_tests() {
         Value,
         "()",
         1,
-        "(".len(),
+        "(".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::Text,
             found: TokenType::ListEnd,
+            text: None,
         }
     );
     assert_err!(
         Value,
         "(-1)",
         1,
-        "(-1".len(),
+        "(-1".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::Text,
             found: TokenType::ListEnd,
+            text: None,
         }
     );
     assert_err!(
         Value,
         "(-1 -2 -3)",
         1,
-        "(-1 -2 ".len(),
+        "(-1 -2 ".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::ListEnd,
             found: TokenType::Text,
+            text: Some(_),
         }
     );
 }
@@ -235,20 +274,22 @@ fn map_tests() {
         Value,
         "(-1)",
         1,
-        "(-1".len(),
+        "(-1".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::Text,
             found: TokenType::ListEnd,
+            text: None,
         }
     );
     assert_err!(
         Value,
         "(-1 -2 -3)",
         1,
-        "(-1 -2 -3".len(),
+        "(-1 -2 -3".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::Text,
             found: TokenType::ListEnd,
+            text: None,
         }
     );
 }
@@ -264,20 +305,22 @@ fn struct_tests() {
         Value,
         "(a)",
         1,
-        "(a".len(),
+        "(a".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::Text,
             found: TokenType::ListEnd,
+            text: None,
         }
     );
     assert_err!(
         Value,
         "(a -1 b)",
         1,
-        "(a -1 b".len(),
+        "(a -1 b".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::Text,
             found: TokenType::ListEnd,
+            text: None,
         }
     );
 }
@@ -295,20 +338,22 @@ fn struct_optional_tests() {
         Value,
         "(a)",
         1,
-        "(a".len(),
+        "(a".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::Text,
             found: TokenType::ListEnd,
+            text: None,
         }
     );
     assert_err!(
         Value,
         "(a -1 b)",
         1,
-        "(a -1 b".len(),
+        "(a -1 b".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::Text,
             found: TokenType::ListEnd,
+            text: None,
         }
     );
 }
@@ -319,7 +364,7 @@ fn enum_unit_variant_tests() {
 
     assert_ok!(Value, "V", UnitVariant::V);
 
-    let err = unwrap_err!(Value, "!", 1, 0);
+    let err = unwrap_err!(Value, "!", 1, 1);
     assert_matches!(err.code(), ErrorCode::Custom(s) if s.contains("unknown variant"))
 }
 
@@ -329,27 +374,29 @@ fn enum_newtype_variant_tests() {
 
     assert_ok!(Value, "V(-1)", NewTypeVariant::V(-1));
 
-    let err = unwrap_err!(Value, "!", 1, 0);
+    let err = unwrap_err!(Value, "!", 1, 1);
     assert_matches!(err.code(), ErrorCode::Custom(s) if s.contains("unknown variant"));
 
     assert_err!(
         Value,
         "V()",
         1,
-        "V(".len(),
+        "V(".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::Text,
             found: TokenType::ListEnd,
+            text: None,
         }
     );
     assert_err!(
         Value,
         "V(-1 -2)",
         1,
-        "V(-1 ".len(),
+        "V(-1 ".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::ListEnd,
             found: TokenType::Text,
+            text: Some(_),
         }
     );
 }
@@ -360,37 +407,40 @@ fn enum_tuple_variant_tests() {
 
     assert_ok!(Value, "V(-1 -2)", TupleVariant::V(-1, -2));
 
-    let err = unwrap_err!(Value, "!", 1, 0);
+    let err = unwrap_err!(Value, "!", 1, 1);
     assert_matches!(err.code(), ErrorCode::Custom(s) if s.contains("unknown variant"));
 
     assert_err!(
         Value,
         "V()",
         1,
-        "V(".len(),
+        "V(".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::Text,
             found: TokenType::ListEnd,
+            text: None,
         }
     );
     assert_err!(
         Value,
         "V(-1)",
         1,
-        "V(-1".len(),
+        "V(-1".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::Text,
             found: TokenType::ListEnd,
+            text: None,
         }
     );
     assert_err!(
         Value,
         "V(-1 -2 -3)",
         1,
-        "V(-1 -2 ".len(),
+        "V(-1 -2 ".len() + 1,
         ErrorCode::ExpectedToken {
             expected: TokenType::ListEnd,
             found: TokenType::Text,
+            text: Some(_),
         }
     );
 }
@@ -402,7 +452,7 @@ fn enum_struct_variant_tests() {
     assert_ok!(Value, "V(a -1 b -2)", StructVariant::V { a: -1, b: -2 });
     assert_ok!(Value, "V(b -2 a -1)", StructVariant::V { a: -1, b: -2 });
 
-    let err = unwrap_err!(Value, "!", 1, 0);
+    let err = unwrap_err!(Value, "!", 1, 1);
     assert_matches!(err.code(), ErrorCode::Custom(s) if s.contains("unknown variant"));
 }
 
@@ -416,3 +466,152 @@ fn enum_struct_variant_optional_tests() {
     assert_ok!(Value, "V(a -1)", OptStructVariant::V { a: -1, b: 0 });
     assert_ok!(Value, "V(b -2)", OptStructVariant::V { a: 0, b: -2 });
 }
+
+#[test]
+fn location_tests() {
+    // the first character of every line is column 1
+    assert_err!(i32, "a", 1, 1, ErrorCode::ParseIntError { .. });
+    assert_err!(i32, "\na", 2, 1, ErrorCode::ParseIntError { .. });
+    assert_err!(i32, "\r\na", 2, 1, ErrorCode::ParseIntError { .. });
+    assert_err!(i32, "\n\t a", 2, 3, ErrorCode::ParseIntError { .. });
+    assert_err!(Vec<i32>, "(1\n2\na)", 3, 1, ErrorCode::ParseIntError { .. });
+    // newlines inside quotes
+    let err = unwrap_err!((String, i32), "(\"a\nb\" x)", 2, "b\" ".len() + 1);
+    assert_matches!(err.code(), ErrorCode::ParseIntError { .. });
+    let err = unwrap_err!((String, i32), "(\"a\r\nb\" x)", 2, "b\" ".len() + 1);
+    assert_matches!(err.code(), ErrorCode::ParseIntError { .. });
+    let err = unwrap_err!((String, i32), "(\"a\r\n\r\nb\" x)", 3, "b\" ".len() + 1);
+    assert_matches!(err.code(), ErrorCode::ParseIntError { .. });
+}
+
+#[test]
+fn quoted_newline_tests() {
+    // Windows newlines inside quotes are kept verbatim
+    let v: Vec<String> = from_str("(\"a\r\nb\" \"c\nd\")").unwrap();
+    assert_eq!(v, vec!["a\r\nb", "c\nd"]);
+}
+
+#[test]
+fn line_comment_tests() {
+    let config = ReaderConfig::builder().line_comments(true).build();
+    let s = "; leading comment\r\n(\r\n\ta -1 ; the first field\r\n\tb;no space\r\n\t-2 ;\r\n) ; trailing";
+    let v: Struct = from_str_with_config(s, &config).unwrap();
+    assert_eq!(v, Struct { a: -1, b: -2 });
+
+    // comments inside quotes are kept
+    let v: Vec<String> = from_str_with_config("(\"a ; b\" c;d)\n)", &config).unwrap();
+    assert_eq!(v, vec!["a ; b", "c"]);
+
+    // comments at the end of the data, without a newline
+    let v: i32 = from_str_with_config("1;", &config).unwrap();
+    assert_eq!(v, 1);
+
+    // errors after comments are reported on the correct line
+    let err = from_str_with_config::<Struct>("; comment\n(a x)", &config).unwrap_err();
+    assert_eq!(err.location(), Some(&Location::new(2, 4)));
+
+    // without the option, a `;` is text
+    let v: Vec<String> = from_str("(a;b ;)").unwrap();
+    assert_eq!(v, vec!["a;b", ";"]);
+}
+
+#[test]
+fn unexpected_eof_tests() {
+    let err = unwrap_err!(i32, "", 1, 1);
+    assert_matches!(
+        err.code(),
+        ErrorCode::UnexpectedEof {
+            expected: TokenType::Text
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "expected text, found end of file (at line: 1, column: 1)"
+    );
+
+    let err = unwrap_err!(Vec<i32>, "(1 2\n", 2, 1);
+    assert_matches!(err.code(), ErrorCode::UnexpectedEof { .. });
+
+    // other mismatches are not the end of the data
+    let err = unwrap_err!(Vec<i32>, "(1 2))", 1, "(1 2)".len() + 1);
+    assert_matches!(
+        err.code(),
+        ErrorCode::ExpectedToken {
+            expected: TokenType::Eof,
+            found: TokenType::ListEnd,
+            ..
+        }
+    );
+}
+
+#[test]
+fn expected_token_text_tests() {
+    let err = unwrap_err!((i32,), "(-1 foo)", 1, "(-1 ".len() + 1);
+    assert_matches!(
+        err.code(),
+        ErrorCode::ExpectedToken {
+            expected: TokenType::ListEnd,
+            found: TokenType::Text,
+            text: Some(s),
+        } if s == "foo"
+    );
+    assert_eq!(
+        err.to_string(),
+        "expected end of list, found text `foo` (at line: 1, column: 5)"
+    );
+
+    // quoted text is reported without quotes
+    let err = unwrap_err!((), "\"b a r\"", 1, 1);
+    assert_matches!(
+        err.code(),
+        ErrorCode::ExpectedToken { text: Some(s), .. } if s == "b a r"
+    );
+
+    let err = unwrap_err!(i32, "()", 1, 1);
+    assert_matches!(err.code(), ErrorCode::ExpectedToken { text: None, .. });
+    assert_eq!(
+        err.to_string(),
+        "expected text, found start of list (at line: 1, column: 1)"
+    );
+}
+
+#[test]
+fn error_source_tests() {
+    use std::error::Error as _;
+
+    let err = unwrap_err!(i32, "a", 1, 1);
+    assert_eq!(
+        err.to_string(),
+        "invalid integer `a` (at line: 1, column: 1)"
+    );
+    let source = err.source().unwrap();
+    assert!(source.is::<std::num::ParseIntError>());
+    assert_eq!(source.to_string(), "invalid digit found in string");
+
+    let err = unwrap_err!(f32, "a", 1, 1);
+    assert_eq!(err.to_string(), "invalid float `a` (at line: 1, column: 1)");
+    let source = err.source().unwrap();
+    assert!(source.is::<std::num::ParseFloatError>());
+    assert_eq!(source.to_string(), "invalid float literal");
+
+    let err = unwrap_err!(i32, "()", 1, 1);
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn error_eq_tests() {
+    use std::error::Error as _;
+
+    let err = unwrap_err!(i32, "a", 1, 1);
+    let cloned = err.clone();
+    assert_eq!(cloned, err);
+    // the source is kept
+    assert!(cloned.source().unwrap().is::<std::num::ParseIntError>());
+    assert_eq!(err.code().clone(), *cloned.code());
+
+    // errors at different locations are different
+    let other = unwrap_err!((i32,), "(a)", 1, 2);
+    assert_eq!(other.code(), err.code());
+    assert_ne!(other, err);
+    assert_ne!(unwrap_err!(i32, "b", 1, 1), err);
+}
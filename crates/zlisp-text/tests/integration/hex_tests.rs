@@ -0,0 +1,72 @@
+use serde_derive::{Deserialize, Serialize};
+use zlisp_hex::{Hex, HexU32};
+use zlisp_text::{from_str, to_pretty, to_string, to_string_canonical, WhitespaceConfig};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct HexStruct {
+    a: Hex,
+    b: i32,
+}
+
+#[test]
+fn hex_round_trip_tests() {
+    let expected = HexStruct {
+        a: 0xdead.try_into().unwrap(),
+        b: -1,
+    };
+    let config = WhitespaceConfig::builder()
+        .indent(" ")
+        .delimiter(" ")
+        .newline("\n")
+        .build();
+    let s = to_pretty(&expected, &config).unwrap();
+    assert_eq!(s, "(a 0xdead b -1)\n");
+    let actual: HexStruct = from_str(&s).unwrap();
+    assert_eq!(actual, expected);
+
+    let s = to_string(&expected, &config).unwrap();
+    let actual: HexStruct = from_str(&s).unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn hex_human_readable_tests() {
+    let value: Hex = 0xdead.try_into().unwrap();
+    let config = WhitespaceConfig::builder().newline("\n").build();
+    // all text writers must agree on human-readability
+    assert_eq!(to_string(&value, &config).unwrap(), "0xdead\n");
+    assert_eq!(to_pretty(&value, &config).unwrap(), "0xdead\n");
+    assert_eq!(to_string_canonical(&value).unwrap(), "0xdead");
+    let list = vec![value, value];
+    assert_eq!(to_pretty(&list, &config).unwrap(), "(0xdead\t0xdead)\n");
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct HexU32Struct {
+    a: HexU32,
+    b: HexU32,
+}
+
+#[test]
+fn hex_u32_round_trip_tests() {
+    let expected = HexU32Struct {
+        a: HexU32::new(0xFFFFFFFF),
+        b: HexU32::new(0x80000000),
+    };
+    let config = WhitespaceConfig::builder()
+        .indent(" ")
+        .delimiter(" ")
+        .newline("\n")
+        .build();
+    let s = to_pretty(&expected, &config).unwrap();
+    assert_eq!(s, "(a 0xffffffff b 0x80000000)\n");
+    let actual: HexU32Struct = from_str(&s).unwrap();
+    assert_eq!(actual, expected);
+
+    let s = to_string(&expected, &config).unwrap();
+    let actual: HexU32Struct = from_str(&s).unwrap();
+    assert_eq!(actual, expected);
+
+    let err = from_str::<HexU32>("0x100000000").unwrap_err();
+    assert_eq!(err.to_string(), "invalid hexadecimal value: 0x100000000");
+}
@@ -0,0 +1,30 @@
+use serde_json::{json, Value};
+use zlisp_text::from_str;
+
+// lists always become JSON arrays. zlisp has no map type, so structs and maps
+// (which are flat lists of keys and values) also become arrays, not objects.
+
+#[test]
+fn scalar_tests() {
+    assert_eq!(from_str::<Value>("-1").unwrap(), json!(-1));
+    assert_eq!(from_str::<Value>("1.5").unwrap(), json!(1.5));
+    assert_eq!(from_str::<Value>("foo").unwrap(), json!("foo"));
+    assert_eq!(from_str::<Value>("\"1\"").unwrap(), json!("1"));
+}
+
+#[test]
+fn list_tests() {
+    assert_eq!(from_str::<Value>("()").unwrap(), json!([]));
+    let v: Value = from_str("(1 2.5 foo (bar ()))").unwrap();
+    assert_eq!(v, json!([1, 2.5, "foo", ["bar", []]]));
+    // maps are flat lists
+    let v: Value = from_str("(a 1 b 2)").unwrap();
+    assert_eq!(v, json!(["a", 1, "b", 2]));
+}
+
+#[test]
+fn pretty_print_tests() {
+    let v: Value = from_str("(1 (foo))").unwrap();
+    let s = serde_json::to_string_pretty(&v).unwrap();
+    assert_eq!(s, "[\n  1,\n  [\n    \"foo\"\n  ]\n]");
+}
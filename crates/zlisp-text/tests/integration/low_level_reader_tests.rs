@@ -0,0 +1,107 @@
+use assert_matches::assert_matches;
+use zlisp_text::{ErrorCode, Location, Reader, Result, TokenType};
+
+#[derive(Debug, PartialEq)]
+enum Shape {
+    Circle(f32),
+    Rect(i32, i32),
+}
+
+/// A format where the structure depends on earlier values, i.e. a count
+/// followed by that many shapes, each tagged by name.
+fn read_shapes(input: &str) -> Result<Vec<Shape>> {
+    let mut reader = Reader::new(input);
+    let shapes = reader.read_list(|reader| {
+        let count = reader.read_i32()?;
+        let mut shapes = Vec::new();
+        for _ in 0..count {
+            let shape = reader.read_list(|reader| match reader.read_string()?.as_str() {
+                "circle" => Ok(Shape::Circle(reader.read_f32()?)),
+                _ => Ok(Shape::Rect(reader.read_i32()?, reader.read_i32()?)),
+            })?;
+            shapes.push(shape);
+        }
+        Ok(shapes)
+    })?;
+    reader.finish()?;
+    Ok(shapes)
+}
+
+#[test]
+fn read_tests() {
+    let shapes = read_shapes("(2 (circle 1.5) (rect 2 3))").unwrap();
+    assert_eq!(shapes, vec![Shape::Circle(1.5), Shape::Rect(2, 3)]);
+
+    let shapes = read_shapes("(0)").unwrap();
+    assert!(shapes.is_empty());
+}
+
+#[test]
+fn read_error_tests() {
+    // too few shapes
+    let err = read_shapes("(2 (circle 1.5))").unwrap_err();
+    assert_matches!(
+        err.code(),
+        ErrorCode::ExpectedToken {
+            expected: TokenType::ListStart,
+            found: TokenType::ListEnd,
+            ..
+        }
+    );
+    assert_eq!(err.location(), Some(&Location::new(1, 16)));
+
+    // too many shapes
+    let err = read_shapes("(1 (circle 1.5) (rect 2 3))").unwrap_err();
+    assert_matches!(
+        err.code(),
+        ErrorCode::ExpectedToken {
+            expected: TokenType::ListEnd,
+            found: TokenType::ListStart,
+            ..
+        }
+    );
+
+    // trailing data
+    let err = read_shapes("(0) (0)").unwrap_err();
+    assert_matches!(
+        err.code(),
+        ErrorCode::ExpectedToken {
+            expected: TokenType::Eof,
+            ..
+        }
+    );
+}
+
+#[test]
+fn peek_tests() {
+    let mut reader = Reader::new("(a ()) ");
+    assert_eq!(reader.peek().unwrap(), TokenType::ListStart);
+    // peeking doesn't consume the token
+    assert_eq!(reader.peek().unwrap(), TokenType::ListStart);
+    reader.read_list_start().unwrap();
+    assert_eq!(reader.peek().unwrap(), TokenType::Text);
+    assert_eq!(reader.location(), Location::new(1, 2));
+    assert_eq!(reader.read_string().unwrap(), "a");
+    assert_eq!(reader.peek().unwrap(), TokenType::ListStart);
+    reader.read_list(|reader| reader.peek()).unwrap();
+    assert_eq!(reader.peek().unwrap(), TokenType::ListEnd);
+    reader.read_list_end().unwrap();
+    assert_eq!(reader.peek().unwrap(), TokenType::Eof);
+    reader.finish().unwrap();
+}
+
+#[test]
+fn read_list_variable_length_tests() {
+    let mut reader = Reader::new("(1 2 3)");
+    let sum = reader
+        .read_list(|reader| {
+            let mut sum = 0;
+            while reader.peek()? != TokenType::ListEnd {
+                sum += reader.read_i32()?;
+            }
+            Ok(sum)
+        })
+        .unwrap();
+    assert_eq!(sum, 6);
+    reader.finish().unwrap();
+}
@@ -1,10 +1,31 @@
+mod canonical_tests;
+mod events_tests;
+mod flatten_tests;
 mod from_str_de_tests;
+mod hex_tests;
+mod json_value_tests;
+mod low_level_reader_tests;
+mod paren_escape_tests;
+mod quote_escape_tests;
+mod reader_config_tests;
+mod records_tests;
+mod recursion_limit_tests;
 mod round_trip_tests;
+mod serialized_len_tests;
+mod snippet_tests;
+mod spanned_tests;
+mod stream_tests;
 mod string_quoting_tests;
 mod structs;
 mod to_pretty_fmt_tests;
 mod to_pretty_ser_tests;
 mod to_string_ser_tests;
+mod to_writer_tests;
+mod tokenize_tests;
+mod trailing_newline_tests;
+mod value_tests;
+mod whitespace_config_tests;
+mod write_element_tests;
 
 #[macro_export]
 macro_rules! map {
@@ -0,0 +1,70 @@
+use zlisp_text::{from_str_with_config, to_pretty, to_string, ReaderConfig, WhitespaceConfig};
+
+const WRITE: WhitespaceConfig<'static> = WhitespaceConfig::builder().paren_escape(true).build();
+const READ: ReaderConfig = ReaderConfig::builder().paren_escape(true).build();
+
+macro_rules! assert_escaped {
+    ($value:expr, $output:expr) => {
+        let o = concat!($output, "\r\n");
+        let v: String = String::from($value);
+        let s = to_string(&v, &WRITE).expect("to_string");
+        assert_eq!(&s, o, "to_string");
+        let s = to_pretty(&v, &WRITE).expect("to_pretty");
+        assert_eq!(&s, o, "to_pretty");
+        let r: String = from_str_with_config(&s, &READ).expect("from_str");
+        assert_eq!(&r, $value, "from_str");
+    };
+}
+
+#[test]
+fn escape_tests() {
+    assert_escaped!("a(b)", "a\\(b\\)");
+    assert_escaped!("()", "\\(\\)");
+    assert_escaped!("a\\(", "a\\\\(");
+    assert_escaped!("a\\b", "a\\b");
+    assert_escaped!("foo", "foo");
+    // strings requiring quotes for other reasons are still quoted
+    assert_escaped!("a (b)", "\"a (b)\"");
+    assert_escaped!("", "\"\"");
+    assert_escaped!("0", "\"0\"");
+}
+
+#[test]
+fn escape_disabled_tests() {
+    let v = String::from("a(b)");
+    let s = to_string(&v, WhitespaceConfig::default()).unwrap();
+    assert_eq!(s, "\"a(b)\"\r\n");
+    let s = to_pretty(&v, WhitespaceConfig::default()).unwrap();
+    assert_eq!(s, "\"a(b)\"\r\n");
+}
+
+#[test]
+fn read_tests() {
+    let v: Vec<String> = from_str_with_config("(a\\(b\\) \"c d\"\\( e)", &READ).unwrap();
+    assert_eq!(v, vec!["a(b)", "c d(", "e"]);
+
+    // escapes are not decoded inside quotes
+    let v: String = from_str_with_config("\"a\\(\"", &READ).unwrap();
+    assert_eq!(v, "a\\(");
+
+    // escapes are not decoded by default
+    let v: (String, Vec<String>) =
+        from_str_with_config("(a\\())", ReaderConfig::default()).unwrap();
+    assert_eq!(v, (String::from("a\\"), Vec::new()));
+}
+
+#[test]
+fn round_trip_tests() {
+    let expected = vec![
+        String::from("f(x)"),
+        String::from("(g)"),
+        String::from("plain"),
+        String::from("with space(s)"),
+    ];
+    let s = to_string(&expected, &WRITE).unwrap();
+    let actual: Vec<String> = from_str_with_config(&s, &READ).unwrap();
+    assert_eq!(actual, expected);
+    let s = to_pretty(&expected, &WRITE).unwrap();
+    let actual: Vec<String> = from_str_with_config(&s, &READ).unwrap();
+    assert_eq!(actual, expected);
+}
@@ -0,0 +1,91 @@
+use assert_matches::assert_matches;
+use zlisp_text::{
+    from_str, from_str_with_config, to_pretty, to_string, to_string_canonical, ErrorCode, Location,
+    ReaderConfig, WhitespaceConfig,
+};
+
+const WRITE: WhitespaceConfig<'static> = WhitespaceConfig::builder().quote_escape(true).build();
+const READ: ReaderConfig = ReaderConfig::builder().quote_escape(true).build();
+
+macro_rules! assert_escaped {
+    ($value:expr, $output:expr) => {
+        let o = concat!($output, "\r\n");
+        let v: String = String::from($value);
+        let s = to_string(&v, &WRITE).expect("to_string");
+        assert_eq!(&s, o, "to_string");
+        let s = to_pretty(&v, &WRITE).expect("to_pretty");
+        assert_eq!(&s, o, "to_pretty");
+        let r: String = from_str_with_config(&s, &READ).expect("from_str");
+        assert_eq!(&r, $value, "from_str");
+    };
+}
+
+#[test]
+fn escape_tests() {
+    assert_escaped!("a\"b", "\"a\\\"b\"");
+    assert_escaped!("\"", "\"\\\"\"");
+    assert_escaped!("a\nb", "\"a\\nb\"");
+    assert_escaped!("a\tb", "\"a\\tb\"");
+    assert_escaped!("a \\b", "\"a \\\\b\"");
+    // strings that don't require quotes aren't escaped
+    assert_escaped!("a\\b", "a\\b");
+    assert_escaped!("foo", "foo");
+    assert_escaped!("", "\"\"");
+}
+
+#[test]
+fn escape_disabled_tests() {
+    let v = String::from("a\"b");
+    let err = to_string(&v, WhitespaceConfig::default()).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::StringContainsQuote);
+    let err = to_pretty(&v, WhitespaceConfig::default()).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::StringContainsQuote);
+    let err = to_string_canonical(&v).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::StringContainsQuote);
+
+    // without escapes, quoted strings are written verbatim
+    let v = String::from("a\nb\\");
+    let s = to_string(&v, WhitespaceConfig::default()).unwrap();
+    assert_eq!(s, "\"a\nb\\\"\r\n");
+}
+
+#[test]
+fn read_tests() {
+    let v: Vec<String> =
+        from_str_with_config("(\"a\\\"b\" \"\\\\\" \"\\n\\t\" c\\d)", &READ).unwrap();
+    assert_eq!(v, vec!["a\"b", "\\", "\n\t", "c\\d"]);
+
+    // escapes are not decoded by default
+    let v: String = from_str("\"a\\nb\"").unwrap();
+    assert_eq!(v, "a\\nb");
+}
+
+#[test]
+fn read_error_tests() {
+    let err = from_str_with_config::<String>("\"a\\xb\"", &READ).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::InvalidEscape);
+    assert_eq!(err.location(), Some(&Location::new(1, 3)));
+    assert_eq!(
+        err.to_string(),
+        "invalid escape sequence (at line: 1, column: 3)"
+    );
+
+    let err = from_str_with_config::<String>("\"a\\", &READ).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::EofWhileParsingQuote);
+}
+
+#[test]
+fn round_trip_tests() {
+    let expected = vec![
+        String::from("say \"hi\""),
+        String::from("line 1\r\nline 2"),
+        String::from("C:\\path with spaces\\"),
+        String::from("\"(\")"),
+    ];
+    let s = to_pretty(&expected, &WRITE).unwrap();
+    let actual: Vec<String> = from_str_with_config(&s, &READ).unwrap();
+    assert_eq!(actual, expected);
+    let s = to_string(&expected, &WRITE).unwrap();
+    let actual: Vec<String> = from_str_with_config(&s, &READ).unwrap();
+    assert_eq!(actual, expected);
+}
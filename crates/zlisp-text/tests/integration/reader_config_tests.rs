@@ -0,0 +1,285 @@
+use assert_matches::assert_matches;
+use std::collections::{BTreeSet, HashSet};
+use zlisp_text::{
+    from_str, from_str_with_config, ErrorCode, Location, NonFinitePolicy, ReaderConfig,
+};
+use zlisp_value::Value;
+
+#[test]
+fn max_elements_tests() {
+    let config = ReaderConfig::builder().max_elements(3).build();
+
+    // the list counts as an element
+    let v: Vec<i32> = from_str_with_config("(1 2)", &config).unwrap();
+    assert_eq!(v, vec![1, 2]);
+
+    let err = from_str_with_config::<Vec<i32>>("(1 2 3)", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ElementLimitExceeded);
+    assert_eq!(err.location(), Some(&Location::new(1, "(1 2 ".len() + 1)));
+
+    // nested lists count as well
+    let err = from_str_with_config::<Vec<Vec<i32>>>("(() () ())", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ElementLimitExceeded);
+
+    // a dense input is rejected before it is fully read
+    let dense = format!("({})", "0 ".repeat(100_000));
+    let err = from_str_with_config::<Vec<i32>>(&dense, &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ElementLimitExceeded);
+}
+
+#[test]
+fn max_elements_default_tests() {
+    assert_eq!(ReaderConfig::default().max_elements(), None);
+
+    let dense = format!("({})", "0 ".repeat(10_000));
+    let config = ReaderConfig::builder().build();
+    let v: Vec<i32> = from_str_with_config(&dense, &config).unwrap();
+    assert_eq!(v.len(), 10_000);
+}
+
+#[test]
+fn lenient_whitespace_tests() {
+    assert_eq!(ReaderConfig::default().lenient_whitespace(), None);
+    let config = ReaderConfig::builder().lenient_whitespace(',').build();
+    assert_eq!(config.lenient_whitespace(), Some(','));
+
+    let v: Vec<i32> = from_str_with_config("(1, 2 ,3,)", &config).unwrap();
+    assert_eq!(v, vec![1, 2, 3]);
+    let v: Vec<String> = from_str_with_config("(,a,,\"b,c\" d,)", &config).unwrap();
+    assert_eq!(v, vec!["a", "b,c", "d"]);
+
+    // strict by default
+    let err = from_str_with_config::<Vec<i32>>("(1, 2)", ReaderConfig::default()).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ParseIntError { .. });
+}
+
+#[test]
+fn deny_duplicates_tests() {
+    assert!(!ReaderConfig::default().deny_duplicates());
+    let config = ReaderConfig::builder().deny_duplicates(true).build();
+
+    let v: HashSet<i32> = from_str_with_config("(1 2 1)", ReaderConfig::default()).unwrap();
+    assert_eq!(v.len(), 2);
+
+    let err = from_str_with_config::<HashSet<i32>>("(1 2 1)", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::DuplicateElement);
+    assert_eq!(err.location(), Some(&Location::new(1, "(1 2 ".len() + 1)));
+    let err = from_str_with_config::<Vec<i32>>("(1 2 1)", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::DuplicateElement);
+
+    let v: BTreeSet<i32> = from_str_with_config("(1 2 3)", &config).unwrap();
+    assert_eq!(v.len(), 3);
+
+    // whitespace and quoting are ignored
+    let err = from_str_with_config::<HashSet<String>>("(a \"a\")", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::DuplicateElement);
+    let err = from_str_with_config::<HashSet<Vec<i32>>>("((1 2)\n(1\t2))", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::DuplicateElement);
+    let v: HashSet<Vec<i32>> = from_str_with_config("((1 2) (2 1))", &config).unwrap();
+    assert_eq!(v.len(), 2);
+
+    // numbers are compared by value, not by how they are written
+    for input in ["(1 01)", "(1 +1)", "(-0 0)", "((1 2) (01 +2))"] {
+        let err = from_str_with_config::<HashSet<Value>>(input, &config).unwrap_err();
+        assert_matches!(err.code(), ErrorCode::DuplicateElement, "{}", input);
+    }
+    let err = from_str_with_config::<BTreeSet<i32>>("(1 01)", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::DuplicateElement);
+    let err = from_str_with_config::<Vec<f32>>("(1.0 1.00)", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::DuplicateElement);
+    let err = from_str_with_config::<Vec<f32>>("(1 1.0)", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::DuplicateElement);
+    // but strings are compared as text
+    let v: HashSet<String> = from_str_with_config("(1 01 +1)", &config).unwrap();
+    assert_eq!(v.len(), 3);
+}
+
+#[test]
+fn saturate_ints_tests() {
+    assert!(!ReaderConfig::default().saturate_ints());
+    let config = ReaderConfig::builder().saturate_ints(true).build();
+
+    let err = from_str_with_config::<i32>("99999999999", ReaderConfig::default()).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ParseIntError { .. });
+
+    let v: i32 = from_str_with_config("99999999999", &config).unwrap();
+    assert_eq!(v, i32::MAX);
+    let v: Vec<i32> =
+        from_str_with_config("(-99999999999 2147483648 -2147483649 1)", &config).unwrap();
+    assert_eq!(v, vec![i32::MIN, i32::MAX, i32::MIN, 1]);
+
+    // invalid integers are still errors
+    let err = from_str_with_config::<i32>("1a", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ParseIntError { .. });
+}
+
+#[test]
+fn hex_ints_tests() {
+    assert!(!ReaderConfig::default().hex_ints());
+    let config = ReaderConfig::builder().hex_ints(true).build();
+
+    let v: Value = from_str_with_config("0xff", ReaderConfig::default()).unwrap();
+    assert_eq!(v, Value::String("0xff".to_owned()));
+    let v: Value = from_str_with_config("0xff", &config).unwrap();
+    assert_eq!(v, Value::Int(255));
+
+    let v: Value = from_str_with_config("(0x1f \"0x1f\" 0xzz 1)", &config).unwrap();
+    assert_eq!(
+        v,
+        Value::List(vec![
+            Value::Int(0x1f),
+            Value::String("0x1f".to_owned()),
+            Value::String("0xzz".to_owned()),
+            Value::Int(1),
+        ])
+    );
+
+    // typed reads are not affected
+    let err = from_str_with_config::<i32>("0xff", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ParseIntError { .. });
+}
+
+#[test]
+fn bools_tests() {
+    assert!(!ReaderConfig::default().bools());
+    let config = ReaderConfig::builder().bools(true).build();
+
+    let err = from_str::<bool>("true").unwrap_err();
+    assert_matches!(err.code(), ErrorCode::UnsupportedType);
+
+    assert!(from_str_with_config::<bool>("true", &config).unwrap());
+    assert!(!from_str_with_config::<bool>("false", &config).unwrap());
+    assert!(from_str_with_config::<bool>("TRUE", &config).unwrap());
+    assert!(!from_str_with_config::<bool>("False", &config).unwrap());
+    let v: Vec<bool> = from_str_with_config("(true false)", &config).unwrap();
+    assert_eq!(v, vec![true, false]);
+
+    let err = from_str_with_config::<bool>("1", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ParseBoolError { s } if s == "1");
+    assert_eq!(err.location(), Some(&Location::new(1, 1)));
+    let err = from_str_with_config::<bool>("\"true\"", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::QuotedString);
+
+    // untyped reads are not affected
+    let v: Value = from_str_with_config("true", &config).unwrap();
+    assert_eq!(v, Value::String("true".to_owned()));
+}
+
+#[test]
+fn chars_tests() {
+    assert!(!ReaderConfig::default().chars());
+    let config = ReaderConfig::builder().chars(true).build();
+
+    let err = from_str::<char>("x").unwrap_err();
+    assert_matches!(err.code(), ErrorCode::UnsupportedType);
+
+    assert_eq!(from_str_with_config::<char>("x", &config).unwrap(), 'x');
+    assert_eq!(from_str_with_config::<char>("\" \"", &config).unwrap(), ' ');
+    assert_eq!(from_str_with_config::<char>("7", &config).unwrap(), '7');
+    let v: Vec<char> = from_str_with_config("(a b c)", &config).unwrap();
+    assert_eq!(v, vec!['a', 'b', 'c']);
+
+    let err = from_str_with_config::<char>("xy", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ParseCharError { s } if s == "xy");
+    assert_eq!(err.location(), Some(&Location::new(1, 1)));
+    let err = from_str_with_config::<char>("\"\"", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ParseCharError { s } if s.is_empty());
+}
+
+#[test]
+fn infer_maps_tests() {
+    use serde_derive::Deserialize;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    let config = ReaderConfig::builder().infer_maps(true).build();
+    assert!(config.infer_maps());
+
+    let v: serde_json::Value = from_str_with_config("(a 1 b 2)", &config).unwrap();
+    assert_eq!(v, json!({ "a": 1, "b": 2 }));
+    let v: serde_json::Value = from_str_with_config("(a (b (1 2)) c ())", &config).unwrap();
+    assert_eq!(v, json!({ "a": { "b": [1, 2] }, "c": [] }));
+
+    // lists that can't be maps are still sequences
+    let cases = [
+        ("()", json!([])),
+        ("(a 1 b)", json!(["a", 1, "b"])),
+        ("(1 a 2 b)", json!([1, "a", 2, "b"])),
+        ("(a 1 (b) 2)", json!(["a", 1, ["b"], 2])),
+    ];
+    for (input, expected) in cases {
+        let v: serde_json::Value = from_str_with_config(input, &config).unwrap();
+        assert_eq!(v, expected, "{}", input);
+    }
+
+    // maps are also inferred for untagged enums
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(untagged)]
+    enum Untagged {
+        Map(HashMap<String, i32>),
+        Seq(Vec<i32>),
+    }
+    let v: Untagged = from_str_with_config("(a 1 b 2)", &config).unwrap();
+    let expected: HashMap<String, i32> = [("a".to_owned(), 1), ("b".to_owned(), 2)].into();
+    assert_eq!(v, Untagged::Map(expected));
+
+    // errors are still reported
+    let err = from_str_with_config::<serde_json::Value>("(a 1 b", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::UnexpectedEof { .. });
+    let config = ReaderConfig::builder()
+        .infer_maps(true)
+        .max_depth(2)
+        .build();
+    let err = from_str_with_config::<serde_json::Value>("(a (b (c 1)))", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::RecursionLimitExceeded);
+    assert_eq!(err.location(), Some(&Location::new(1, 7)));
+    let config = ReaderConfig::builder().infer_maps(true).build();
+
+    // deeply nested lists are read in one pass
+    let depth = 100;
+    let input = format!("{}1{}", "(a ".repeat(depth), ")".repeat(depth));
+    let mut v: serde_json::Value = from_str_with_config(&input, &config).unwrap();
+    for _ in 0..depth {
+        v = v["a"].take();
+    }
+    assert_eq!(v, json!(1));
+
+    // disabled by default
+    let v: serde_json::Value = from_str_with_config("(a 1 b 2)", ReaderConfig::default()).unwrap();
+    assert_eq!(v, json!(["a", 1, "b", 2]));
+}
+
+#[test]
+fn non_finite_tests() {
+    assert_eq!(
+        ReaderConfig::default().non_finite(),
+        NonFinitePolicy::Reject
+    );
+    let err = from_str_with_config::<f32>("inf", ReaderConfig::default()).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ParseFloatError { .. });
+
+    let config = ReaderConfig::builder()
+        .non_finite(NonFinitePolicy::Saturate)
+        .build();
+    let v: Vec<f32> = from_str_with_config("(inf -inf 1.5)", &config).unwrap();
+    assert_eq!(v, vec![f32::MAX, f32::MIN, 1.5]);
+    let err = from_str_with_config::<f32>("NaN", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ParseFloatError { .. });
+
+    let config = ReaderConfig::builder()
+        .non_finite(NonFinitePolicy::Zero)
+        .build();
+    let v: Vec<f32> = from_str_with_config("(inf NaN 1.5)", &config).unwrap();
+    assert_eq!(v, vec![0.0, 0.0, 1.5]);
+
+    // untyped reads are not affected
+    let v: Value = from_str_with_config("inf", &config).unwrap();
+    assert_eq!(v, Value::String("inf".to_string()));
+}
+
+#[test]
+fn line_comments_tests() {
+    assert!(!ReaderConfig::default().line_comments());
+    let config = ReaderConfig::builder().line_comments(true).build();
+    assert!(config.line_comments());
+}
@@ -0,0 +1,76 @@
+use serde_derive::{Deserialize, Serialize};
+use zlisp_text::{from_str, from_str_many, to_pretty_many, ErrorCode, WhitespaceConfig};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: i32,
+    name: String,
+}
+
+fn records() -> Vec<Record> {
+    vec![
+        Record {
+            id: 1,
+            name: "foo".to_owned(),
+        },
+        Record {
+            id: 2,
+            name: "bar".to_owned(),
+        },
+    ]
+}
+
+#[test]
+fn round_trip_tests() {
+    let config = WhitespaceConfig::default();
+    let expected = records();
+    let s = to_pretty_many(&expected, config).unwrap();
+    let actual: Vec<Record> = from_str_many(&s).unwrap();
+    assert_eq!(actual, expected);
+
+    let s = to_pretty_many(Vec::<Record>::new(), config).unwrap();
+    assert_eq!(&s, "");
+    let actual: Vec<Record> = from_str_many(&s).unwrap();
+    assert!(actual.is_empty());
+}
+
+#[test]
+fn record_separator_tests() {
+    let config = WhitespaceConfig::builder().newline("\n").build();
+    assert_eq!(config.record_separator(), "\r\n");
+    let s = to_pretty_many([1, 2, 3], &config).unwrap();
+    assert_eq!(&s, "1\n\r\n2\n\r\n3\n");
+
+    let config = WhitespaceConfig::builder()
+        .newline("\n")
+        .record_separator("\n")
+        .build();
+    let s = to_pretty_many(records(), &config).unwrap();
+    assert_eq!(&s, "(id\t1\tname\tfoo)\n\n(id\t2\tname\tbar)\n");
+
+    // records on consecutive lines
+    let config = WhitespaceConfig::builder()
+        .newline("\n")
+        .record_separator("")
+        .build();
+    let s = to_pretty_many([1, 2, 3], &config).unwrap();
+    assert_eq!(&s, "1\n2\n3\n");
+    let actual: Vec<i32> = from_str_many(&s).unwrap();
+    assert_eq!(actual, vec![1, 2, 3]);
+
+    // a single value is unaffected
+    let s = to_pretty_many([1], &config).unwrap();
+    assert_eq!(&s, "1\n");
+}
+
+#[test]
+fn from_str_many_tests() {
+    let actual: Vec<i32> = from_str_many(" 1 2\n\n3 ").unwrap();
+    assert_eq!(actual, vec![1, 2, 3]);
+
+    let err = from_str_many::<i32>("1 a").unwrap_err();
+    assert!(matches!(err.code(), ErrorCode::ParseIntError { .. }));
+
+    // a single value still requires exactly one record
+    from_str::<i32>("1 2").unwrap_err();
+}
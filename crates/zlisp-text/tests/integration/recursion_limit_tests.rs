@@ -0,0 +1,51 @@
+use assert_matches::assert_matches;
+use zlisp_text::{events, from_str, from_str_with_config, ErrorCode, Location, ReaderConfig};
+use zlisp_value::Value;
+
+fn nested(depth: usize) -> String {
+    format!("{}0{}", "(".repeat(depth), ")".repeat(depth))
+}
+
+#[test]
+fn max_depth_tests() {
+    assert_eq!(ReaderConfig::default().max_depth(), 128);
+    let config = ReaderConfig::builder().max_depth(2).build();
+    assert_eq!(config.max_depth(), 2);
+}
+
+#[test]
+fn within_limit_tests() {
+    let s = nested(128);
+    from_str::<Value>(&s).unwrap();
+    from_str::<serde_json::Value>(&s).unwrap();
+}
+
+#[test]
+fn deeply_nested_tests() {
+    let s = nested(10_000);
+    let location = Some(Location::new(1, 129));
+
+    let err = from_str::<Value>(&s).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::RecursionLimitExceeded);
+    assert_eq!(err.location(), location.as_ref());
+
+    let err = from_str::<serde_json::Value>(&s).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::RecursionLimitExceeded);
+    assert_eq!(err.location(), location.as_ref());
+
+    // events don't recurse, but are limited all the same
+    let err = events(&s).find_map(Result::err).unwrap();
+    assert_matches!(err.code(), ErrorCode::RecursionLimitExceeded);
+}
+
+#[test]
+fn sequential_tests() {
+    // the depth is the nesting depth, not the number of lists
+    let config = ReaderConfig::builder().max_depth(2).build();
+    let v: Vec<Vec<i32>> = from_str_with_config("((1) (2) (3))", &config).unwrap();
+    assert_eq!(v, vec![vec![1], vec![2], vec![3]]);
+
+    let err = from_str_with_config::<Vec<Vec<Vec<i32>>>>("(((1)))", &config).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::RecursionLimitExceeded);
+    assert_eq!(err.location(), Some(&Location::new(1, 3)));
+}
@@ -34,6 +34,35 @@ fn float_tests() {
     round_trip!(f32, f32::MAX);
 }
 
+#[test]
+fn float_bits_tests() {
+    let values = [
+        0.0f32,
+        -0.0,
+        0.1,
+        0.1234567,
+        1.0 / 3.0,
+        1e-7,
+        123456.79,
+        16777216.0,
+        f32::EPSILON,
+        f32::MIN_POSITIVE,
+        f32::from_bits(1),
+        f32::MIN,
+        f32::MAX,
+    ];
+    for expected in values {
+        let s = to_string(&expected, WhitespaceConfig::default()).unwrap();
+        // floats are always written with a decimal point
+        assert!(s.contains('.'), "{}", s);
+        let actual: f32 = from_str(&s).unwrap();
+        assert_eq!(actual.to_bits(), expected.to_bits(), "to_string {}", s);
+        let s = to_pretty(&expected, WhitespaceConfig::default()).unwrap();
+        let actual: f32 = from_str(&s).unwrap();
+        assert_eq!(actual.to_bits(), expected.to_bits(), "to_pretty {}", s);
+    }
+}
+
 #[test]
 fn string_tests() {
     round_trip!(String, String::from("foo"));
@@ -93,6 +122,39 @@ fn map_tests() {
     round_trip!(HashMap<String, i32>, map![String::from("a") => -1, String::from("b") => -2]);
 }
 
+#[test]
+fn map_field_tests() {
+    // the map is framed by a list, so the int keys and values can't be
+    // confused with the surrounding int fields
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct MapField {
+        a: i32,
+        map: HashMap<i32, i32>,
+        b: i32,
+    }
+
+    round_trip!(
+        MapField,
+        MapField {
+            a: 1,
+            map: map![2 => 3, 4 => 5, 6 => 7],
+            b: 8,
+        }
+    );
+    round_trip!(
+        MapField,
+        MapField {
+            a: 1,
+            map: map![],
+            b: 2,
+        }
+    );
+    round_trip!(
+        Vec<HashMap<i32, i32>>,
+        vec![map![1 => 2], map![], map![3 => 4]]
+    );
+}
+
 #[test]
 fn struct_tests() {
     round_trip!(Struct, Struct { a: -1, b: -2 });
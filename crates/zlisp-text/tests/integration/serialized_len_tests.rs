@@ -0,0 +1,102 @@
+use assert_matches::assert_matches;
+use serde_derive::Serialize;
+use std::collections::BTreeMap;
+use zlisp_text::{serialized_len, to_pretty, ErrorCode, WhitespaceConfig};
+
+#[derive(Debug, Serialize)]
+struct Inner {
+    name: String,
+    values: Vec<f32>,
+}
+
+#[derive(Debug, Serialize)]
+enum Kind {
+    Unit,
+    Tuple(i32, i32),
+    Struct { a: i32 },
+}
+
+#[derive(Debug, Serialize)]
+struct Outer {
+    id: i32,
+    inner: Inner,
+    items: Vec<Inner>,
+    map: BTreeMap<i32, String>,
+    kinds: Vec<Kind>,
+    opt: Option<i32>,
+}
+
+fn outer() -> Outer {
+    let inner = |name: &str| Inner {
+        name: name.to_string(),
+        values: vec![1.0, -2.5, 3.25],
+    };
+    let mut map = BTreeMap::new();
+    map.insert(1, "one".to_string());
+    map.insert(2, "a (b)".to_string());
+    map.insert(3, "".to_string());
+    map.insert(4, "line\r\nbreak".to_string());
+    Outer {
+        id: 42,
+        inner: inner("foo bar"),
+        items: (0..8).map(|i| inner(&i.to_string())).collect(),
+        map,
+        kinds: vec![Kind::Unit, Kind::Tuple(1, 2), Kind::Struct { a: 3 }],
+        opt: Some(7),
+    }
+}
+
+macro_rules! assert_len {
+    ($value:expr, $config:expr) => {{
+        let config = $config;
+        let expected = to_pretty($value, config).unwrap().len();
+        assert_eq!(serialized_len($value, config).unwrap(), expected);
+    }};
+}
+
+#[test]
+fn default_config_tests() {
+    let config = WhitespaceConfig::default();
+    assert_len!(&1i32, config);
+    assert_len!(&1.5f32, config);
+    assert_len!(&"foo", config);
+    assert_len!(&(), config);
+    assert_len!(&Vec::<i32>::new(), config);
+    assert_len!(&outer(), config);
+}
+
+#[test]
+fn custom_config_tests() {
+    let config = WhitespaceConfig::builder()
+        .indent("    ")
+        .newline("\n")
+        .delimiter(" ")
+        .paren_escape(true)
+        .quote_numbers(false)
+        .float_quantize(Some(0.5))
+        .build();
+    assert_len!(&outer(), &config);
+
+    for width in [0, 10, 20, 40, 80] {
+        let config = WhitespaceConfig::builder()
+            .max_line_width(Some(width))
+            .build();
+        assert_len!(&outer(), &config);
+    }
+}
+
+#[test]
+fn reserve_tests() {
+    let value = outer();
+    let config = WhitespaceConfig::default();
+    let len = serialized_len(&value, config).unwrap();
+    let mut buffer = String::with_capacity(len);
+    buffer.push_str(&to_pretty(&value, config).unwrap());
+    assert_eq!(buffer.len(), len);
+}
+
+#[test]
+fn error_tests() {
+    let err = serialized_len(&true, WhitespaceConfig::default()).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::UnsupportedType);
+}
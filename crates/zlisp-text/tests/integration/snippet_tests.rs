@@ -0,0 +1,57 @@
+use serde_derive::Deserialize;
+use zlisp_text::{from_str, from_str_verbose, Location};
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+struct Config {
+    a: i32,
+    b: i32,
+}
+
+#[test]
+fn snippet_tests() {
+    let input = "(\r\n\ta\t1\r\n\tb\tfoo\r\n)\r\n";
+    let err = from_str::<Config>(input).unwrap_err();
+    assert_eq!(err.location(), Some(&Location::new(3, 4)));
+    assert_eq!(err.snippet(input).unwrap(), "\tb\tfoo\n\t \t^");
+
+    // the snippet isn't displayed by default
+    assert_eq!(
+        err.to_string(),
+        "invalid integer `foo` (at line: 3, column: 4)"
+    );
+}
+
+#[test]
+fn snippet_eof_tests() {
+    let input = "(a 1\nb";
+    let err = from_str::<Config>(input).unwrap_err();
+    assert_eq!(err.location(), Some(&Location::new(2, 2)));
+    assert_eq!(err.snippet(input).unwrap(), "b\n ^");
+}
+
+#[test]
+fn snippet_mismatch_tests() {
+    let input = "(a 1\nb foo)";
+    let err = from_str::<Config>(input).unwrap_err();
+    // the location isn't in different source data
+    assert_eq!(err.snippet(""), None);
+    assert_eq!(err.snippet("(a 1\nb"), None);
+
+    // errors without a location have no snippet
+    let err: zlisp_text::Error = serde::de::Error::custom("foo");
+    assert_eq!(err.snippet(input), None);
+}
+
+#[test]
+fn verbose_tests() {
+    let input = "(a 1\nb foo)";
+    let err = from_str_verbose::<Config>(input).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid integer `foo` (at line: 2, column: 3)\nb foo)\n  ^"
+    );
+
+    let actual = from_str_verbose::<i32>("1").unwrap();
+    assert_eq!(actual, 1);
+}
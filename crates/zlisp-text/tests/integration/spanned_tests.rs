@@ -0,0 +1,78 @@
+use serde_derive::{Deserialize, Serialize};
+use zlisp_text::{from_str, to_string, Location, Spanned, WhitespaceConfig};
+
+#[test]
+fn scalar_tests() {
+    let v: Spanned<i32> = from_str("  42 ").unwrap();
+    assert_eq!(v.get_ref(), &42);
+    assert_eq!(v.start(), &Location::new(1, 3));
+    assert_eq!(v.end(), &Location::new(1, 5));
+
+    let v: Spanned<String> = from_str("\"a b\"").unwrap();
+    assert_eq!(v.into_inner(), "a b");
+
+    // a Windows newline inside a quote is a single line break
+    let v: Spanned<String> = from_str("\"a\r\nbc\" ").unwrap();
+    assert_eq!(v.start(), &Location::new(1, 1));
+    assert_eq!(v.end(), &Location::new(2, "bc\"".len() + 1));
+    assert_eq!(v.into_inner(), "a\r\nbc");
+}
+
+#[test]
+fn list_tests() {
+    let v: Spanned<Vec<i32>> = from_str("\n(1\n 2)\n").unwrap();
+    assert_eq!(v.get_ref(), &vec![1, 2]);
+    assert_eq!(v.start(), &Location::new(2, 1));
+    assert_eq!(v.end(), &Location::new(3, 4));
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Config {
+    name: Spanned<String>,
+    values: Spanned<Vec<i32>>,
+    after: i32,
+}
+
+#[test]
+fn struct_tests() {
+    let input = "(\n\tname\tfoo\n\tvalues\t(1 2 3)\n\tafter\t4\n)\n";
+    let v: Spanned<Config> = from_str(input).unwrap();
+    assert_eq!(v.start(), &Location::new(1, 1));
+    assert_eq!(v.end(), &Location::new(5, 2));
+
+    let config = v.into_inner();
+    assert_eq!(config.name.get_ref(), "foo");
+    assert_eq!(config.name.start(), &Location::new(2, 7));
+    assert_eq!(config.name.end(), &Location::new(2, 10));
+    assert_eq!(config.values.get_ref(), &vec![1, 2, 3]);
+    assert_eq!(config.values.start(), &Location::new(3, 9));
+    assert_eq!(config.values.end(), &Location::new(3, 16));
+    assert_eq!(config.after, 4);
+}
+
+#[test]
+fn serialize_tests() {
+    #[derive(Debug, Serialize)]
+    struct Plain {
+        name: String,
+        values: Vec<i32>,
+        after: i32,
+    }
+
+    let input = "(name foo values (1 2) after 3)";
+    let v: Config = from_str(input).unwrap();
+    let plain = Plain {
+        name: String::from("foo"),
+        values: vec![1, 2],
+        after: 3,
+    };
+    let expected = to_string(&plain, WhitespaceConfig::default()).unwrap();
+    let s = to_string(&v, WhitespaceConfig::default()).unwrap();
+    assert_eq!(s, expected);
+}
+
+#[test]
+fn error_tests() {
+    let err = from_str::<Spanned<i32>>("(foo)").unwrap_err();
+    assert_eq!(err.location(), Some(&Location::new(1, 1)));
+}
@@ -0,0 +1,74 @@
+use assert_matches::assert_matches;
+use serde_derive::Deserialize;
+use zlisp_text::{Deserializer, ErrorCode, Location, ReaderConfig};
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Record {
+    a: i32,
+    b: String,
+}
+
+#[test]
+fn stream_tests() {
+    let input = "(a 1 b foo)\n(a 2 b bar)\r\n\n  (a 3 b baz)\n";
+    let mut iter = Deserializer::from_str(input).into_iter::<Record>();
+    assert_eq!(iter.location(), None);
+
+    let record = iter.next().unwrap().unwrap();
+    assert_eq!(record.a, 1);
+    assert_eq!(iter.location(), Some(&Location::new(1, 1)));
+    let record = iter.next().unwrap().unwrap();
+    assert_eq!(record.b, "bar");
+    assert_eq!(iter.location(), Some(&Location::new(2, 1)));
+    let record = iter.next().unwrap().unwrap();
+    assert_eq!(record.a, 3);
+    assert_eq!(iter.location(), Some(&Location::new(4, 3)));
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn stream_scalar_tests() {
+    let values = Deserializer::from_str("1 2\t3")
+        .into_iter::<i32>()
+        .collect::<zlisp_text::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(values, vec![1, 2, 3]);
+
+    let values = Deserializer::from_str("  \r\n")
+        .into_iter::<i32>()
+        .collect::<zlisp_text::Result<Vec<_>>>()
+        .unwrap();
+    assert!(values.is_empty());
+}
+
+#[test]
+fn stream_borrowed_tests() {
+    let values = Deserializer::from_str("a b")
+        .into_iter::<&str>()
+        .collect::<zlisp_text::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(values, vec!["a", "b"]);
+}
+
+#[test]
+fn stream_error_tests() {
+    let mut iter = Deserializer::from_str("1 a 3").into_iter::<i32>();
+    assert_eq!(iter.next().unwrap().unwrap(), 1);
+    let err = iter.next().unwrap().unwrap_err();
+    assert_matches!(err.code(), ErrorCode::ParseIntError { .. });
+    assert_eq!(err.location(), Some(&Location::new(1, 3)));
+    assert_eq!(iter.location(), Some(&Location::new(1, 3)));
+    // the iterator is exhausted after an error
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn stream_config_tests() {
+    const CONFIG: ReaderConfig = ReaderConfig::builder().line_comments(true).build();
+    let values = Deserializer::with_config("1 ; first\n2 ; second\n", &CONFIG)
+        .into_iter::<i32>()
+        .collect::<zlisp_text::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(values, vec![1, 2]);
+}
@@ -1,4 +1,6 @@
-use zlisp_text::{from_str, to_pretty, to_string, WhitespaceConfig};
+use zlisp_text::{
+    from_str, from_str_with_config, to_pretty, to_string, ReaderConfig, WhitespaceConfig,
+};
 
 macro_rules! assert_quoted {
     ($input:expr, $value:expr, $output:expr) => {
@@ -33,3 +35,116 @@ fn string_tests() {
     assert_quoted!("\"f\"\"o\"\"o\"", "foo", "foo");
     assert_quoted!("\" \t\r\n\"", " \t\r\n", "\" \t\r\n\"");
 }
+
+#[test]
+fn quote_numbers_disabled_tests() {
+    let config = WhitespaceConfig::builder().quote_numbers(false).build();
+    let cases = [
+        ("0", "0"),
+        ("-1.5", "-1.5"),
+        ("+", "+"),
+        ("123abc", "123abc"),
+        // strings requiring quotes for other reasons are still quoted
+        ("", "\"\""),
+        ("1 2", "\"1 2\""),
+    ];
+    for (value, output) in cases {
+        let expected = format!("{}\r\n", output);
+        let v = String::from(value);
+        let s = to_string(&v, &config).expect("to_string");
+        assert_eq!(s, expected, "to_string");
+        let s = to_pretty(&v, &config).expect("to_pretty");
+        assert_eq!(s, expected, "to_pretty");
+        let r: String = from_str(&s).expect("from_str");
+        assert_eq!(r, value, "from_str");
+    }
+}
+
+#[test]
+fn quote_numbers_strict_tests() {
+    let config = WhitespaceConfig::builder()
+        .quote_numbers_strict(true)
+        .build();
+    let cases = [
+        // numbers are still quoted
+        ("007", "\"007\""),
+        ("-1", "\"-1\""),
+        ("+1.5", "\"+1.5\""),
+        (".5", "\".5\""),
+        // strings that only look like numbers aren't
+        ("+", "+"),
+        ("-", "-"),
+        (".", "."),
+        ("1.2.3", "1.2.3"),
+        ("1-2", "1-2"),
+        ("--1", "--1"),
+    ];
+    for (value, output) in cases {
+        let expected = format!("{}\r\n", output);
+        let v = String::from(value);
+        let s = to_string(&v, &config).expect("to_string");
+        assert_eq!(s, expected, "to_string");
+        let s = to_pretty(&v, &config).expect("to_pretty");
+        assert_eq!(s, expected, "to_pretty");
+    }
+}
+
+/// Every string of up to four characters from an alphabet of characters
+/// found in numbers, and some letters.
+fn all_strings() -> Vec<String> {
+    const ALPHABET: &[char] = &['0', '1', '9', '-', '+', '.', 'e', 'x', 'n', ' '];
+    let mut all = vec![String::new()];
+    let mut current = vec![String::new()];
+    for _ in 0..4 {
+        current = current
+            .iter()
+            .flat_map(|s| {
+                ALPHABET.iter().map(move |c| {
+                    let mut s = s.clone();
+                    s.push(*c);
+                    s
+                })
+            })
+            .collect();
+        all.extend(current.iter().cloned());
+    }
+    all
+}
+
+#[test]
+fn quote_numbers_round_trip_tests() {
+    let lenient = WhitespaceConfig::default();
+    let strict = WhitespaceConfig::builder()
+        .quote_numbers_strict(true)
+        .build();
+    for config in [lenient, &strict] {
+        for s in all_strings() {
+            // a value is read as a number if it can be, so this catches
+            // strings that weren't quoted but should have been
+            let value = zlisp_value::Value::String(s);
+            let text = to_string(&value, config).expect("to_string");
+            let actual: zlisp_value::Value = from_str(&text).expect("from_str");
+            assert_eq!(actual, value, "{:?}", text);
+        }
+    }
+}
+
+#[test]
+fn semicolon_tests() {
+    // a semicolon starts a comment when line comments are enabled, so it must
+    // be quoted to round-trip
+    let config = ReaderConfig::builder().line_comments(true).build();
+    let cases = ["a;b", ";", "a;", "1;2"];
+    for value in cases {
+        let expected = vec![value.to_owned(), "c".to_owned()];
+        let s = to_string(&expected, WhitespaceConfig::default()).unwrap();
+        assert!(s.contains(&format!("\"{}\"", value)), "{}", s);
+        let actual: Vec<String> = from_str_with_config(&s, &config).unwrap();
+        assert_eq!(actual, expected, "to_string");
+        let s = to_pretty(&expected, WhitespaceConfig::default()).unwrap();
+        let actual: Vec<String> = from_str_with_config(&s, &config).unwrap();
+        assert_eq!(actual, expected, "to_pretty");
+        let actual: Vec<String> = from_str(&s).unwrap();
+        assert_eq!(actual, expected, "without comments");
+    }
+}
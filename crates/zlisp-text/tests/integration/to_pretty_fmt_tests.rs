@@ -2,7 +2,7 @@ use super::map;
 use super::structs::*;
 use serde_derive::{Deserialize, Serialize};
 use std::collections::HashMap;
-use zlisp_text::{to_pretty, WhitespaceConfig};
+use zlisp_text::{to_pretty, to_string, WhitespaceConfig};
 
 macro_rules! assert_fmt {
     ($type:ty, $value:expr, $expected:expr) => {
@@ -14,6 +14,7 @@ macro_rules! assert_fmt {
             .newline("\n")
             .build();
         let expected = concat!($expected, "\n");
+        #[allow(clippy::type_complexity)]
         let v: $type = $value;
         let actual = to_pretty(&v, &config).unwrap();
         assert_eq!(&actual, &expected);
@@ -23,7 +24,7 @@ macro_rules! assert_fmt {
 #[test]
 fn fmt_scalar_tests() {
     assert_fmt!(i32, 0, "0");
-    assert_fmt!(f32, 0.0, "0.000000");
+    assert_fmt!(f32, 0.0, "0.0");
     assert_fmt!(String, String::from("foo"), "foo");
     assert_fmt!(NewTypeStruct, NewTypeStruct(0), "0");
     // no expanded or nested (well, newtype, but it just delegates)
@@ -377,3 +378,272 @@ fn fmt_enum_struct_variant_tests() {
         "V(a -1 b -2)"
     );
 }
+
+#[test]
+fn fmt_deeply_nested_tests() {
+    use zlisp_value::Value;
+
+    // lists with 7 items are never compact, so every level is indented
+    fn nested(depth: usize) -> Value {
+        let mut items = vec![if depth == 0 {
+            Value::Int(0)
+        } else {
+            nested(depth - 1)
+        }];
+        items.extend((1..7).map(Value::Int));
+        Value::List(items)
+    }
+
+    fn expected(depth: usize, level: usize) -> String {
+        let indent = "    ".repeat(level + 1);
+        let first = if depth == 0 {
+            String::from("0")
+        } else {
+            expected(depth - 1, level + 1)
+        };
+        let mut s = format!("(\n{}{}\n", indent, first);
+        for i in 1..7 {
+            s.push_str(&format!("{}{}\n", indent, i));
+        }
+        s.push_str(&"    ".repeat(level));
+        s.push(')');
+        s
+    }
+
+    let config = WhitespaceConfig::builder()
+        .indent("    ")
+        .delimiter(" ")
+        .newline("\n")
+        .build();
+    let actual = to_pretty(&nested(20), &config).unwrap();
+    assert_eq!(actual, format!("{}\n", expected(20, 0)));
+}
+
+#[test]
+fn fmt_struct_field_order_tests() {
+    // fields are always written in declaration order, not sorted
+    #[derive(Serialize)]
+    struct FieldOrder {
+        z: i32,
+        a: i32,
+        m: i32,
+    }
+
+    #[derive(Serialize)]
+    enum FieldOrderVariant {
+        V { z: i32, a: i32 },
+    }
+
+    assert_fmt!(FieldOrder, FieldOrder { z: 1, a: 2, m: 3 }, "(z 1 a 2 m 3)");
+    assert_fmt!(
+        FieldOrderVariant,
+        FieldOrderVariant::V { z: 1, a: 2 },
+        "V(z 1 a 2)"
+    );
+}
+
+#[test]
+fn fmt_float_quantize_tests() {
+    let config = WhitespaceConfig::builder()
+        .newline("\n")
+        .float_quantize(Some(0.001))
+        .float_precision(Some(6))
+        .build();
+    let cases = [
+        (1.0004f32, "1.000000\n"),
+        (0.9996, "1.000000\n"),
+        (1.2345678, "1.235000\n"),
+        (-0.0004, "0.000000\n"),
+    ];
+    for (value, expected) in cases {
+        let actual = to_pretty(&value, &config).unwrap();
+        assert_eq!(&actual, expected, "to_pretty");
+        let actual = to_string(&value, &config).unwrap();
+        assert_eq!(&actual, expected, "to_string");
+    }
+
+    // near-equal values serialize identically
+    let a = to_pretty(&vec![0.1f32 + 0.2], &config).unwrap();
+    let b = to_pretty(&vec![0.3f32], &config).unwrap();
+    assert_eq!(a, b);
+
+    // invalid steps are ignored
+    let config = WhitespaceConfig::builder()
+        .newline("\n")
+        .float_quantize(Some(0.0))
+        .float_precision(Some(6))
+        .build();
+    assert_eq!(&to_pretty(&1.0000004f32, &config).unwrap(), "1.000000\n");
+    assert_eq!(&to_pretty(&1.2345678f32, &config).unwrap(), "1.234568\n");
+}
+
+#[test]
+fn fmt_float_quantize_overflow_tests() {
+    let plain = WhitespaceConfig::builder().newline("\n").build();
+    let cases = [
+        (0.001f32, f32::MAX),
+        (0.001, f32::MIN),
+        // rounding up to the next step overflows
+        (f32::MAX * 0.6, f32::MAX),
+    ];
+    for (step, value) in cases {
+        let config = WhitespaceConfig::builder()
+            .newline("\n")
+            .float_quantize(Some(step))
+            .build();
+        // values that overflow when quantized are kept as-is
+        let actual = to_pretty(&value, &config).unwrap();
+        let expected = to_pretty(&value, &plain).unwrap();
+        assert_eq!(actual, expected, "{} {}", step, value);
+        assert!(!actual.contains("inf"), "{}", actual);
+    }
+}
+
+#[test]
+fn fmt_float_precision_tests() {
+    let cases = [
+        (None, 1.0f32, "1.0\n"),
+        (None, 0.1234567, "0.1234567\n"),
+        (None, -2.5, "-2.5\n"),
+        (Some(6), 1.0, "1.000000\n"),
+        (Some(6), 0.1234567, "0.123457\n"),
+        (Some(2), -2.5, "-2.50\n"),
+        // there is always at least one decimal place
+        (Some(0), 1.0, "1.0\n"),
+    ];
+    for (precision, value, expected) in cases {
+        let config = WhitespaceConfig::builder()
+            .newline("\n")
+            .float_precision(precision)
+            .build();
+        let actual = to_pretty(&value, &config).unwrap();
+        assert_eq!(&actual, expected, "to_pretty");
+        let actual = to_string(&value, &config).unwrap();
+        assert_eq!(&actual, expected, "to_string");
+    }
+}
+
+#[test]
+fn fmt_max_line_width_tests() {
+    let config = WhitespaceConfig::builder()
+        .indent("    ")
+        .delimiter(" ")
+        .newline("\n")
+        .max_line_width(Some(80))
+        .build();
+
+    // short compact lists are unaffected
+    let value = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    let actual = to_pretty(&value, &config).unwrap();
+    assert_eq!(&actual, "((1 2 3) (4 5 6))\n");
+
+    // long compact lists wrap
+    let long = "a".repeat(30);
+    let value = vec![vec![long.clone(), long.clone(), long.clone()]];
+    let actual = to_pretty(&value, &config).unwrap();
+    let expected = format!(
+        "(\n    (\n        {0}\n        {0}\n        {0}\n    )\n)\n",
+        long
+    );
+    assert_eq!(actual, expected);
+    for line in actual.lines() {
+        assert!(line.len() <= 80, "{:?}", line);
+    }
+
+    // nested lists that fit stay compact, even if the outer list doesn't
+    let value = vec![vec![long.clone()], vec![long.clone()], vec![long.clone()]];
+    let actual = to_pretty(&value, &config).unwrap();
+    let expected = format!("(\n    ({0})\n    ({0})\n    ({0})\n)\n", long);
+    assert_eq!(actual, expected);
+
+    // the width is inclusive
+    let value = vec![vec!["a".repeat(38), "b".repeat(37)]];
+    let actual = to_pretty(&value, &config).unwrap();
+    assert_eq!(actual.len(), 80 + 1);
+    assert_eq!(actual.lines().count(), 1);
+    let value = vec![vec!["a".repeat(38), "b".repeat(38)]];
+    let actual = to_pretty(&value, &config).unwrap();
+    assert_eq!(actual.lines().count(), 6);
+
+    // short lists with many items stay compact
+    let value = vec![(0..20).collect::<Vec<i32>>()];
+    let actual = to_pretty(&value, &config).unwrap();
+    assert_eq!(actual.lines().count(), 1);
+    let value: Vec<Struct> = (0..7).map(|_| Struct { a: 1, b: 2 }).collect();
+    let actual = to_pretty(&value, &config).unwrap();
+    assert_eq!(actual.lines().count(), 1);
+
+    // long lists with few items expand
+    let value = vec!["a".repeat(50), "b".repeat(50)];
+    let actual = to_pretty(&value, &config).unwrap();
+    let expected = format!("(\n    {}\n    {}\n)\n", "a".repeat(50), "b".repeat(50));
+    assert_eq!(actual, expected);
+
+    // without a width, long lists stay compact
+    let config = WhitespaceConfig::builder()
+        .indent("    ")
+        .delimiter(" ")
+        .newline("\n")
+        .build();
+    let value = vec![vec![long.clone(), long.clone(), long]];
+    let actual = to_pretty(&value, &config).unwrap();
+    assert_eq!(actual.lines().count(), 1);
+    // but lists with many items expand
+    let value = vec![(0..7).collect::<Vec<i32>>()];
+    let actual = to_pretty(&value, &config).unwrap();
+    assert_eq!(actual.lines().count(), 11);
+}
+
+#[test]
+fn fmt_map_field_tests() {
+    #[derive(Serialize)]
+    struct MapField {
+        a: i32,
+        map: HashMap<i32, i32>,
+        b: i32,
+    }
+
+    assert_fmt!(
+        MapField,
+        MapField {
+            a: 1,
+            map: map![2 => 3],
+            b: 4,
+        },
+        "(
+    a 1
+    map (
+        2 3
+    )
+    b 4
+)"
+    );
+}
+
+#[test]
+fn fmt_compact_preset_tests() {
+    #[derive(Serialize)]
+    struct Nested {
+        a: Vec<Vec<i32>>,
+        map: HashMap<i32, i32>,
+        s: Struct,
+        v: StructVariant,
+    }
+
+    let value = Nested {
+        a: vec![vec![1, 2], vec![3]],
+        map: map![4 => 5],
+        s: Struct { a: 6, b: 7 },
+        v: StructVariant::V { a: 8, b: 9 },
+    };
+    let actual = to_pretty(&value, &WhitespaceConfig::COMPACT).unwrap();
+    // no newlines, even for maps and lists that are usually expanded
+    assert_eq!(actual, "(a ((1 2) (3)) map (4 5) s (a 6 b 7) v V(a 8 b 9))");
+}
+
+#[test]
+fn fmt_spaces_preset_tests() {
+    let value = map![1 => vec![2, 3]];
+    let actual = to_pretty(&value, &WhitespaceConfig::SPACES).unwrap();
+    assert_eq!(actual, "(\n    1 (2 3)\n)\n");
+}
@@ -61,7 +61,18 @@ fn string_tests() {
     let _ = to_pretty(&max_len, WhitespaceConfig::default()).unwrap();
 
     let over_len = " ".repeat(256);
-    assert_err!(&str, &over_len, ErrorCode::StringTooLong);
+    assert_err!(
+        &str,
+        &over_len,
+        ErrorCode::StringTooLong { len: 256, max: 255 }
+    );
+
+    let over_len = " ".repeat(300);
+    assert_err!(
+        &str,
+        &over_len,
+        ErrorCode::StringTooLong { len: 300, max: 255 }
+    );
 }
 
 #[test]
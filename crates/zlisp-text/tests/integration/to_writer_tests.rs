@@ -0,0 +1,100 @@
+use super::map;
+use super::structs::*;
+use assert_matches::assert_matches;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use zlisp_text::{to_pretty, to_string, to_writer, to_writer_pretty, ErrorCode, WhitespaceConfig};
+
+/// A writer that fails after some bytes.
+struct Failing(usize);
+
+impl Write for Failing {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.0 == 0 {
+            return Err(io::Error::other("failed"));
+        }
+        let n = buf.len().min(self.0);
+        self.0 -= n;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+type Values = (
+    Vec<Struct>,
+    HashMap<String, Option<i32>>,
+    (StructVariant, TupleVariant, String),
+);
+
+fn values() -> Values {
+    (
+        vec![Struct { a: 1, b: 2 }, Struct { a: -1, b: -2 }],
+        map! { "foo bar".to_owned() => Some(1) },
+        (
+            StructVariant::V { a: 3, b: 4 },
+            TupleVariant::V(5, 6),
+            "(baz)".to_owned(),
+        ),
+    )
+}
+
+#[test]
+fn to_writer_tests() {
+    let value = values();
+    let config = WhitespaceConfig::builder()
+        .indent("  ")
+        .newline("\n")
+        .build();
+    for config in [WhitespaceConfig::default(), &config] {
+        let mut buf = Vec::new();
+        to_writer(&mut buf, &value, config).unwrap();
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            to_string(&value, config).unwrap()
+        );
+    }
+}
+
+#[test]
+fn to_writer_pretty_tests() {
+    let value = values();
+    let config = WhitespaceConfig::builder()
+        .newline("\n")
+        .max_line_width(Some(12))
+        .build();
+    for config in [WhitespaceConfig::default(), &config] {
+        let mut buf = Vec::new();
+        to_writer_pretty(&mut buf, &value, config).unwrap();
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            to_pretty(&value, config).unwrap()
+        );
+    }
+}
+
+#[test]
+fn io_error_tests() {
+    let value = values();
+    let err = to_writer(Failing(10), &value, WhitespaceConfig::default()).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::IO(_));
+    assert_eq!(err.to_string(), "I/O error");
+    assert!(std::error::Error::source(&err).is_some());
+    // I/O errors can be cloned and compared
+    assert_eq!(err.clone(), err);
+
+    let err = to_writer_pretty(Failing(10), &value, WhitespaceConfig::default()).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::IO(_));
+}
+
+#[test]
+fn serialize_error_tests() {
+    // serialization errors take precedence over I/O errors
+    let err = to_writer(Failing(0), &true, WhitespaceConfig::default()).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::UnsupportedType);
+
+    let err = to_writer_pretty(Vec::new(), &vec!["\""], WhitespaceConfig::default()).unwrap_err();
+    assert_matches!(err.code(), ErrorCode::StringContainsQuote);
+}
@@ -0,0 +1,66 @@
+use assert_matches::assert_matches;
+use zlisp_text::{
+    tokenize, tokenize_with_config, ErrorCode, Location, ReaderConfig, Result, Token,
+};
+
+#[test]
+fn tokenize_tests() {
+    let v = tokenize("(1 \"foo bar\"\r\n  ())")
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(
+        v,
+        vec![
+            (Token::ListStart, Location::new(1, 1)),
+            (Token::Unquoted("1".to_owned()), Location::new(1, 2)),
+            (Token::Quoted("foo bar".to_owned()), Location::new(1, 4)),
+            (Token::ListStart, Location::new(2, 3)),
+            (Token::ListEnd, Location::new(2, 4)),
+            (Token::ListEnd, Location::new(2, 5)),
+        ]
+    );
+}
+
+#[test]
+fn tokenize_empty_tests() {
+    assert_matches!(tokenize("").next(), None);
+    assert_matches!(tokenize(" \r\n\t").next(), None);
+}
+
+#[test]
+fn tokenize_unbalanced_tests() {
+    // lists are not checked
+    let v = tokenize(") (")
+        .map(|r| r.map(|(token, _loc)| token))
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(v, vec![Token::ListEnd, Token::ListStart]);
+}
+
+#[test]
+fn tokenize_error_tests() {
+    let mut tokens = tokenize("foo \"bar");
+    assert_matches!(tokens.next(), Some(Ok((Token::Unquoted(_), _))));
+    let err = tokens.next().unwrap().unwrap_err();
+    assert_matches!(err.code(), ErrorCode::EofWhileParsingQuote);
+    assert_matches!(tokens.next(), None);
+}
+
+#[test]
+fn tokenize_with_config_tests() {
+    let config = ReaderConfig::builder()
+        .paren_escape(true)
+        .lenient_whitespace(',')
+        .build();
+    let v = tokenize_with_config("a\\(b\\),c", &config)
+        .map(|r| r.map(|(token, _loc)| token))
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(
+        v,
+        vec![
+            Token::Unquoted("a(b)".to_owned()),
+            Token::Unquoted("c".to_owned()),
+        ]
+    );
+}
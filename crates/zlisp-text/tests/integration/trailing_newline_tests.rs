@@ -0,0 +1,56 @@
+use zlisp_text::{
+    from_str, serialized_len, to_pretty, to_string, to_writer, to_writer_pretty, value_to_string,
+    WhitespaceConfig,
+};
+use zlisp_value::zlisp;
+
+fn config(trailing_newline: bool) -> WhitespaceConfig<'static> {
+    WhitespaceConfig::builder()
+        .newline("\n")
+        .trailing_newline(trailing_newline)
+        .build()
+}
+
+#[test]
+fn to_string_tests() {
+    let value = ("a", 1, ());
+    let expected = "(\n\ta\n\t1\n\t()\n)";
+    let actual = to_string(&value, &config(false)).unwrap();
+    assert_eq!(actual, expected);
+    let actual = to_string(&value, &config(true)).unwrap();
+    assert_eq!(actual, format!("{}\n", expected));
+
+    let mut actual = Vec::new();
+    to_writer(&mut actual, &value, &config(false)).unwrap();
+    assert_eq!(actual, expected.as_bytes());
+
+    let value = zlisp!(["a", 1]);
+    let actual = value_to_string(&value, &config(false)).unwrap();
+    assert_eq!(actual, "(\n\ta\n\t1\n)");
+}
+
+#[test]
+fn to_pretty_tests() {
+    let value = ("a", 1, ());
+    let expected = "(a\t1\t())";
+    let actual = to_pretty(&value, &config(false)).unwrap();
+    assert_eq!(actual, expected);
+    let actual = to_pretty(&value, &config(true)).unwrap();
+    assert_eq!(actual, format!("{}\n", expected));
+
+    let mut actual = Vec::new();
+    to_writer_pretty(&mut actual, &value, &config(false)).unwrap();
+    assert_eq!(actual, expected.as_bytes());
+
+    let len = serialized_len(&value, &config(false)).unwrap();
+    assert_eq!(len, expected.len());
+}
+
+#[test]
+fn round_trip_tests() {
+    let value = zlisp!(["a", 1, [2.5, "b"], []]);
+    let text = to_pretty(&value, &config(false)).unwrap();
+    assert!(!text.ends_with('\n'));
+    let actual: zlisp_value::Value = from_str(&text).unwrap();
+    assert_eq!(actual, value);
+}
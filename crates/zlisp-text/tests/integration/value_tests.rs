@@ -0,0 +1,147 @@
+use super::map;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::HashMap;
+use zlisp_text::{
+    from_str, to_pretty, to_string, to_string_canonical, value_to_string, WhitespaceConfig,
+};
+use zlisp_value::Value;
+
+macro_rules! round_trip {
+    ($type:ty, $value:expr) => {
+        let expected: $type = $value;
+        let s = to_string(&expected, WhitespaceConfig::default()).expect("to_string");
+        let actual: $type = from_str(&s).expect("to_string");
+        assert_eq!(actual, expected, "to_string");
+        let s = to_pretty(&expected, WhitespaceConfig::default()).expect("to_pretty");
+        let actual: $type = from_str(&s).expect("to_pretty");
+        assert_eq!(actual, expected, "to_pretty");
+    };
+}
+
+fn values() -> Vec<Value> {
+    vec![
+        Value::Int(-1),
+        Value::Float(1.5),
+        Value::from("foo"),
+        Value::from(""),
+        Value::from("0"),
+        Value::List(vec![]),
+        Value::List(vec![Value::Int(1), Value::List(vec![Value::from("a b")])]),
+    ]
+}
+
+#[test]
+fn value_integral_float_tests() {
+    // integral floats keep a decimal point, so they aren't read back as ints
+    for v in [3.0f32, 0.0, -1.0, 16777216.0, f32::MAX] {
+        let config = WhitespaceConfig::default();
+        let outputs = [
+            to_string(&v, config).unwrap(),
+            to_pretty(&v, config).unwrap(),
+            to_string_canonical(&v).unwrap(),
+            value_to_string(&Value::Float(v), config).unwrap(),
+        ];
+        for s in outputs {
+            assert!(s.contains('.'), "{}", s);
+            let actual: Value = from_str(&s).unwrap();
+            assert_eq!(actual, Value::Float(v), "{}", s);
+        }
+    }
+}
+
+#[test]
+fn value_seq_tests() {
+    round_trip!(Vec<Value>, vec![]);
+    round_trip!(Vec<Value>, values());
+}
+
+#[test]
+fn value_map_tests() {
+    round_trip!(HashMap<String, Value>, map![]);
+    let m = values()
+        .into_iter()
+        .enumerate()
+        .map(|(i, v)| (format!("k{}", i), v))
+        .collect();
+    round_trip!(HashMap<String, Value>, m);
+}
+
+#[test]
+fn value_struct_tests() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Partial {
+        name: String,
+        value: Value,
+        values: Vec<Value>,
+        extras: HashMap<String, Value>,
+        after: i32,
+    }
+
+    round_trip!(
+        Partial,
+        Partial {
+            name: String::from("foo"),
+            value: Value::List(values()),
+            values: values(),
+            extras: map![
+                String::from("a") => Value::Int(1),
+                String::from("b") => Value::List(values()),
+            ],
+            after: -1,
+        }
+    );
+}
+
+#[test]
+fn value_to_string_tests() {
+    let values = vec![
+        Value::Int(-1),
+        Value::Float(1.5),
+        Value::from("foo"),
+        Value::List(vec![]),
+        Value::List(values()),
+    ];
+    for value in values {
+        let expected = to_string(&value, WhitespaceConfig::default()).unwrap();
+        let actual = value_to_string(&value, WhitespaceConfig::default()).unwrap();
+        assert_eq!(actual, expected, "{:?}", value);
+    }
+}
+
+#[test]
+fn value_to_string_err_tests() {
+    let value = Value::from("a\"b");
+    let expected = to_string(&value, WhitespaceConfig::default()).unwrap_err();
+    let actual = value_to_string(&value, WhitespaceConfig::default()).unwrap_err();
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn value_pretty_display_tests() {
+    let values = vec![
+        Value::from(0),
+        Value::from(-1.5),
+        Value::from("foo"),
+        Value::from(""),
+        Value::from("a b"),
+        Value::from("a(b)"),
+        Value::from("a;b"),
+        Value::from("123"),
+        Value::from(vec![]),
+        Value::from(vec![Value::from(1), Value::from("two"), Value::from(3.0)]),
+        Value::from(vec![Value::from("a;b"), Value::from(1)]),
+        Value::from(vec![Value::from(vec![]), Value::from(vec![Value::from(1)])]),
+        Value::from(vec![Value::from(1); 6]),
+        Value::from(vec![Value::from(1); 7]),
+        Value::from(vec![
+            Value::from("name"),
+            Value::from("a b"),
+            Value::from("items"),
+            Value::from(vec![Value::from(vec![Value::from(1); 7]), Value::from(2)]),
+        ]),
+    ];
+    for value in values {
+        let expected = to_pretty(&value, WhitespaceConfig::default()).unwrap();
+        assert_eq!(format!("{:#}", value), expected, "{:?}", value);
+    }
+}
@@ -0,0 +1,57 @@
+use assert_matches::assert_matches;
+use zlisp_text::{from_str, to_pretty, to_string, ErrorCode, WhitespaceConfig};
+use zlisp_value::{zlisp, Value};
+
+#[test]
+fn try_build_ok_tests() {
+    let config = WhitespaceConfig::builder().try_build().unwrap();
+    assert_eq!(config.indent(), "\t");
+    let config = WhitespaceConfig::builder()
+        .indent("")
+        .newline("")
+        .delimiter(" \t")
+        .record_separator("\r\n\r\n")
+        .try_build()
+        .unwrap();
+    assert_eq!(config.delimiter(), " \t");
+}
+
+#[test]
+fn try_build_err_tests() {
+    let builder = WhitespaceConfig::builder;
+    let cases = [
+        (builder().indent("."), "indent"),
+        (builder().indent("\0"), "indent"),
+        (builder().newline(";\n"), "newline"),
+        (builder().delimiter("("), "delimiter"),
+        (builder().delimiter(")"), "delimiter"),
+        (builder().delimiter("\""), "delimiter"),
+        (builder().delimiter("0"), "delimiter"),
+        (builder().delimiter(""), "delimiter"),
+        (builder().record_separator("--"), "record_separator"),
+    ];
+    for (builder, expected) in cases {
+        let err = builder.try_build().unwrap_err();
+        assert_matches!(err.code(), ErrorCode::InvalidWhitespace { field } if *field == expected);
+        assert_eq!(err.location(), None);
+    }
+}
+
+#[test]
+fn empty_newline_tests() {
+    // without newlines, scalars are still separated by the delimiter
+    let value = zlisp!(["a", 1, [2, 3], "b", []]);
+    for config in [
+        WhitespaceConfig::COMPACT,
+        WhitespaceConfig::builder().newline("").try_build().unwrap(),
+    ] {
+        let text = to_string(&value, &config).unwrap();
+        let actual: Value = from_str(&text).unwrap();
+        assert_eq!(actual, value, "{:?}", text);
+        let text = to_pretty(&value, &config).unwrap();
+        let actual: Value = from_str(&text).unwrap();
+        assert_eq!(actual, value, "{:?}", text);
+    }
+    let text = to_string(&value, &WhitespaceConfig::COMPACT).unwrap();
+    assert_eq!(text, "( a 1 ( 2 3 ) b ( ) )");
+}
@@ -0,0 +1,70 @@
+use assert_matches::assert_matches;
+use zlisp_text::{to_pretty, write_element, Element, ErrorCode, WhitespaceConfig};
+
+macro_rules! assert_write {
+    ($element:expr, $expected:expr) => {
+        let config = WhitespaceConfig::builder()
+            .indent("    ")
+            .delimiter(" ")
+            .newline("\n")
+            .build();
+        let expected = concat!($expected, "\n");
+        let actual = write_element($element, &config);
+        assert_eq!(&actual, &expected);
+    };
+}
+
+#[test]
+fn scalar_tests() {
+    assert_write!(Element::int(-1), "-1");
+    assert_write!(Element::float(0.0), "0.0");
+    assert_write!(Element::string("foo").unwrap(), "foo");
+    assert_write!(Element::string("").unwrap(), "\"\"");
+    assert_write!(Element::string("0").unwrap(), "\"0\"");
+    assert_write!(Element::string("a b").unwrap(), "\"a b\"");
+
+    let err = Element::string("\"").unwrap_err();
+    assert_matches!(err.code(), ErrorCode::StringContainsQuote);
+}
+
+#[test]
+fn list_tests() {
+    assert_write!(Element::list(vec![]).unwrap(), "()");
+    assert_write!(
+        Element::list(vec![Element::int(-1), Element::int(-2)]).unwrap(),
+        "(-1 -2)"
+    );
+    let items = (0..7).map(Element::int).collect();
+    assert_write!(
+        Element::list(items).unwrap(),
+        "(
+    0
+    1
+    2
+    3
+    4
+    5
+    6
+)"
+    );
+}
+
+#[test]
+fn matches_to_pretty_tests() {
+    let config = WhitespaceConfig::default();
+    let value = (1, 2.0f32, String::from("foo"), (0, 1, 2, 3, 4, 5, 6, 7));
+
+    let items = (0..8).map(Element::int).collect();
+    let element = Element::list(vec![
+        Element::int(1),
+        Element::float(2.0),
+        Element::string("foo").unwrap(),
+        Element::list(items).unwrap(),
+    ])
+    .unwrap();
+
+    assert_eq!(
+        write_element(element, config),
+        to_pretty(&value, config).unwrap()
+    );
+}
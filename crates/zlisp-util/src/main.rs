@@ -1,97 +1,353 @@
 use clap::Parser;
-use zlisp_value::Value;
+use std::error::Error;
+use std::io::{Read as _, Write as _};
+use zlisp_value::{Value, ValueError};
 
-#[derive(clap::ArgEnum, Debug, Clone)]
+/// The path that refers to stdin or stdout.
+const STDIO: &str = "-";
+
+#[derive(clap::ArgEnum, Debug, Clone, Copy)]
 enum FromFormat {
-    JSON,
+    Json,
     Bin,
     Text,
 }
 
-#[derive(clap::ArgEnum, Debug, Clone)]
+#[derive(clap::ArgEnum, Debug, Clone, Copy)]
 enum ToFormat {
-    JSON,
+    Json,
     Bin,
     Text,
     Ast,
 }
 
+#[derive(clap::ArgEnum, Debug, Clone, Copy)]
+enum Newline {
+    Unix,
+    Windows,
+}
+
+impl Newline {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Unix => "\n",
+            Self::Windows => "\r\n",
+        }
+    }
+}
+
+/// Whitespace made of tabs or spaces, e.g. `tabs` or `spaces:2`.
+#[derive(Debug, Clone)]
+struct Spacing(String);
+
+impl std::str::FromStr for Spacing {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        const INVALID: &str = "expected `tabs`, `spaces`, `tabs:<count>`, or `spaces:<count>`";
+        match s.split_once(':') {
+            None if s == "tabs" => Ok(Self("\t".to_owned())),
+            None if s == "spaces" => Ok(Self(" ".to_owned())),
+            Some(("tabs", count)) => {
+                let count: usize = count.parse().map_err(|_| INVALID)?;
+                Ok(Self("\t".repeat(count)))
+            }
+            Some(("spaces", count)) => {
+                let count: usize = count.parse().map_err(|_| INVALID)?;
+                Ok(Self(" ".repeat(count)))
+            }
+            _ => Err(INVALID.to_owned()),
+        }
+    }
+}
+
 #[derive(Parser, Debug, Clone)]
 struct Args {
     #[clap(long, arg_enum, help = "The input format")]
     from: FromFormat,
-    #[clap(long, arg_enum, help = "The output format")]
-    to: ToFormat,
-    #[clap(help = "The source path")]
+    #[clap(
+        long,
+        arg_enum,
+        required_unless_present = "validate",
+        help = "The output format"
+    )]
+    to: Option<ToFormat>,
+    #[clap(
+        long,
+        conflicts_with = "to",
+        help = "Only check the input is valid, without writing output"
+    )]
+    validate: bool,
+    #[clap(
+        long,
+        conflicts_with = "validate",
+        help = "Only check the text input is formatted, without writing output"
+    )]
+    check: bool,
+    #[clap(
+        long,
+        conflicts_with_all = &["validate", "check"],
+        help = "Read the output back, and check it matches the input"
+    )]
+    verify: bool,
+    #[clap(
+        long,
+        conflicts_with_all = &["validate", "check"],
+        help = "Print the number of nodes and the nesting depth of the input"
+    )]
+    stats: bool,
+    #[clap(long, help = "Convert JSON booleans to the ints 0 and 1")]
+    json_bool_as_int: bool,
+    #[clap(
+        long,
+        default_value = "tabs",
+        help = "The text indent, e.g. `tabs` or `spaces:2`"
+    )]
+    indent: Spacing,
+    #[clap(
+        long,
+        default_value = "tabs",
+        help = "The text delimiter, e.g. `tabs` or `spaces`"
+    )]
+    delimiter: Spacing,
+    #[clap(
+        long,
+        arg_enum,
+        default_value = "windows",
+        help = "The text newline style"
+    )]
+    newline: Newline,
+    #[clap(help = "The source path, or `-` for stdin")]
     input: String,
-    #[clap(help = "The destination path (will be overwritten)")]
+    #[clap(
+        default_value = STDIO,
+        help = "The destination path (will be overwritten), or `-` for stdout"
+    )]
     output: String,
 }
 
-fn json_to_zlisp(value: serde_json::Value) -> Value {
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+fn read_input(path: &str) -> Result<Vec<u8>> {
+    let result = if path == STDIO {
+        let mut buf = Vec::new();
+        std::io::stdin().lock().read_to_end(&mut buf).map(|_| buf)
+    } else {
+        std::fs::read(path)
+    };
+    result.map_err(|e| format!("failed to read `{}`: {}", path, e).into())
+}
+
+fn read_input_string(path: &str) -> Result<String> {
+    let input = read_input(path)?;
+    String::from_utf8(input).map_err(|e| format!("failed to read `{}`: {}", path, e).into())
+}
+
+fn write_output<C: AsRef<[u8]>>(path: &str, contents: C) -> Result<()> {
+    let result = if path == STDIO {
+        let mut stdout = std::io::stdout().lock();
+        stdout
+            .write_all(contents.as_ref())
+            .and_then(|_| stdout.flush())
+    } else {
+        std::fs::write(path, contents)
+    };
+    result.map_err(|e| format!("failed to write `{}`: {}", path, e).into())
+}
+
+/// Apply the conversion options the library conversion doesn't have.
+fn normalize_json(value: serde_json::Value, bool_as_int: bool) -> serde_json::Value {
     use serde_json::Value::*;
     match value {
-        Null => panic!("expected any valid zlisp value, found `null`"),
-        Bool(_) => panic!("expected any valid zlisp value, found `bool`"),
-        Object(_) => panic!("expected any valid zlisp value, found `object`"),
-        Number(n) => {
-            if let Some(i) = n.as_i64() {
-                return Value::Int(i.try_into().unwrap());
+        // `None` and unit are empty lists
+        Null => Array(Vec::new()),
+        Bool(b) if bool_as_int => Number(i32::from(b).into()),
+        Array(a) => Array(
+            a.into_iter()
+                .map(|value| normalize_json(value, bool_as_int))
+                .collect(),
+        ),
+        Object(o) => Object(
+            o.into_iter()
+                .map(|(key, value)| (key, normalize_json(value, bool_as_int)))
+                .collect(),
+        ),
+        value => value,
+    }
+}
+
+fn json_to_zlisp(value: serde_json::Value, bool_as_int: bool) -> Result<Value> {
+    Value::try_from(normalize_json(value, bool_as_int)).map_err(|e| match e {
+        ValueError::JsonBool => {
+            "expected any valid zlisp value, found `bool` (try --json-bool-as-int)".into()
+        }
+        e => e.into(),
+    })
+}
+
+fn validate(from: FromFormat, path: &str, bool_as_int: bool) -> Result<()> {
+    match from {
+        FromFormat::Json => {
+            let input = read_input_string(path)?;
+            let value: serde_json::Value = serde_json::from_str(&input)?;
+            json_to_zlisp(value, bool_as_int)?;
+        }
+        // the events don't build the value, which is faster
+        FromFormat::Bin => {
+            let input = read_input(path)?;
+            for event in zlisp_bin::events(&input) {
+                event?;
             }
-            if let Some(u) = n.as_u64() {
-                return Value::Int(u.try_into().unwrap());
+        }
+        FromFormat::Text => {
+            let input = read_input_string(path)?;
+            for event in zlisp_text::events(&input) {
+                event?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn whitespace_config(args: &Args) -> zlisp_text::WhitespaceConfig<'_> {
+    zlisp_text::WhitespaceConfig::builder()
+        .indent(&args.indent.0)
+        .delimiter(&args.delimiter.0)
+        .newline(args.newline.as_str())
+        .build()
+}
+
+fn check(args: &Args) -> Result<()> {
+    if !matches!(
+        (&args.from, &args.to),
+        (FromFormat::Text, Some(ToFormat::Text))
+    ) {
+        return Err("checking requires `--from text --to text`".into());
+    }
+    let input = read_input_string(&args.input)?;
+    let value: Value = zlisp_text::from_str(&input)?;
+    let output = zlisp_text::to_pretty(&value, &whitespace_config(args))?;
+    if output != input {
+        return Err(format!("`{}` is not formatted", args.input).into());
+    }
+    Ok(())
+}
+
+/// Find the first difference between two values, with a path to it.
+fn difference(expected: &Value, actual: &Value, path: &str) -> Option<String> {
+    match (expected, actual) {
+        (Value::List(expected), Value::List(actual)) => {
+            for (index, (e, a)) in expected.iter().zip(actual).enumerate() {
+                let path = format!("{}[{}]", path, index);
+                if let Some(diff) = difference(e, a, &path) {
+                    return Some(diff);
+                }
             }
-            if let Some(f) = n.as_f64() {
-                return Value::Float(f as f32);
+            if expected.len() != actual.len() {
+                return Some(format!(
+                    "at `{}`: expected list length {}, found {}",
+                    path,
+                    expected.len(),
+                    actual.len()
+                ));
             }
-            panic!("expected any valid zlisp value");
+            None
+        }
+        // compare the bits, so `NaN` is equal to itself, and the sign of zero
+        // is checked
+        (Value::Float(expected), Value::Float(actual))
+            if expected.to_bits() == actual.to_bits() =>
+        {
+            None
         }
-        String(s) => Value::String(s),
-        Array(a) => {
-            let v: Vec<Value> = a.into_iter().map(json_to_zlisp).collect();
-            Value::List(v)
+        (Value::Float(_), Value::Float(_)) => {
+            Some(format!("at `{}`:\n- {}\n+ {}", path, expected, actual))
         }
+        (expected, actual) if expected == actual => None,
+        (expected, actual) => Some(format!("at `{}`:\n- {}\n+ {}", path, expected, actual)),
+    }
+}
+
+fn verify(args: &Args, to: ToFormat, expected: &Value, output: &[u8]) -> Result<()> {
+    let actual: Value = match to {
+        ToFormat::Json => {
+            let value: serde_json::Value = serde_json::from_slice(output)?;
+            json_to_zlisp(value, args.json_bool_as_int)?
+        }
+        ToFormat::Bin => zlisp_bin::from_slice(output)?,
+        ToFormat::Text => zlisp_text::from_str(std::str::from_utf8(output)?)?,
+        ToFormat::Ast => return Err("verifying is not supported for `--to ast`".into()),
+    };
+    match difference(expected, &actual, "$") {
+        Some(diff) => Err(format!("`{}` does not match the input {}", args.output, diff).into()),
+        None => Ok(()),
     }
 }
 
 fn main() {
     let args: Args = Args::parse();
-    println!("Reading {}", args.input);
+    if let Err(e) = run(args) {
+        eprintln!("Error: {}", e);
+        let mut source = e.source();
+        while let Some(e) = source {
+            eprintln!("Caused by: {}", e);
+            source = e.source();
+        }
+        std::process::exit(1);
+    }
+}
+
+fn run(args: Args) -> Result<()> {
+    eprintln!("Reading {}", args.input);
+    if args.validate {
+        validate(args.from, &args.input, args.json_bool_as_int)?;
+        println!("OK");
+        return Ok(());
+    }
+    if args.check {
+        check(&args)?;
+        println!("OK");
+        return Ok(());
+    }
+    let to = args.to.ok_or("an output format is required")?;
+    if args.verify && matches!(to, ToFormat::Ast) {
+        return Err("verifying is not supported for `--to ast`".into());
+    }
     let value: Value = match args.from {
-        FromFormat::JSON => {
-            let input = std::fs::read_to_string(args.input).unwrap();
+        FromFormat::Json => {
+            let input = read_input_string(&args.input)?;
             // due to serde_json's float handling (f64), an indirection is needed
-            let value: serde_json::Value = serde_json::from_str(&input).unwrap();
-            json_to_zlisp(value)
+            let value: serde_json::Value = serde_json::from_str(&input)?;
+            json_to_zlisp(value, args.json_bool_as_int)?
         }
         FromFormat::Bin => {
-            let input = std::fs::read(args.input).unwrap();
-            zlisp_bin::from_slice(&input).unwrap()
+            let input = read_input(&args.input)?;
+            zlisp_bin::from_slice(&input)?
         }
         FromFormat::Text => {
-            let input = std::fs::read_to_string(args.input).unwrap();
-            zlisp_text::from_str(&input).unwrap()
+            let input = read_input_string(&args.input)?;
+            zlisp_text::from_str(&input)?
         }
     };
-    println!("Writing {}", args.output);
-    match args.to {
-        ToFormat::JSON => {
-            let output = serde_json::to_string_pretty(&value).unwrap();
-            std::fs::write(args.output, output).unwrap();
-        }
-        ToFormat::Bin => {
-            let output = zlisp_bin::to_vec(&value).unwrap();
-            std::fs::write(args.output, output).unwrap();
-        }
-        ToFormat::Text => {
-            let config = zlisp_text::WhitespaceConfig::default();
-            let output = zlisp_text::to_pretty(&value, config).unwrap();
-            std::fs::write(args.output, output).unwrap();
-        }
-        ToFormat::Ast => {
-            let output = format!("{:#?}", value);
-            std::fs::write(args.output, output).unwrap();
+    if args.stats {
+        eprintln!("Nodes: {}, depth: {}", value.node_count(), value.depth());
+    }
+    eprintln!("Writing {}", args.output);
+    let output = match to {
+        ToFormat::Json => {
+            // serde_json would silently write non-finite floats as `null`
+            let value = serde_json::Value::try_from(value.clone())?;
+            serde_json::to_string_pretty(&value)?.into_bytes()
         }
+        ToFormat::Bin => zlisp_bin::to_vec(&value)?,
+        ToFormat::Text => zlisp_text::to_pretty(&value, &whitespace_config(&args))?.into_bytes(),
+        ToFormat::Ast => format!("{:#?}", value).into_bytes(),
+    };
+    write_output(&args.output, &output)?;
+    if args.verify {
+        eprintln!("Verifying {}", args.output);
+        verify(&args, to, &value, &output)?;
     }
-    println!("Done.");
+    eprintln!("Done.");
+    Ok(())
 }
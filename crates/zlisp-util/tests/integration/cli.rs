@@ -0,0 +1,36 @@
+use std::io::Write as _;
+use std::process::{Command, Output, Stdio};
+
+/// Run the utility, reading from stdin and writing to stdout.
+pub fn run(args: &[&str], input: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_zlisp"))
+        .args(args)
+        .args(["--newline", "unix", "-", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // the utility may exit before reading stdin, e.g. for invalid arguments
+    match child.stdin.take().unwrap().write_all(input) {
+        Err(e) if e.kind() == std::io::ErrorKind::BrokenPipe => {}
+        result => result.unwrap(),
+    }
+    child.wait_with_output().unwrap()
+}
+
+/// Run the utility, and return stdout on success.
+pub fn convert(args: &[&str], input: &[u8]) -> Vec<u8> {
+    let output = run(args, input);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    output.stdout
+}
+
+/// Run the utility, and return stdout as a string on success.
+pub fn convert_str(args: &[&str], input: &str) -> String {
+    String::from_utf8(convert(args, input.as_bytes())).unwrap()
+}
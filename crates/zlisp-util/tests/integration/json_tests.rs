@@ -0,0 +1,49 @@
+use super::cli::{convert_str, run};
+
+#[test]
+fn json_object_round_trip_tests() {
+    let json = r#"{"a": 1, "b": {"c": [2.5, "d"], "e": null}}"#;
+    let text = convert_str(&["--from", "json", "--to", "text"], json);
+    assert_eq!(text, "(a\t1\tb\t(c\t(2.5\td)\te\t()))\n");
+
+    // objects are read back as lists of keys and values
+    let json = convert_str(&["--from", "text", "--to", "json"], &text);
+    let actual: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let expected = serde_json::json!(["a", 1, "b", ["c", [2.5, "d"], "e", []]]);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_null_tests() {
+    let text = convert_str(&["--from", "json", "--to", "text"], "null");
+    assert_eq!(text, "()\n");
+}
+
+#[test]
+fn json_error_tests() {
+    let output = run(&["--from", "json", "--to", "text"], b"[true]");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("found `bool`"), "{}", stderr);
+
+    let output = run(&["--from", "json", "--to", "text"], b"[4294967296]");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("integer out of range"), "{}", stderr);
+}
+
+#[test]
+fn json_non_finite_tests() {
+    // JSON can't represent non-finite floats, which would otherwise be
+    // written as `null`, and read back as an empty list
+    let bin = zlisp_bin::to_vec(&vec![1.0f32, f32::NAN]).unwrap();
+    for args in [
+        &["--from", "bin", "--to", "json"][..],
+        &["--from", "bin", "--to", "json", "--verify"],
+    ] {
+        let output = run(args, &bin);
+        assert!(!output.status.success());
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stderr.contains("non-finite float"), "{}", stderr);
+    }
+}
@@ -0,0 +1,5 @@
+mod cli;
+mod json_tests;
+mod stats_tests;
+mod stdio_tests;
+mod verify_tests;
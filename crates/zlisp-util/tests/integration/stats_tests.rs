@@ -0,0 +1,18 @@
+use super::cli::run;
+
+#[test]
+fn stats_tests() {
+    let output = run(
+        &["--from", "text", "--to", "text", "--stats"],
+        b"(a (1 (2.0)) b ())",
+    );
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(output.status.success(), "{}", stderr);
+    assert!(stderr.contains("Nodes: 8, depth: 3"), "{}", stderr);
+}
+
+#[test]
+fn stats_conflicts_tests() {
+    let output = run(&["--from", "text", "--validate", "--stats"], b"()");
+    assert!(!output.status.success());
+}
@@ -0,0 +1,23 @@
+use super::cli::{convert, convert_str};
+
+#[test]
+fn stdio_tests() {
+    let text = "(a\t(1\t2.5))\n";
+
+    let bin = convert(&["--from", "text", "--to", "bin"], text.as_bytes());
+    let expected = zlisp_bin::to_vec(&("a", (1, 2.5f32))).unwrap();
+    assert_eq!(bin, expected);
+
+    let actual = convert(&["--from", "bin", "--to", "text"], &bin);
+    assert_eq!(String::from_utf8(actual).unwrap(), text);
+
+    let json = convert(&["--from", "bin", "--to", "json"], &bin);
+    let actual: serde_json::Value = serde_json::from_slice(&json).unwrap();
+    assert_eq!(actual, serde_json::json!(["a", [1, 2.5]]));
+
+    let ast = convert_str(&["--from", "text", "--to", "ast"], text);
+    assert!(ast.contains("Float(\n"), "{}", ast);
+
+    let ok = convert_str(&["--from", "text", "--validate"], text);
+    assert_eq!(ok, "OK\n");
+}
@@ -0,0 +1,36 @@
+use super::cli::{convert, run};
+
+#[test]
+fn verify_tests() {
+    let text = "(a\t(-0.0\t0.1)\tb\t())\n";
+    let bin = convert(
+        &["--from", "text", "--to", "bin", "--verify"],
+        text.as_bytes(),
+    );
+    for to in ["text", "json", "bin"] {
+        let output = run(&["--from", "bin", "--to", to, "--verify"], &bin);
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(output.status.success(), "{}", stderr);
+        assert!(stderr.contains("Verifying -"), "{}", stderr);
+    }
+}
+
+#[test]
+fn verify_ast_tests() {
+    let output = run(&["--from", "text", "--to", "ast", "--verify"], b"()");
+    assert!(!output.status.success());
+    // nothing is written
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("not supported for `--to ast`"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn verify_conflicts_tests() {
+    let output = run(&["--from", "text", "--validate", "--verify"], b"()");
+    assert!(!output.status.success());
+}
@@ -0,0 +1,2 @@
+pub(crate) const MAX_STRING_LEN: usize = 255;
+pub(crate) const MAX_LIST_LEN: usize = 4096;
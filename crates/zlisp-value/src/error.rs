@@ -0,0 +1,63 @@
+use std::fmt;
+
+/// This type represents all possible errors that can occur when building a
+/// [`Value`](crate::Value) that is valid for the zlisp data formats.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ValueError {
+    /// A list is too long.
+    ///
+    /// Lists may not be longer than 4096 items.
+    ListTooLong,
+    /// A string is too long.
+    ///
+    /// Strings may not be longer than 255 bytes.
+    StringTooLong,
+    /// A string contains a null character.
+    StringContainsNull,
+    /// A string contains a quote character.
+    StringContainsQuote,
+    /// A string contains an invalid character.
+    StringContainsInvalidChar,
+    /// A JSON `null` has no zlisp equivalent.
+    JsonNull,
+    /// A JSON boolean has no zlisp equivalent.
+    JsonBool,
+    /// A JSON integer is out of range for an `i32`.
+    IntOutOfRange,
+    /// A JSON float is out of range for an `f32`.
+    FloatOutOfRange,
+    /// A non-finite float has no JSON equivalent.
+    NonFiniteFloat,
+    /// A custom error message.
+    ///
+    /// This is how serde errors are reported when deserializing from a
+    /// [`&Value`](crate::Value).
+    Custom(String),
+}
+
+impl fmt::Display for ValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ListTooLong => f.write_str("list is too long"),
+            Self::StringTooLong => f.write_str("string is too long"),
+            Self::StringContainsNull => f.write_str("string contains a null"),
+            Self::StringContainsQuote => f.write_str("string contains a quote"),
+            Self::StringContainsInvalidChar => f.write_str("string contains a non-ASCII character"),
+            Self::JsonNull => f.write_str("JSON null is not supported"),
+            Self::JsonBool => f.write_str("JSON boolean is not supported"),
+            Self::IntOutOfRange => f.write_str("integer out of range"),
+            Self::FloatOutOfRange => f.write_str("float out of range"),
+            Self::NonFiniteFloat => f.write_str("non-finite float is not supported"),
+            Self::Custom(s) => f.write_str(s),
+        }
+    }
+}
+
+impl std::error::Error for ValueError {}
+
+impl serde::de::Error for ValueError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
@@ -10,10 +10,23 @@
 //! used. This is more useful for serialization, since the supported data types
 //! are fairly limited. For example, this can be used to serialize zlisp to
 //! JSON, using the [`serde_json`](https://crates.io/crates/serde_json) crate.
+//! With the `json` feature, values can also be converted to and from
+//! `serde_json::Value` directly.
 //!
 //! Apart from serde support, [`Value`] has several [`From`] implementations
 //! for easy constructing, as well as [`Debug`](std::fmt::Debug) and
-//! [`Display`](std::fmt::Display) implementations.
+//! [`Display`](std::fmt::Display) implementations. The [`zlisp!`] macro
+//! constructs nested values from a literal-like syntax.
+//!
+//! [`Value`] also implements [`IntoDeserializer`](serde::de::IntoDeserializer),
+//! so values can be used with generic serde adapters, for example to
+//! deserialize a struct from values. A borrowed [`Value`] is also a
+//! deserializer, which borrows its strings, e.g. for structs with `&str`
+//! fields.
+//!
+//! Values constructed directly are only validated against the format limits
+//! when serialized. [`ValueBuilder`] and [`Value::validate`] can be used to
+//! surface invalid values earlier.
 #![warn(
     missing_docs,
     future_incompatible,
@@ -21,6 +34,12 @@
     rust_2018_idioms,
     unused
 )]
+mod constants;
+mod error;
+mod macros;
 mod value;
 
-pub use value::Value;
+pub use error::ValueError;
+pub use value::{
+    IntoIter, Iter, Value, ValueBuilder, ValueDeserializer, ValueRef, ValueRefDeserializer,
+};
@@ -0,0 +1,33 @@
+/// Construct a [`Value`](crate::Value) from a literal-like syntax.
+///
+/// Bracketed or parenthesized groups of comma-separated elements are lists.
+/// Any other element is converted with [`From`], so integers are ints, floats
+/// are floats, and string literals are strings. Variables and other
+/// expressions can also be used, including nested values. For example,
+/// `zlisp!([1, 2.0, "foo", [3, -4]])` is a list of an int, a float, a string,
+/// and a nested list.
+#[macro_export]
+macro_rules! zlisp {
+    // munch list elements, since an element may be more than one token
+    (@list [$($done:expr,)*]) => {
+        $crate::Value::List(::std::vec![$($done,)*])
+    };
+    (@list [$($done:expr,)*] [$($inner:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::zlisp!(@list [$($done,)* $crate::zlisp!([$($inner)*]),] $($($rest)*)?)
+    };
+    (@list [$($done:expr,)*] ($($inner:tt)*) $(, $($rest:tt)*)?) => {
+        $crate::zlisp!(@list [$($done,)* $crate::zlisp!([$($inner)*]),] $($($rest)*)?)
+    };
+    (@list [$($done:expr,)*] $next:expr $(, $($rest:tt)*)?) => {
+        $crate::zlisp!(@list [$($done,)* $crate::zlisp!($next),] $($($rest)*)?)
+    };
+    ([$($tt:tt)*]) => {
+        $crate::zlisp!(@list [] $($tt)*)
+    };
+    (($($tt:tt)*)) => {
+        $crate::zlisp!(@list [] $($tt)*)
+    };
+    ($other:expr) => {
+        $crate::Value::from($other)
+    };
+}
@@ -0,0 +1,103 @@
+use super::Value;
+use crate::constants::{MAX_LIST_LEN, MAX_STRING_LEN};
+use crate::error::ValueError;
+
+fn validate_str(v: &str) -> Result<(), ValueError> {
+    if v.len() > MAX_STRING_LEN {
+        return Err(ValueError::StringTooLong);
+    }
+    for b in v.bytes() {
+        match b {
+            b'\0' => return Err(ValueError::StringContainsNull),
+            b'"' => return Err(ValueError::StringContainsQuote),
+            _ if !b.is_ascii() => return Err(ValueError::StringContainsInvalidChar),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+impl Value {
+    /// Construct a string value, validating it against the format limits.
+    pub fn try_string(v: &str) -> Result<Self, ValueError> {
+        validate_str(v)?;
+        Ok(Self::String(v.to_owned()))
+    }
+
+    /// Validate the value and any nested values against the format limits.
+    ///
+    /// A valid value can be serialized to both the binary and text format.
+    pub fn validate(&self) -> Result<(), ValueError> {
+        match self {
+            Self::Int(_) | Self::Float(_) => Ok(()),
+            Self::String(v) => validate_str(v),
+            Self::List(items) => {
+                if items.len() > MAX_LIST_LEN {
+                    return Err(ValueError::ListTooLong);
+                }
+                items.iter().try_for_each(Value::validate)
+            }
+        }
+    }
+}
+
+/// A builder of list values, which validates items as they are pushed.
+///
+/// Unlike constructing a [`Value::List`] directly, this surfaces values that
+/// exceed the format limits when building instead of when serializing.
+#[derive(Debug, Clone, Default)]
+pub struct ValueBuilder {
+    items: Vec<Value>,
+}
+
+impl ValueBuilder {
+    /// Construct a new, empty list builder.
+    pub const fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    fn push_unchecked(&mut self, value: Value) -> Result<&mut Self, ValueError> {
+        if self.items.len() >= MAX_LIST_LEN {
+            return Err(ValueError::ListTooLong);
+        }
+        self.items.push(value);
+        Ok(self)
+    }
+
+    /// Push a value, which is validated (including any nested values).
+    pub fn push(&mut self, value: Value) -> Result<&mut Self, ValueError> {
+        value.validate()?;
+        self.push_unchecked(value)
+    }
+
+    /// Push an int value.
+    pub fn push_int(&mut self, v: i32) -> Result<&mut Self, ValueError> {
+        self.push_unchecked(Value::Int(v))
+    }
+
+    /// Push a float value.
+    pub fn push_float(&mut self, v: f32) -> Result<&mut Self, ValueError> {
+        self.push_unchecked(Value::Float(v))
+    }
+
+    /// Push a string value, which is validated.
+    pub fn push_str(&mut self, v: &str) -> Result<&mut Self, ValueError> {
+        let value = Value::try_string(v)?;
+        self.push_unchecked(value)
+    }
+
+    /// The number of items pushed so far.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Whether no items have been pushed so far.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Construct the list value.
+    pub fn build(self) -> Value {
+        Value::List(self.items)
+    }
+}
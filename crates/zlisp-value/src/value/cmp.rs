@@ -0,0 +1,62 @@
+use super::Value;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// Values can be used as keys, e.g. in a `HashMap` or `HashSet`.
+///
+/// Floats are compared with `==`, so `NaN` is not equal to itself. Keys
+/// containing `NaN` can be inserted, but never found again.
+impl Eq for Value {}
+
+/// Floats are hashed by their bits, except that `-0.0` and `0.0` hash the
+/// same, since they are equal.
+impl Hash for Value {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Int(v) => v.hash(state),
+            Self::Float(v) => {
+                // adding zero turns a negative zero into a positive zero
+                (v + 0.0).to_bits().hash(state)
+            }
+            Self::String(v) => v.hash(state),
+            Self::List(v) => v.hash(state),
+        }
+    }
+}
+
+fn variant_order(value: &Value) -> u8 {
+    match value {
+        Value::Int(_) => 0,
+        Value::Float(_) => 1,
+        Value::String(_) => 2,
+        Value::List(_) => 3,
+    }
+}
+
+impl Value {
+    /// A total ordering of values, e.g. for sorting.
+    ///
+    /// Values of different types are ordered ints, floats, strings, and then
+    /// lists. Floats are ordered by [`f32::total_cmp`], and lists are ordered
+    /// lexicographically. This isn't an [`Ord`] implementation, since it
+    /// disagrees with `==` for floats: `-0.0` is less than `0.0`, and `NaN` is
+    /// equal to itself.
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Int(a), Self::Int(b)) => a.cmp(b),
+            (Self::Float(a), Self::Float(b)) => a.total_cmp(b),
+            (Self::String(a), Self::String(b)) => a.cmp(b),
+            (Self::List(a), Self::List(b)) => {
+                for (a, b) in a.iter().zip(b.iter()) {
+                    match a.total_cmp(b) {
+                        Ordering::Equal => {}
+                        ordering => return ordering,
+                    }
+                }
+                a.len().cmp(&b.len())
+            }
+            (a, b) => variant_order(a).cmp(&variant_order(b)),
+        }
+    }
+}
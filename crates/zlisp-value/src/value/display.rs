@@ -1,8 +1,9 @@
-use super::Value;
+use super::{Value, ValueRef};
 use std::fmt;
 
 trait Scope {
     fn write_list(&self, f: &mut fmt::Formatter<'_>, entries: &[Value]) -> fmt::Result;
+    fn write_string(&self, f: &mut fmt::Formatter<'_>, v: &str) -> fmt::Result;
     fn inc(&self) -> Self;
 }
 
@@ -21,13 +22,41 @@ impl Scope for DefaultScope {
         f.write_str(")")
     }
 
+    fn write_string(&self, f: &mut fmt::Formatter<'_>, v: &str) -> fmt::Result {
+        f.write_str(v)
+    }
+
     fn inc(&self) -> Self {
         Self
     }
 }
 
+/// The pretty scope matches the output of `zlisp_text::to_pretty` with the
+/// default whitespace configuration.
 struct PrettyScope(usize);
 
+const NEWLINE: &str = "\r\n";
+
+/// Whether a list is written on a single line.
+fn is_compact(value: &Value) -> bool {
+    match value {
+        Value::List(v) => v.len() < 7 && v.iter().all(is_compact),
+        _ => true,
+    }
+}
+
+/// Whether a string must be quoted to be read back as the same string.
+fn needs_quoting(s: &str) -> bool {
+    // empty strings would disappear, strings that could be numbers would be
+    // read as numbers, and a semicolon starts a comment if the reader allows
+    // comments.
+    s.is_empty()
+        || s.bytes()
+            .all(|b| matches!(b, b'-' | b'+' | b'.' | b'0'..=b'9'))
+        || s.bytes()
+            .any(|b| matches!(b, b' ' | b'\t' | b'\r' | b'\n' | b'(' | b')' | b';'))
+}
+
 impl PrettyScope {
     fn write_indent(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for _ in 0..self.0 {
@@ -44,54 +73,89 @@ impl Scope for PrettyScope {
             return f.write_str("()");
         }
 
-        let nested_list = v.iter().any(|item| matches!(item, Value::List(_)));
-        if nested_list {
-            f.write_str("(\n")?;
-            {
-                let scope = self.inc();
-                for item in v {
-                    scope.write_indent(f)?;
-                    Display::fmt(item, &scope, f)?;
-                    f.write_str("\n")?;
-                }
+        let scope = self.inc();
+        if v.len() < 7 && v.iter().all(is_compact) {
+            f.write_str("(")?;
+            Display::fmt(&v[0], &scope, f)?;
+            for item in &v[1..] {
+                f.write_str("\t")?;
+                Display::fmt(item, &scope, f)?;
             }
-            self.write_indent(f)?;
             f.write_str(")")
         } else {
             f.write_str("(")?;
-            Display::fmt(&v[0], self, f)?;
-            for item in &v[1..] {
-                f.write_str("\t")?;
-                Display::fmt(item, self, f)?;
+            f.write_str(NEWLINE)?;
+            for item in v {
+                scope.write_indent(f)?;
+                Display::fmt(item, &scope, f)?;
+                f.write_str(NEWLINE)?;
             }
+            self.write_indent(f)?;
             f.write_str(")")
         }
     }
 
+    fn write_string(&self, f: &mut fmt::Formatter<'_>, v: &str) -> fmt::Result {
+        if needs_quoting(v) {
+            write!(f, "\"{}\"", v)
+        } else {
+            f.write_str(v)
+        }
+    }
+
     fn inc(&self) -> Self {
         Self(self.0 + 1)
     }
 }
 
+/// Write a float using the shortest representation that round-trips.
+///
+/// Finite values always have a decimal point, so they aren't read back as
+/// ints.
+fn write_float(f: &mut fmt::Formatter<'_>, v: f32) -> fmt::Result {
+    let s = v.to_string();
+    f.write_str(&s)?;
+    if v.is_finite() && !s.contains('.') {
+        f.write_str(".0")?;
+    }
+    Ok(())
+}
+
 trait Display<S: Scope> {
     fn fmt(&self, scope: &S, f: &mut fmt::Formatter<'_>) -> fmt::Result;
 }
 
 impl<S: Scope> Display<S> for Value {
     fn fmt(&self, scope: &S, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
+        Display::fmt(&self.as_ref(), scope, f)
+    }
+}
+
+impl<S: Scope> Display<S> for ValueRef<'_> {
+    fn fmt(&self, scope: &S, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
             Self::List(v) => scope.write_list(f, v),
             Self::Int(v) => write!(f, "{}", v),
-            Self::Float(v) => write!(f, "{:.6}", v),
-            Self::String(v) => f.write_str(v),
+            Self::Float(v) => write_float(f, v),
+            Self::String(v) => scope.write_string(f, v),
         }
     }
 }
 
+/// The alternate form (`{:#}`) is identical to `zlisp_text::to_pretty` with
+/// the default whitespace configuration, including the trailing newline.
 impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_ref(), f)
+    }
+}
+
+/// This is identical to the [`Value`] implementation.
+impl fmt::Display for ValueRef<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if f.alternate() {
-            Display::fmt(self, &PrettyScope(0), f)
+            Display::fmt(self, &PrettyScope(0), f)?;
+            f.write_str(NEWLINE)
         } else {
             Display::fmt(self, &DefaultScope, f)
         }
@@ -6,6 +6,13 @@ impl From<i32> for Value {
     }
 }
 
+/// Booleans are integer-encoded, as the ints `0` and `1`.
+impl From<bool> for Value {
+    fn from(v: bool) -> Self {
+        Self::Int(v.into())
+    }
+}
+
 impl From<f32> for Value {
     fn from(v: f32) -> Self {
         Self::Float(v)
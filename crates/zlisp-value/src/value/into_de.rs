@@ -0,0 +1,377 @@
+use super::Value;
+use crate::error::ValueError;
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{self, IntoDeserializer, Unexpected, Visitor};
+use std::marker::PhantomData;
+
+/// A deserializer that owns a [`Value`].
+///
+/// This is constructed via [`IntoDeserializer`], and can be used with any
+/// error type.
+#[derive(Debug)]
+pub struct ValueDeserializer<E> {
+    value: Value,
+    marker: PhantomData<E>,
+}
+
+/// A deserializer that borrows a [`Value`].
+///
+/// This is constructed via [`IntoDeserializer`], and can be used with any
+/// error type.
+#[derive(Debug)]
+pub struct ValueRefDeserializer<'de, E> {
+    value: &'de Value,
+    marker: PhantomData<E>,
+}
+
+impl<'de, E> IntoDeserializer<'de, E> for Value
+where
+    E: de::Error,
+{
+    type Deserializer = ValueDeserializer<E>;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        ValueDeserializer {
+            value: self,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, E> IntoDeserializer<'de, E> for &'de Value
+where
+    E: de::Error,
+{
+    type Deserializer = ValueRefDeserializer<'de, E>;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        ValueRefDeserializer::new(self)
+    }
+}
+
+impl<'de, E> ValueRefDeserializer<'de, E> {
+    const fn new(value: &'de Value) -> Self {
+        Self {
+            value,
+            marker: PhantomData,
+        }
+    }
+}
+
+fn unexpected(value: &Value) -> Unexpected<'_> {
+    match value {
+        Value::Int(v) => Unexpected::Signed(*v as i64),
+        Value::Float(v) => Unexpected::Float(*v as f64),
+        Value::String(s) => Unexpected::Str(s),
+        Value::List(_) => Unexpected::Seq,
+    }
+}
+
+/// Maps are lists of alternating keys and values.
+struct Pairs<I>(I);
+
+impl<I> Iterator for Pairs<I>
+where
+    I: Iterator,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.0.next()?;
+        let value = self.0.next()?;
+        Some((key, value))
+    }
+}
+
+impl<'de, E> de::Deserializer<'de> for ValueDeserializer<E>
+where
+    E: de::Error,
+{
+    type Error = E;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Int(v) => visitor.visit_i32(v),
+            Value::Float(v) => visitor.visit_f32(v),
+            Value::String(s) => visitor.visit_string(s),
+            Value::List(v) => {
+                let mut seq = SeqDeserializer::new(v.into_iter());
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        // an option is a list of zero or one elements
+        match self.value {
+            Value::List(v) if v.is_empty() => visitor.visit_none(),
+            Value::List(mut v) if v.len() == 1 => {
+                // PANIC: the length was checked
+                visitor.visit_some(v.pop().unwrap().into_deserializer())
+            }
+            Value::List(v) => Err(de::Error::invalid_length(v.len(), &visitor)),
+            ref other => Err(de::Error::invalid_type(unexpected(other), &visitor)),
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        // unit is an empty list
+        match self.value {
+            Value::List(v) if v.is_empty() => visitor.visit_unit(),
+            ref other => Err(de::Error::invalid_type(unexpected(other), &visitor)),
+        }
+    }
+
+    fn deserialize_unit_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        // a map is a list of alternating keys and values
+        match self.value {
+            Value::List(v) if v.len() % 2 == 0 => {
+                let mut map = MapDeserializer::new(Pairs(v.into_iter()));
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+            Value::List(v) => Err(de::Error::invalid_length(v.len(), &visitor)),
+            ref other => Err(de::Error::invalid_type(unexpected(other), &visitor)),
+        }
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_map(visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf seq tuple tuple_struct enum identifier ignored_any
+    }
+}
+
+impl<'de, E> de::Deserializer<'de> for ValueRefDeserializer<'de, E>
+where
+    E: de::Error,
+{
+    type Error = E;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Int(v) => visitor.visit_i32(*v),
+            Value::Float(v) => visitor.visit_f32(*v),
+            Value::String(s) => visitor.visit_borrowed_str(s),
+            Value::List(v) => {
+                let mut seq = SeqDeserializer::new(v.iter());
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        // an option is a list of zero or one elements
+        match self.value {
+            Value::List(v) => match v.as_slice() {
+                [] => visitor.visit_none(),
+                [inner] => visitor.visit_some(inner.into_deserializer()),
+                _ => Err(de::Error::invalid_length(v.len(), &visitor)),
+            },
+            other => Err(de::Error::invalid_type(unexpected(other), &visitor)),
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        // unit is an empty list
+        match self.value {
+            Value::List(v) if v.is_empty() => visitor.visit_unit(),
+            other => Err(de::Error::invalid_type(unexpected(other), &visitor)),
+        }
+    }
+
+    fn deserialize_unit_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        // a map is a list of alternating keys and values
+        match self.value {
+            Value::List(v) if v.len() % 2 == 0 => {
+                let mut map = MapDeserializer::new(Pairs(v.iter()));
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+            Value::List(v) => Err(de::Error::invalid_length(v.len(), &visitor)),
+            other => Err(de::Error::invalid_type(unexpected(other), &visitor)),
+        }
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_map(visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf seq tuple tuple_struct enum identifier ignored_any
+    }
+}
+
+macro_rules! forward_to_ref {
+    ($($method:ident)*) => {
+        $(
+        fn $method<V>(self, visitor: V) -> Result<V::Value, ValueError>
+        where
+            V: Visitor<'de>,
+        {
+            ValueRefDeserializer::<ValueError>::new(self).$method(visitor)
+        }
+        )*
+    };
+}
+
+/// A borrowed value can be deserialized from directly.
+///
+/// Strings are borrowed from the value, so types with borrowed fields can be
+/// deserialized, as long as the value outlives them.
+impl<'de> de::Deserializer<'de> for &'de Value {
+    type Error = ValueError;
+
+    forward_to_ref! {
+        deserialize_any deserialize_bool deserialize_i8 deserialize_i16
+        deserialize_i32 deserialize_i64 deserialize_i128 deserialize_u8
+        deserialize_u16 deserialize_u32 deserialize_u64 deserialize_u128
+        deserialize_f32 deserialize_f64 deserialize_char deserialize_str
+        deserialize_string deserialize_bytes deserialize_byte_buf
+        deserialize_option deserialize_unit deserialize_seq deserialize_map
+        deserialize_identifier deserialize_ignored_any
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ValueError>
+    where
+        V: Visitor<'de>,
+    {
+        ValueRefDeserializer::<ValueError>::new(self).deserialize_unit_struct(name, visitor)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ValueError>
+    where
+        V: Visitor<'de>,
+    {
+        ValueRefDeserializer::<ValueError>::new(self).deserialize_newtype_struct(name, visitor)
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, ValueError>
+    where
+        V: Visitor<'de>,
+    {
+        ValueRefDeserializer::<ValueError>::new(self).deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, ValueError>
+    where
+        V: Visitor<'de>,
+    {
+        ValueRefDeserializer::<ValueError>::new(self).deserialize_tuple_struct(name, len, visitor)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ValueError>
+    where
+        V: Visitor<'de>,
+    {
+        ValueRefDeserializer::<ValueError>::new(self).deserialize_struct(name, fields, visitor)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ValueError>
+    where
+        V: Visitor<'de>,
+    {
+        ValueRefDeserializer::<ValueError>::new(self).deserialize_enum(name, variants, visitor)
+    }
+}
@@ -0,0 +1,95 @@
+use super::Value;
+use std::iter::FusedIterator;
+
+/// An owning iterator over the elements of a list value.
+///
+/// This is created by [`Value::into_iter`]. Values that aren't lists have no
+/// elements.
+#[derive(Debug)]
+pub struct IntoIter(std::vec::IntoIter<Value>);
+
+impl Iterator for IntoIter {
+    type Item = Value;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for IntoIter {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl ExactSizeIterator for IntoIter {}
+impl FusedIterator for IntoIter {}
+
+/// A borrowing iterator over the elements of a list value.
+///
+/// This is created by iterating over a `&Value`. Values that aren't lists
+/// have no elements.
+#[derive(Debug, Clone)]
+pub struct Iter<'a>(std::slice::Iter<'a, Value>);
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a Value;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+impl FusedIterator for Iter<'_> {}
+
+impl IntoIterator for Value {
+    type Item = Value;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            Self::List(v) => IntoIter(v.into_iter()),
+            _ => IntoIter(Vec::new().into_iter()),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Value {
+    type Item = &'a Value;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            Value::List(v) => Iter(v.iter()),
+            _ => Iter([].iter()),
+        }
+    }
+}
+
+/// Collect values into a list value.
+impl FromIterator<Value> for Value {
+    fn from_iter<I: IntoIterator<Item = Value>>(iter: I) -> Self {
+        Self::List(iter.into_iter().collect())
+    }
+}
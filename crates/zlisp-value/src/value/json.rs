@@ -0,0 +1,91 @@
+use super::Value;
+use crate::error::ValueError;
+use serde_json::{Map, Number};
+
+fn from_number(n: Number) -> Result<Value, ValueError> {
+    if let Some(i) = n.as_i64() {
+        i.try_into()
+            .map(Value::Int)
+            .map_err(|_| ValueError::IntOutOfRange)
+    } else if n.is_u64() {
+        // any u64 that isn't also an i64 is out of range
+        Err(ValueError::IntOutOfRange)
+    } else {
+        // serde_json numbers are always finite, but may not fit an f32
+        let f = n.as_f64().ok_or(ValueError::FloatOutOfRange)? as f32;
+        if f.is_finite() {
+            Ok(Value::Float(f))
+        } else {
+            Err(ValueError::FloatOutOfRange)
+        }
+    }
+}
+
+fn from_object(o: Map<String, serde_json::Value>) -> Result<Value, ValueError> {
+    let mut items = Vec::with_capacity(o.len() * 2);
+    for (key, value) in o {
+        items.push(Value::String(key));
+        items.push(value.try_into()?);
+    }
+    Ok(Value::List(items))
+}
+
+/// Convert a JSON value to a zlisp value.
+///
+/// Arrays are converted to lists, and objects to lists of alternating keys and
+/// values, like structs. JSON `null`, booleans, and numbers that don't fit an
+/// `i32` or `f32` have no zlisp equivalent, and are rejected.
+impl TryFrom<serde_json::Value> for Value {
+    type Error = ValueError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        match value {
+            serde_json::Value::Null => Err(ValueError::JsonNull),
+            serde_json::Value::Bool(_) => Err(ValueError::JsonBool),
+            serde_json::Value::Number(n) => from_number(n),
+            serde_json::Value::String(s) => Ok(Value::String(s)),
+            serde_json::Value::Array(a) => a
+                .into_iter()
+                .map(Value::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::List),
+            serde_json::Value::Object(o) => from_object(o),
+        }
+    }
+}
+
+fn to_number(f: f32) -> Result<serde_json::Value, ValueError> {
+    if !f.is_finite() {
+        return Err(ValueError::NonFiniteFloat);
+    }
+    // widening the float directly would show the error of the f32, e.g.
+    // `0.1` as `0.10000000149011612`. instead, use the shortest representation
+    // of the f32, which reads back as the same f32.
+    format!("{}", f)
+        .parse()
+        .ok()
+        .and_then(Number::from_f64)
+        .map(serde_json::Value::Number)
+        .ok_or(ValueError::NonFiniteFloat)
+}
+
+/// Convert a zlisp value to a JSON value.
+///
+/// Lists are always converted to arrays, even if they could be objects.
+/// Non-finite floats have no JSON equivalent, and are rejected.
+impl TryFrom<Value> for serde_json::Value {
+    type Error = ValueError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Int(v) => Ok(Self::Number(v.into())),
+            Value::Float(v) => to_number(v),
+            Value::String(v) => Ok(Self::String(v)),
+            Value::List(items) => items
+                .into_iter()
+                .map(Self::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .map(Self::Array),
+        }
+    }
+}
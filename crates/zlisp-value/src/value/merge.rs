@@ -0,0 +1,41 @@
+use super::Value;
+
+/// Whether the items are alternating string keys and values.
+fn is_map(items: &[Value]) -> bool {
+    items.len().is_multiple_of(2) && items.iter().step_by(2).all(|key| key.as_str().is_some())
+}
+
+impl Value {
+    /// Merge another value into this value.
+    ///
+    /// If both values are lists of alternating string keys and values, i.e.
+    /// association lists like `(k1 v1 k2 v2)`, they are merged by key. Keys
+    /// match if the strings are equal. For each key in the other value, the
+    /// value of the first matching key in this value is merged recursively.
+    /// Keys that don't match are appended in order. Empty lists are also
+    /// association lists, so merging an empty list has no effect.
+    ///
+    /// Otherwise, e.g. for scalars, odd-length lists, or lists with non-string
+    /// keys, this value is replaced by the other value.
+    pub fn merge(&mut self, other: Value) {
+        match (self, other) {
+            (Value::List(items), Value::List(other)) if is_map(items) && is_map(&other) => {
+                let mut iter = other.into_iter();
+                while let (Some(key), Some(value)) = (iter.next(), iter.next()) {
+                    let existing = items
+                        .chunks_exact_mut(2)
+                        .find(|pair| pair[0] == key)
+                        .map(|pair| &mut pair[1]);
+                    match existing {
+                        Some(existing) => existing.merge(value),
+                        None => {
+                            items.push(key);
+                            items.push(value);
+                        }
+                    }
+                }
+            }
+            (this, other) => *this = other,
+        }
+    }
+}
@@ -1,9 +1,24 @@
+mod builder;
+mod cmp;
 mod de;
 mod display;
 mod from;
+mod into_de;
+mod iter;
+#[cfg(feature = "json")]
+mod json;
+mod merge;
 mod ser;
+mod stats;
+mod value_ref;
 
 use std::fmt;
+use std::ops::Index;
+
+pub use builder::ValueBuilder;
+pub use into_de::{ValueDeserializer, ValueRefDeserializer};
+pub use iter::{IntoIter, Iter};
+pub use value_ref::ValueRef;
 
 /// Represents any valid zlisp value.
 #[derive(Clone, PartialEq)]
@@ -18,13 +33,214 @@ pub enum Value {
     List(Vec<Value>),
 }
 
-impl fmt::Debug for Value {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Value {
+    /// The human-readable name of the value's type.
+    ///
+    /// This is one of "int", "float", "string", or "list", and is useful for
+    /// error messages and logging.
+    #[inline]
+    pub const fn type_name(&self) -> &'static str {
+        match self {
+            Self::Int(_) => "int",
+            Self::Float(_) => "float",
+            Self::String(_) => "string",
+            Self::List(_) => "list",
+        }
+    }
+
+    /// Borrow the value, e.g. to match on it without cloning.
+    #[inline]
+    pub fn as_ref(&self) -> ValueRef<'_> {
+        ValueRef::from(self)
+    }
+
+    /// Whether the value is an int.
+    #[inline]
+    pub const fn is_int(&self) -> bool {
+        matches!(self, Self::Int(_))
+    }
+
+    /// Whether the value is a float.
+    #[inline]
+    pub const fn is_float(&self) -> bool {
+        matches!(self, Self::Float(_))
+    }
+
+    /// Whether the value is a string.
+    #[inline]
+    pub const fn is_string(&self) -> bool {
+        matches!(self, Self::String(_))
+    }
+
+    /// Whether the value is a list.
+    #[inline]
+    pub const fn is_list(&self) -> bool {
+        matches!(self, Self::List(_))
+    }
+
+    /// Interpret an integer-encoded boolean.
+    ///
+    /// Booleans are commonly stored as the ints `0` and `1`. Any other value
+    /// is not a boolean, and returns `None`.
+    #[inline]
+    pub const fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Int(0) => Some(false),
+            Self::Int(1) => Some(true),
+            _ => None,
+        }
+    }
+
+    /// The integer, if the value is an int.
+    #[inline]
+    pub const fn as_int(&self) -> Option<i32> {
+        match self {
+            Self::Int(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// The float, if the value is a float.
+    #[inline]
+    pub const fn as_float(&self) -> Option<f32> {
+        match self {
+            Self::Float(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// The string, if the value is a string.
+    #[inline]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// The list elements, if the value is a list.
+    #[inline]
+    pub fn as_list(&self) -> Option<&[Value]> {
+        match self {
+            Self::List(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// The mutable integer, if the value is an int.
+    #[inline]
+    pub fn as_int_mut(&mut self) -> Option<&mut i32> {
+        match self {
+            Self::Int(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// The mutable float, if the value is a float.
+    #[inline]
+    pub fn as_float_mut(&mut self) -> Option<&mut f32> {
+        match self {
+            Self::Float(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// The mutable string, if the value is a string.
+    #[inline]
+    pub fn as_string_mut(&mut self) -> Option<&mut String> {
+        match self {
+            Self::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// The mutable list, if the value is a list.
+    ///
+    /// This can be used to edit a list in place, e.g. to add elements.
+    #[inline]
+    pub fn as_list_mut(&mut self) -> Option<&mut Vec<Value>> {
+        match self {
+            Self::List(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// The list element at the index, if the value is a list and the index is
+    /// in bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&Value> {
+        self.as_list().and_then(|v| v.get(index))
+    }
+
+    /// The nested list element at the path of indices.
+    ///
+    /// Each index selects an element of the list at the previous level, so
+    /// `value.pointer(&[1, 0])` is the first element of the second element.
+    /// An empty path returns the value itself.
+    pub fn pointer(&self, path: &[usize]) -> Option<&Value> {
+        path.iter().try_fold(self, |value, index| value.get(*index))
+    }
+
+    /// Take the integer, or return the value if it is not an int.
+    #[inline]
+    pub fn into_int(self) -> Result<i32, Self> {
+        match self {
+            Self::Int(v) => Ok(v),
+            other => Err(other),
+        }
+    }
+
+    /// Take the float, or return the value if it is not a float.
+    #[inline]
+    pub fn into_float(self) -> Result<f32, Self> {
+        match self {
+            Self::Float(v) => Ok(v),
+            other => Err(other),
+        }
+    }
+
+    /// Take the string, or return the value if it is not a string.
+    ///
+    /// Unlike matching on a reference, this does not clone the string.
+    #[inline]
+    pub fn into_string(self) -> Result<String, Self> {
+        match self {
+            Self::String(v) => Ok(v),
+            other => Err(other),
+        }
+    }
+
+    /// Take the list, or return the value if it is not a list.
+    ///
+    /// Unlike matching on a reference, this does not clone the elements.
+    #[inline]
+    pub fn into_list(self) -> Result<Vec<Value>, Self> {
+        match self {
+            Self::List(v) => Ok(v),
+            other => Err(other),
+        }
+    }
+}
+
+/// Index into a list value.
+///
+/// # Panics
+///
+/// Panics if the value is not a list, or the index is out of bounds. Use
+/// [`Value::get`] to avoid panicking.
+impl Index<usize> for Value {
+    type Output = Value;
+
+    fn index(&self, index: usize) -> &Self::Output {
         match self {
-            Self::Int(v) => f.debug_tuple("Int").field(v).finish(),
-            Self::Float(v) => f.debug_tuple("Float").field(v).finish(),
-            Self::String(v) => f.debug_tuple("String").field(v).finish(),
-            Self::List(v) => f.debug_list().entries(v.iter()).finish(),
+            Self::List(v) => &v[index],
+            other => panic!("cannot index into {}", other.type_name()),
         }
     }
 }
+
+impl fmt::Debug for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.as_ref(), f)
+    }
+}
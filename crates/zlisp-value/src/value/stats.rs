@@ -0,0 +1,37 @@
+use super::Value;
+
+impl Value {
+    /// The total number of nodes in the value, i.e. scalars and lists.
+    ///
+    /// Every scalar counts as one node, as does every list, in addition to its
+    /// items. This does not recurse, so it is safe for deeply nested values.
+    pub fn node_count(&self) -> usize {
+        let mut count = 0;
+        let mut stack = vec![self];
+        while let Some(value) = stack.pop() {
+            count += 1;
+            if let Value::List(items) = value {
+                stack.extend(items);
+            }
+        }
+        count
+    }
+
+    /// The maximum nesting depth of lists in the value.
+    ///
+    /// Scalars have a depth of zero, and a list has a depth of one more than
+    /// its deepest item, so an empty list has a depth of one. This does not
+    /// recurse, so it is safe for deeply nested values.
+    pub fn depth(&self) -> usize {
+        let mut max = 0;
+        let mut stack = vec![(self, 0)];
+        while let Some((value, depth)) = stack.pop() {
+            if let Value::List(items) = value {
+                let depth = depth + 1;
+                max = max.max(depth);
+                stack.extend(items.iter().map(|item| (item, depth)));
+            }
+        }
+        max
+    }
+}
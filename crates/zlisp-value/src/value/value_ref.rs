@@ -0,0 +1,53 @@
+use super::Value;
+use std::fmt;
+
+/// A borrowed zlisp value.
+///
+/// This mirrors [`Value`], but borrows strings and lists, so a value can be
+/// matched and walked without cloning. Use [`Value::as_ref`] to construct
+/// one.
+#[derive(Clone, Copy, PartialEq)]
+pub enum ValueRef<'a> {
+    /// Represents an integer.
+    Int(i32),
+    /// Represents a float.
+    Float(f32),
+    /// Represents a string.
+    String(&'a str),
+    /// Represents a list.
+    List(&'a [Value]),
+}
+
+impl<'a> ValueRef<'a> {
+    /// Clone the borrowed data into an owned value.
+    pub fn to_value(&self) -> Value {
+        match *self {
+            Self::Int(v) => Value::Int(v),
+            Self::Float(v) => Value::Float(v),
+            Self::String(v) => Value::String(v.to_owned()),
+            Self::List(v) => Value::List(v.to_vec()),
+        }
+    }
+}
+
+impl<'a> From<&'a Value> for ValueRef<'a> {
+    fn from(v: &'a Value) -> Self {
+        match v {
+            Value::Int(v) => Self::Int(*v),
+            Value::Float(v) => Self::Float(*v),
+            Value::String(v) => Self::String(v),
+            Value::List(v) => Self::List(v),
+        }
+    }
+}
+
+impl fmt::Debug for ValueRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(v) => f.debug_tuple("Int").field(v).finish(),
+            Self::Float(v) => f.debug_tuple("Float").field(v).finish(),
+            Self::String(v) => f.debug_tuple("String").field(v).finish(),
+            Self::List(v) => f.debug_list().entries(v.iter()).finish(),
+        }
+    }
+}
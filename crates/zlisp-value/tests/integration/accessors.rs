@@ -0,0 +1,63 @@
+use zlisp_value::Value;
+
+#[test]
+fn as_tests() {
+    let int = Value::from(1);
+    let float = Value::from(1.5);
+    let string = Value::from("foo");
+    let list = Value::from(vec![Value::from(1), Value::from("foo")]);
+
+    assert_eq!(int.as_int(), Some(1));
+    assert_eq!(float.as_int(), None);
+    assert_eq!(float.as_float(), Some(1.5));
+    assert_eq!(int.as_float(), None);
+    assert_eq!(string.as_str(), Some("foo"));
+    assert_eq!(list.as_str(), None);
+    assert_eq!(
+        list.as_list(),
+        Some(&[Value::Int(1), Value::String("foo".to_string())][..])
+    );
+    assert_eq!(string.as_list(), None);
+}
+
+#[test]
+fn as_mut_tests() {
+    let mut value = Value::from(1);
+    *value.as_int_mut().unwrap() += 1;
+    assert_eq!(value, Value::Int(2));
+    assert_eq!(value.as_float_mut(), None);
+
+    let mut value = Value::from(1.5);
+    *value.as_float_mut().unwrap() *= 2.0;
+    assert_eq!(value, Value::Float(3.0));
+    assert_eq!(value.as_int_mut(), None);
+
+    let mut value = Value::from("foo");
+    value.as_string_mut().unwrap().push_str("bar");
+    assert_eq!(value, Value::from("foobar"));
+    assert_eq!(value.as_list_mut(), None);
+
+    let mut value = Value::from(vec![Value::from(1)]);
+    value.as_list_mut().unwrap().push(Value::from(2));
+    assert_eq!(value, Value::from(vec![Value::from(1), Value::from(2)]));
+    assert_eq!(value.as_string_mut(), None);
+}
+
+#[test]
+fn walk_tests() {
+    // e.g. a document of name/value pairs
+    let doc = Value::from(vec![
+        Value::from(vec![Value::from("a"), Value::from(1)]),
+        Value::from(vec![Value::from("b"), Value::from(2)]),
+    ]);
+    let pairs: Vec<(&str, i32)> = doc
+        .as_list()
+        .unwrap()
+        .iter()
+        .filter_map(|pair| {
+            let pair = pair.as_list()?;
+            Some((pair.first()?.as_str()?, pair.get(1)?.as_int()?))
+        })
+        .collect();
+    assert_eq!(pairs, vec![("a", 1), ("b", 2)]);
+}
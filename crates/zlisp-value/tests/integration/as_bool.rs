@@ -0,0 +1,24 @@
+use zlisp_value::Value;
+
+#[test]
+fn as_bool_tests() {
+    assert_eq!(Value::from(0).as_bool(), Some(false));
+    assert_eq!(Value::from(1).as_bool(), Some(true));
+    assert_eq!(Value::from(2).as_bool(), None);
+    assert_eq!(Value::from(-1).as_bool(), None);
+    assert_eq!(Value::from(1.0).as_bool(), None);
+    assert_eq!(Value::from("1").as_bool(), None);
+    assert_eq!(Value::from(vec![]).as_bool(), None);
+}
+
+#[test]
+fn from_bool_tests() {
+    assert_eq!(Value::from(false), Value::Int(0));
+    assert_eq!(Value::from(true), Value::Int(1));
+    assert_eq!(Value::from(true).as_bool(), Some(true));
+    assert_eq!(Value::from(false).as_bool(), Some(false));
+
+    // reading a flag from a list
+    let value = Value::from(vec![Value::from("flag"), Value::from(true)]);
+    assert_eq!(value.get(1).and_then(Value::as_bool), Some(true));
+}
@@ -0,0 +1,58 @@
+use zlisp_value::{Value, ValueBuilder, ValueError};
+
+#[test]
+fn builder_tests() {
+    let mut builder = ValueBuilder::new();
+    assert!(builder.is_empty());
+    builder
+        .push_int(1)
+        .unwrap()
+        .push_float(2.0)
+        .unwrap()
+        .push_str("foo")
+        .unwrap()
+        .push(Value::List(vec![Value::from("bar")]))
+        .unwrap();
+    assert_eq!(builder.len(), 4);
+    let expected = Value::List(vec![
+        Value::Int(1),
+        Value::Float(2.0),
+        Value::from("foo"),
+        Value::List(vec![Value::from("bar")]),
+    ]);
+    assert_eq!(builder.build(), expected);
+}
+
+#[test]
+fn list_too_long_tests() {
+    let mut builder = ValueBuilder::new();
+    for i in 0..4096 {
+        builder.push_int(i).unwrap();
+    }
+    assert_eq!(builder.push_int(4096).unwrap_err(), ValueError::ListTooLong);
+    assert_eq!(builder.len(), 4096);
+
+    let nested = Value::List(vec![Value::Int(0); 4097]);
+    let err = ValueBuilder::new().push(nested).unwrap_err();
+    assert_eq!(err, ValueError::ListTooLong);
+}
+
+#[test]
+fn string_tests() {
+    let mut builder = ValueBuilder::new();
+    builder.push_str(&" ".repeat(255)).unwrap();
+    let err = builder.push_str(&" ".repeat(256)).unwrap_err();
+    assert_eq!(err, ValueError::StringTooLong);
+    let err = builder.push_str("\0").unwrap_err();
+    assert_eq!(err, ValueError::StringContainsNull);
+    let err = builder.push_str("\"").unwrap_err();
+    assert_eq!(err, ValueError::StringContainsQuote);
+    let err = builder.push_str("🎅").unwrap_err();
+    assert_eq!(err, ValueError::StringContainsInvalidChar);
+    assert_eq!(builder.len(), 1);
+
+    let nested = Value::List(vec![Value::List(vec![Value::from("\0")])]);
+    assert_eq!(nested.validate(), Err(ValueError::StringContainsNull));
+    assert_eq!(Value::try_string("foo"), Ok(Value::from("foo")));
+    assert_eq!(err.to_string(), "string contains a non-ASCII character");
+}
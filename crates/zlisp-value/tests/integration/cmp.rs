@@ -0,0 +1,71 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use zlisp_value::{zlisp, Value};
+
+fn hash(value: &Value) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn hash_tests() {
+    // equal values hash the same
+    assert_eq!(hash(&zlisp!([1, "foo"])), hash(&zlisp!([1, "foo"])));
+    assert_eq!(hash(&zlisp!(0.0)), hash(&zlisp!(-0.0)));
+    assert_eq!(zlisp!(0.0), zlisp!(-0.0));
+
+    let mut set = HashSet::new();
+    set.insert(zlisp!(1));
+    set.insert(zlisp!(1.0));
+    set.insert(zlisp!("1"));
+    set.insert(zlisp!([1]));
+    set.insert(zlisp!(1));
+    set.insert(zlisp!(-0.0));
+    set.insert(zlisp!(0.0));
+    assert_eq!(set.len(), 5);
+
+    let mut map = HashMap::new();
+    map.insert(zlisp!(["a", 1]), "first");
+    assert_eq!(map.get(&zlisp!(["a", 1])), Some(&"first"));
+
+    // NaN is never equal to itself, so it can't be found
+    let mut set = HashSet::new();
+    set.insert(zlisp!(f32::NAN));
+    assert!(!set.contains(&zlisp!(f32::NAN)));
+}
+
+#[test]
+fn total_cmp_tests() {
+    assert_eq!(zlisp!(1).total_cmp(&zlisp!(2)), Ordering::Less);
+    assert_eq!(zlisp!(-0.0).total_cmp(&zlisp!(0.0)), Ordering::Less);
+    assert_eq!(
+        zlisp!(f32::NAN).total_cmp(&zlisp!(f32::NAN)),
+        Ordering::Equal
+    );
+    assert_eq!(zlisp!("a").total_cmp(&zlisp!("b")), Ordering::Less);
+    assert_eq!(zlisp!([1, 2]).total_cmp(&zlisp!([1, 3])), Ordering::Less);
+    assert_eq!(zlisp!([1]).total_cmp(&zlisp!([1, 0])), Ordering::Less);
+    assert_eq!(zlisp!([1, 2]).total_cmp(&zlisp!([1, 2])), Ordering::Equal);
+
+    let mut values = vec![
+        zlisp!([]),
+        zlisp!("foo"),
+        zlisp!(1.5),
+        zlisp!(2),
+        zlisp!(-1),
+    ];
+    values.sort_by(Value::total_cmp);
+    assert_eq!(
+        values,
+        vec![
+            zlisp!(-1),
+            zlisp!(2),
+            zlisp!(1.5),
+            zlisp!("foo"),
+            zlisp!([])
+        ]
+    );
+}
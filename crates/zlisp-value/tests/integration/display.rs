@@ -13,9 +13,9 @@ fn display_tests() {
     assert_display!(0, "0");
     assert_display!(i32::MAX, format!("{}", i32::MAX));
 
-    assert_display!(f32::MIN, format!("{:.6}", f32::MIN));
-    assert_display!(0.0, "0.000000");
-    assert_display!(f32::MAX, format!("{:.6}", f32::MAX));
+    assert_display!(f32::MIN, format!("{}.0", f32::MIN));
+    assert_display!(0.0, "0.0");
+    assert_display!(f32::MAX, format!("{}.0", f32::MAX));
 
     assert_display!("foo", "foo");
 
@@ -28,14 +28,14 @@ fn display_tests() {
             Value::from("foo"),
             Value::from(&[])
         ],
-        "(0 0.000000 foo ())"
+        "(0 0.0 foo ())"
     );
 }
 
 macro_rules! assert_pretty {
     ($value:expr, $display:expr) => {
         let v: Value = $value.into();
-        assert_eq!(format!("{:#}", v), $display);
+        assert_eq!(format!("{:#}", v), format!("{}\r\n", $display));
     };
 }
 
@@ -45,9 +45,9 @@ fn pretty_tests() {
     assert_pretty!(0, "0");
     assert_pretty!(i32::MAX, format!("{}", i32::MAX));
 
-    assert_pretty!(f32::MIN, format!("{:.6}", f32::MIN));
-    assert_pretty!(0.0, "0.000000");
-    assert_pretty!(f32::MAX, format!("{:.6}", f32::MAX));
+    assert_pretty!(f32::MIN, format!("{}.0", f32::MIN));
+    assert_pretty!(0.0, "0.0");
+    assert_pretty!(f32::MAX, format!("{}.0", f32::MAX));
 
     assert_pretty!("foo", "foo");
 
@@ -55,22 +55,12 @@ fn pretty_tests() {
     assert_pretty!(&[Value::Int(0)], "(0)");
     assert_pretty!(
         &[Value::from(0), Value::from(0.0), Value::from("foo")],
-        "(0\t0.000000\tfoo)"
-    );
-
-    assert_pretty!(
-        &[Value::from(&[])],
-        "(
-\t()
-)"
-    );
-    assert_pretty!(
-        &[Value::from(&[Value::from(0)])],
-        "(
-\t(0)
-)"
+        "(0\t0.0\tfoo)"
     );
 
+    // nested lists are compact, if they are short
+    assert_pretty!(&[Value::from(&[])], "(())");
+    assert_pretty!(&[Value::from(&[Value::from(0)])], "((0))");
     assert_pretty!(
         &[
             Value::from(0),
@@ -78,11 +68,29 @@ fn pretty_tests() {
             Value::from("foo"),
             Value::from(&[])
         ],
-        "(
-\t0
-\t0.000000
-\tfoo
-\t()
-)"
+        "(0\t0.0\tfoo\t())"
+    );
+
+    // long lists are expanded
+    assert_pretty!(
+        vec![Value::from(0); 7],
+        "(\r\n\t0\r\n\t0\r\n\t0\r\n\t0\r\n\t0\r\n\t0\r\n\t0\r\n)"
+    );
+    assert_pretty!(
+        &[Value::from(vec![Value::from(0); 7]), Value::from(&[])],
+        "(\r\n\t(\r\n\t\t0\r\n\t\t0\r\n\t\t0\r\n\t\t0\r\n\t\t0\r\n\t\t0\r\n\t\t0\r\n\t)\r\n\t()\r\n)"
     );
 }
+
+#[test]
+fn pretty_quoting_tests() {
+    assert_pretty!("", "\"\"");
+    assert_pretty!("a b", "\"a b\"");
+    assert_pretty!("a(b)", "\"a(b)\"");
+    assert_pretty!("1.5", "\"1.5\"");
+    assert_pretty!("-", "\"-\"");
+    assert_pretty!("a1", "a1");
+
+    // the compact form does not quote
+    assert_eq!(format!("{}", Value::from("a b")), "a b");
+}
@@ -0,0 +1,47 @@
+use zlisp_value::Value;
+
+fn record() -> Value {
+    Value::from(vec![
+        Value::from("name"),
+        Value::from(vec![Value::from(1), Value::from(vec![Value::from(2.0)])]),
+    ])
+}
+
+#[test]
+fn index_tests() {
+    let value = record();
+    assert_eq!(value[0], Value::from("name"));
+    assert_eq!(value[1][0], Value::from(1));
+    assert_eq!(value[1][1][0], Value::from(2.0));
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn index_out_of_bounds_tests() {
+    let _ = &record()[2];
+}
+
+#[test]
+#[should_panic(expected = "cannot index into string")]
+fn index_not_list_tests() {
+    let _ = &record()[0][0];
+}
+
+#[test]
+fn get_tests() {
+    let value = record();
+    assert_eq!(value.get(0), Some(&Value::from("name")));
+    assert_eq!(value.get(2), None);
+    assert_eq!(value[0].get(0), None);
+}
+
+#[test]
+fn pointer_tests() {
+    let value = record();
+    assert_eq!(value.pointer(&[]), Some(&value));
+    assert_eq!(value.pointer(&[0]), Some(&Value::from("name")));
+    assert_eq!(value.pointer(&[1, 1, 0]), Some(&Value::from(2.0)));
+    assert_eq!(value.pointer(&[1, 2]), None);
+    assert_eq!(value.pointer(&[0, 0]), None);
+    assert_eq!(value.pointer(&[1, 1, 0, 0]), None);
+}
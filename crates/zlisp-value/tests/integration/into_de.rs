@@ -0,0 +1,132 @@
+use serde::de::value::{Error, MapDeserializer};
+use serde::de::{Deserialize, IntoDeserializer};
+use serde_derive::Deserialize;
+use std::collections::HashMap;
+use zlisp_value::Value;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Struct {
+    a: i32,
+    b: String,
+    c: Option<f32>,
+    d: Vec<i32>,
+}
+
+fn owned<'de, T: Deserialize<'de>>(value: Value) -> Result<T, Error> {
+    T::deserialize(value.into_deserializer())
+}
+
+fn borrowed<'de, T: Deserialize<'de>>(value: &'de Value) -> Result<T, Error> {
+    T::deserialize(value.into_deserializer())
+}
+
+#[test]
+fn scalar_tests() {
+    assert_eq!(owned::<i32>(Value::from(1)).unwrap(), 1);
+    assert_eq!(owned::<f32>(Value::from(1.5)).unwrap(), 1.5);
+    assert_eq!(owned::<String>(Value::from("foo")).unwrap(), "foo");
+    let value = Value::from("foo");
+    assert_eq!(borrowed::<&str>(&value).unwrap(), "foo");
+
+    owned::<i32>(Value::from("foo")).unwrap_err();
+    owned::<String>(Value::from(1)).unwrap_err();
+}
+
+#[test]
+fn list_tests() {
+    let value = Value::from(vec![Value::from(1), Value::from(2)]);
+    assert_eq!(borrowed::<Vec<i32>>(&value).unwrap(), vec![1, 2]);
+    assert_eq!(borrowed::<(i32, i32)>(&value).unwrap(), (1, 2));
+    assert_eq!(owned::<Vec<i32>>(value).unwrap(), vec![1, 2]);
+
+    let value = Value::from(vec![]);
+    assert_eq!(borrowed::<()>(&value).unwrap(), ());
+    assert_eq!(borrowed::<Option<i32>>(&value).unwrap(), None);
+    let value = Value::from(vec![Value::from(1)]);
+    assert_eq!(borrowed::<Option<i32>>(&value).unwrap(), Some(1));
+    assert_eq!(owned::<Option<i32>>(value).unwrap(), Some(1));
+    let value = Value::from(vec![Value::from(1), Value::from(2)]);
+    borrowed::<Option<i32>>(&value).unwrap_err();
+    owned::<(i32,)>(value).unwrap_err();
+}
+
+#[test]
+fn struct_tests() {
+    let value = Value::from(vec![
+        Value::from("a"),
+        Value::from(1),
+        Value::from("b"),
+        Value::from("foo"),
+        Value::from("c"),
+        Value::from(vec![Value::from(1.5)]),
+        Value::from("d"),
+        Value::from(vec![Value::from(2), Value::from(3)]),
+    ]);
+    let expected = Struct {
+        a: 1,
+        b: "foo".to_owned(),
+        c: Some(1.5),
+        d: vec![2, 3],
+    };
+    assert_eq!(borrowed::<Struct>(&value).unwrap(), expected);
+    assert_eq!(owned::<Struct>(value).unwrap(), expected);
+
+    let value = Value::from(vec![Value::from(1), Value::from(2), Value::from(3)]);
+    borrowed::<HashMap<i32, i32>>(&value).unwrap_err();
+}
+
+#[test]
+fn map_deserializer_tests() {
+    let values: Vec<(&str, Value)> = vec![
+        ("a", Value::from(1)),
+        ("b", Value::from("foo")),
+        ("c", Value::from(vec![])),
+        ("d", Value::from(vec![Value::from(2)])),
+    ];
+    let de = MapDeserializer::<_, Error>::new(values.into_iter());
+    let v = Struct::deserialize(de).unwrap();
+    assert_eq!(
+        v,
+        Struct {
+            a: 1,
+            b: "foo".to_owned(),
+            c: None,
+            d: vec![2],
+        }
+    );
+}
+
+#[test]
+fn value_ref_deserializer_tests() {
+    use zlisp_value::ValueError;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Borrowed<'a> {
+        name: &'a str,
+        tags: Vec<&'a str>,
+    }
+
+    let value = Value::from(vec![
+        Value::from("name"),
+        Value::from("foo"),
+        Value::from("tags"),
+        Value::from(vec![Value::from("a"), Value::from("b")]),
+    ]);
+    let v = Borrowed::deserialize(&value).unwrap();
+    assert_eq!(
+        v,
+        Borrowed {
+            name: "foo",
+            tags: vec!["a", "b"],
+        }
+    );
+
+    assert_eq!(i32::deserialize(&Value::from(1)).unwrap(), 1);
+    assert_eq!(<&str>::deserialize(&Value::from("foo")).unwrap(), "foo");
+
+    let err = i32::deserialize(&Value::from("foo")).unwrap_err();
+    assert_eq!(
+        err,
+        ValueError::Custom("invalid type: string \"foo\", expected i32".to_owned())
+    );
+}
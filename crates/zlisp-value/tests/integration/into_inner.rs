@@ -0,0 +1,33 @@
+use zlisp_value::Value;
+
+#[test]
+fn into_int_tests() {
+    assert_eq!(Value::Int(1).into_int(), Ok(1));
+    assert_eq!(Value::Float(1.0).into_int(), Err(Value::Float(1.0)));
+}
+
+#[test]
+fn into_float_tests() {
+    assert_eq!(Value::Float(1.5).into_float(), Ok(1.5));
+    assert_eq!(Value::Int(1).into_float(), Err(Value::Int(1)));
+}
+
+#[test]
+fn into_string_tests() {
+    let value = Value::from("foo");
+    assert_eq!(value.into_string(), Ok("foo".to_string()));
+    let value = Value::from(vec![Value::from("foo")]);
+    assert_eq!(value.clone().into_string(), Err(value));
+}
+
+#[test]
+fn into_list_tests() {
+    let value = Value::from(vec![Value::from(1), Value::from("foo")]);
+    let list = value.into_list().unwrap();
+    assert_eq!(list, vec![Value::Int(1), Value::String("foo".to_string())]);
+
+    // no data is lost on a mismatch
+    let value = Value::from("foo");
+    let err = value.into_list().unwrap_err();
+    assert_eq!(err.into_string(), Ok("foo".to_string()));
+}
@@ -0,0 +1,44 @@
+use zlisp_value::{zlisp, Value};
+
+#[test]
+fn into_iter_tests() {
+    let value = zlisp!([1, "foo", [2]]);
+    let items: Vec<Value> = value.into_iter().collect();
+    assert_eq!(items, vec![zlisp!(1), zlisp!("foo"), zlisp!([2])]);
+
+    // scalars have no elements
+    assert_eq!(zlisp!(1).into_iter().count(), 0);
+    assert_eq!(zlisp!("foo").into_iter().count(), 0);
+
+    let mut iter = zlisp!([1, 2, 3]).into_iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back(), Some(zlisp!(3)));
+    assert_eq!(iter.next(), Some(zlisp!(1)));
+    assert_eq!(iter.len(), 1);
+}
+
+#[test]
+fn iter_tests() {
+    let value = zlisp!([1, 2, 3]);
+    let mut sum = 0;
+    for item in &value {
+        sum += item.as_int().unwrap();
+    }
+    assert_eq!(sum, 6);
+    assert_eq!((&value).into_iter().next_back(), Some(&zlisp!(3)));
+
+    assert_eq!((&zlisp!(1.5)).into_iter().count(), 0);
+}
+
+#[test]
+fn from_iter_tests() {
+    let value: Value = (1..=3).map(Value::from).collect();
+    assert_eq!(value, zlisp!([1, 2, 3]));
+
+    let value: Value = std::iter::empty().collect();
+    assert_eq!(value, zlisp!([]));
+
+    // round trip
+    let value = zlisp!([1, [2.0], "foo"]);
+    assert_eq!(value.clone().into_iter().collect::<Value>(), value);
+}
@@ -0,0 +1,66 @@
+use serde_json::json;
+use zlisp_value::{zlisp, Value, ValueError};
+
+#[test]
+fn scalar_tests() {
+    assert_eq!(Value::try_from(json!(-1)), Ok(zlisp!(-1)));
+    assert_eq!(Value::try_from(json!(0.5)), Ok(zlisp!(0.5)));
+    assert_eq!(Value::try_from(json!("foo")), Ok(zlisp!("foo")));
+
+    assert_eq!(serde_json::Value::try_from(zlisp!(-1)), Ok(json!(-1)));
+    assert_eq!(serde_json::Value::try_from(zlisp!(0.5)), Ok(json!(0.5)));
+    assert_eq!(serde_json::Value::try_from(zlisp!("foo")), Ok(json!("foo")));
+}
+
+#[test]
+fn nested_tests() {
+    let input = json!({
+        "a": [1, [2.5, "b"], []],
+        "c": {"d": {}, "e": "f"},
+    });
+    let expected = zlisp!(["a", [1, [2.5, "b"], []], "c", ["d", [], "e", "f"]]);
+    assert_eq!(Value::try_from(input), Ok(expected.clone()));
+
+    // lists are always converted to arrays
+    let expected_json = json!(["a", [1, [2.5, "b"], []], "c", ["d", [], "e", "f"]]);
+    assert_eq!(serde_json::Value::try_from(expected), Ok(expected_json));
+}
+
+#[test]
+fn float_tests() {
+    // the shortest representation of the f32 is kept
+    assert_eq!(serde_json::Value::try_from(zlisp!(0.1)), Ok(json!(0.1)));
+    for v in [0.1, -1.5, 1e-10, 3.4028235e38, f32::MIN_POSITIVE] {
+        let converted = serde_json::Value::try_from(Value::Float(v)).unwrap();
+        assert_eq!(Value::try_from(converted), Ok(Value::Float(v)));
+    }
+    // non-finite floats can't be represented in JSON
+    for v in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+        assert_eq!(
+            serde_json::Value::try_from(Value::Float(v)),
+            Err(ValueError::NonFiniteFloat)
+        );
+    }
+    // nested errors are surfaced
+    assert_eq!(
+        serde_json::Value::try_from(Value::List(vec![zlisp!(1), Value::Float(f32::NAN)])),
+        Err(ValueError::NonFiniteFloat)
+    );
+}
+
+#[test]
+fn unrepresentable_tests() {
+    let cases = [
+        (json!(null), ValueError::JsonNull),
+        (json!(true), ValueError::JsonBool),
+        (json!(2147483648i64), ValueError::IntOutOfRange),
+        (json!(-2147483649i64), ValueError::IntOutOfRange),
+        (json!(u64::MAX), ValueError::IntOutOfRange),
+        (json!(1e39), ValueError::FloatOutOfRange),
+        // nested errors are surfaced
+        (json!([1, {"a": null}]), ValueError::JsonNull),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(Value::try_from(input), Err(expected));
+    }
+}
@@ -0,0 +1,43 @@
+use zlisp_value::{zlisp, Value};
+
+#[test]
+fn scalar_tests() {
+    assert_eq!(zlisp!(1), Value::Int(1));
+    assert_eq!(zlisp!(-1), Value::Int(-1));
+    assert_eq!(zlisp!(2.0), Value::Float(2.0));
+    assert_eq!(zlisp!("foo"), Value::String("foo".to_string()));
+}
+
+#[test]
+fn list_tests() {
+    assert_eq!(zlisp!([]), Value::List(vec![]));
+    assert_eq!(zlisp!(()), Value::List(vec![]));
+    assert_eq!(
+        zlisp!([1, 2.0, "foo", [3, -4]]),
+        Value::List(vec![
+            Value::Int(1),
+            Value::Float(2.0),
+            Value::String("foo".to_string()),
+            Value::List(vec![Value::Int(3), Value::Int(-4)]),
+        ])
+    );
+    // parentheses and a trailing comma
+    assert_eq!(zlisp!((1, (2, []),)), zlisp!([1, [2, []]]));
+}
+
+#[test]
+fn expr_tests() {
+    let x = 5;
+    let name = String::from("bar");
+    let inner = zlisp!([1]);
+    assert_eq!(
+        zlisp!([x, x + 1, name, inner, -1.5]),
+        Value::List(vec![
+            Value::Int(5),
+            Value::Int(6),
+            Value::String("bar".to_string()),
+            Value::List(vec![Value::Int(1)]),
+            Value::Float(-1.5),
+        ])
+    );
+}
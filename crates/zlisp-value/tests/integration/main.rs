@@ -1,3 +1,18 @@
+mod accessors;
+mod as_bool;
+mod builder;
+mod cmp;
 mod debug;
 mod display;
+mod index;
+mod into_de;
+mod into_inner;
+mod iter;
+#[cfg(feature = "json")]
+mod json;
+mod macros;
+mod merge;
 mod serde;
+mod stats;
+mod type_name;
+mod value_ref;
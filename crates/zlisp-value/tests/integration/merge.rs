@@ -0,0 +1,58 @@
+use zlisp_value::{zlisp, Value};
+
+fn merged(mut base: Value, patch: Value) -> Value {
+    base.merge(patch);
+    base
+}
+
+#[test]
+fn merge_map_tests() {
+    let base = zlisp!(["a", 1, "b", 2]);
+    // later keys win, new keys are appended
+    let actual = merged(base.clone(), zlisp!(["b", 3, "c", 4]));
+    assert_eq!(actual, zlisp!(["a", 1, "b", 3, "c", 4]));
+    // an empty patch has no effect
+    let actual = merged(base.clone(), zlisp!([]));
+    assert_eq!(actual, base);
+    // an empty base takes the patch
+    let actual = merged(zlisp!([]), base.clone());
+    assert_eq!(actual, base);
+}
+
+#[test]
+fn merge_nested_tests() {
+    let base = zlisp!(["a", ["x", 1, "y", 2], "b", [1, 2, 3]]);
+    let patch = zlisp!(["a", ["y", 3, "z", ["w", 4]], "b", [4]]);
+    let actual = merged(base, patch);
+    // nested maps are merged, nested lists are replaced
+    let expected = zlisp!(["a", ["x", 1, "y", 3, "z", ["w", 4]], "b", [4]]);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn merge_key_tests() {
+    // keys are compared as strings, so the int 1 is not the string "1"
+    let base = zlisp!(["1", "a"]);
+    let actual = merged(base.clone(), zlisp!(["1", "b"]));
+    assert_eq!(actual, zlisp!(["1", "b"]));
+    let actual = merged(base, zlisp!([1, "b"]));
+    assert_eq!(actual, zlisp!([1, "b"]));
+
+    // only the first matching key is merged
+    let actual = merged(zlisp!(["a", 1, "a", 2]), zlisp!(["a", 3]));
+    assert_eq!(actual, zlisp!(["a", 3, "a", 2]));
+}
+
+#[test]
+fn merge_replace_tests() {
+    // odd-length lists are replaced
+    let actual = merged(zlisp!(["a", 1, "b"]), zlisp!(["a", 2]));
+    assert_eq!(actual, zlisp!(["a", 2]));
+    let actual = merged(zlisp!(["a", 1]), zlisp!(["a", 2, "b"]));
+    assert_eq!(actual, zlisp!(["a", 2, "b"]));
+    // scalars are replaced
+    let actual = merged(zlisp!(1), zlisp!(["a", 2]));
+    assert_eq!(actual, zlisp!(["a", 2]));
+    let actual = merged(zlisp!(["a", 1]), zlisp!("foo"));
+    assert_eq!(actual, zlisp!("foo"));
+}
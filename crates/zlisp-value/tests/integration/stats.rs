@@ -0,0 +1,47 @@
+use zlisp_value::{zlisp, Value};
+
+fn nested(depth: usize) -> Value {
+    let mut value = zlisp!([]);
+    for _ in 1..depth {
+        value = Value::List(vec![value]);
+    }
+    value
+}
+
+#[test]
+fn scalar_tests() {
+    assert_eq!(zlisp!(1).node_count(), 1);
+    assert_eq!(zlisp!(1).depth(), 0);
+    assert_eq!(zlisp!("foo").node_count(), 1);
+    assert_eq!(zlisp!("foo").depth(), 0);
+}
+
+#[test]
+fn empty_list_tests() {
+    assert_eq!(zlisp!([]).node_count(), 1);
+    assert_eq!(zlisp!([]).depth(), 1);
+}
+
+#[test]
+fn flat_list_tests() {
+    let value = zlisp!([1, 2.0, "three"]);
+    assert_eq!(value.node_count(), 4);
+    assert_eq!(value.depth(), 1);
+}
+
+#[test]
+fn nested_list_tests() {
+    let value = zlisp!([1, [2, [3, []]], [4]]);
+    assert_eq!(value.node_count(), 9);
+    assert_eq!(value.depth(), 4);
+}
+
+#[test]
+fn deeply_nested_list_tests() {
+    // deep enough to overflow the stack if this recursed
+    let value = nested(100_000);
+    assert_eq!(value.node_count(), 100_000);
+    assert_eq!(value.depth(), 100_000);
+    // dropping the value recurses, so leak it instead
+    std::mem::forget(value);
+}
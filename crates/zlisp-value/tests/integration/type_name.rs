@@ -0,0 +1,30 @@
+use zlisp_value::Value;
+
+#[test]
+fn type_name_tests() {
+    assert_eq!(Value::from(0).type_name(), "int");
+    assert_eq!(Value::from(0.0).type_name(), "float");
+    assert_eq!(Value::from("foo").type_name(), "string");
+    assert_eq!(Value::from(vec![]).type_name(), "list");
+    assert_eq!(
+        format!("expected list, got {}", Value::from(0).type_name()),
+        "expected list, got int"
+    );
+}
+
+#[test]
+fn predicate_tests() {
+    let values = [
+        Value::from(0),
+        Value::from(0.0),
+        Value::from("foo"),
+        Value::from(vec![]),
+    ];
+    for value in &values {
+        let name = value.type_name();
+        assert_eq!(value.is_int(), name == "int");
+        assert_eq!(value.is_float(), name == "float");
+        assert_eq!(value.is_string(), name == "string");
+        assert_eq!(value.is_list(), name == "list");
+    }
+}
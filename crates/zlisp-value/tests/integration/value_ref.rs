@@ -0,0 +1,46 @@
+use zlisp_value::{zlisp, Value, ValueRef};
+
+#[test]
+fn as_ref_tests() {
+    assert_eq!(zlisp!(1).as_ref(), ValueRef::Int(1));
+    assert_eq!(zlisp!(1.5).as_ref(), ValueRef::Float(1.5));
+    assert_eq!(zlisp!("foo").as_ref(), ValueRef::String("foo"));
+    let value = zlisp!([1, "foo"]);
+    assert_eq!(value.as_ref(), ValueRef::List(&[zlisp!(1), zlisp!("foo")]));
+}
+
+#[test]
+fn walk_tests() {
+    fn count_strings(value: &Value) -> usize {
+        match value.as_ref() {
+            ValueRef::String(_) => 1,
+            ValueRef::List(items) => items.iter().map(count_strings).sum(),
+            ValueRef::Int(_) | ValueRef::Float(_) => 0,
+        }
+    }
+    assert_eq!(count_strings(&zlisp!(["a", 1, ["b", ["c"]], 2.0])), 3);
+}
+
+#[test]
+fn to_value_tests() {
+    let value = zlisp!([1, 2.0, "foo", [3]]);
+    assert_eq!(value.as_ref().to_value(), value);
+}
+
+#[test]
+fn fmt_tests() {
+    let values = [
+        zlisp!(1),
+        zlisp!(1.5),
+        zlisp!("foo"),
+        zlisp!(""),
+        zlisp!([1, "a b", [2.0]]),
+        zlisp!([1, 2, 3, 4, 5, 6, 7]),
+    ];
+    for value in &values {
+        let value_ref = value.as_ref();
+        assert_eq!(format!("{:?}", value_ref), format!("{:?}", value));
+        assert_eq!(format!("{}", value_ref), format!("{}", value));
+        assert_eq!(format!("{:#}", value_ref), format!("{:#}", value));
+    }
+}
@@ -0,0 +1,90 @@
+use serde::{de, ser};
+use std::fmt;
+
+/// Represents a boolean zlisp value.
+///
+/// The zlisp data formats have no booleans, so the serializers and
+/// deserializers reject `bool`. This newtype opts in to representing a
+/// boolean as an integer in either format: `true` is serialized as `1`, and
+/// `false` as `0`. Other integers can't be deserialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Bool(bool);
+
+impl Bool {
+    /// Construct a new value.
+    pub const fn new(value: bool) -> Self {
+        Self(value)
+    }
+
+    /// Get the underlying value.
+    pub const fn get(&self) -> bool {
+        self.0
+    }
+}
+
+impl From<bool> for Bool {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Bool> for bool {
+    fn from(value: Bool) -> Self {
+        value.0
+    }
+}
+
+impl PartialEq<bool> for Bool {
+    fn eq(&self, other: &bool) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Bool> for bool {
+    fn eq(&self, other: &Bool) -> bool {
+        *self == other.0
+    }
+}
+
+struct BoolVisitor;
+
+impl<'de> de::Visitor<'de> for BoolVisitor {
+    type Value = Bool;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("the integer 0 or 1")
+    }
+
+    fn visit_i32<E>(self, value: i32) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match value {
+            0 => Ok(Bool(false)),
+            1 => Ok(Bool(true)),
+            _ => Err(E::invalid_value(
+                de::Unexpected::Signed(value.into()),
+                &self,
+            )),
+        }
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Bool {
+    fn deserialize<D>(deserializer: D) -> Result<Bool, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_i32(BoolVisitor)
+    }
+}
+
+impl ser::Serialize for Bool {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_i32(i32::from(self.0))
+    }
+}
@@ -0,0 +1,47 @@
+use std::fmt;
+
+/// This type represents all possible errors that can occur when converting
+/// between the zlisp data formats.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// An error reading or writing text data.
+    Text(zlisp_text::Error),
+    /// An error reading or writing binary data.
+    Bin(zlisp_bin::Error),
+}
+
+/// A specialized [Result](std::result::Result) type for conversion
+/// operations.
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(e) => fmt::Display::fmt(e, f),
+            Self::Bin(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        // the errors are displayed transparently, so their sources are too
+        match self {
+            Self::Text(e) => e.source(),
+            Self::Bin(e) => e.source(),
+        }
+    }
+}
+
+impl From<zlisp_text::Error> for Error {
+    fn from(e: zlisp_text::Error) -> Self {
+        Self::Text(e)
+    }
+}
+
+impl From<zlisp_bin::Error> for Error {
+    fn from(e: zlisp_bin::Error) -> Self {
+        Self::Bin(e)
+    }
+}
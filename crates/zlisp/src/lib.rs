@@ -0,0 +1,25 @@
+//! Conversion between the Zipper-style, lisp-like data structures (zlisp)
+//! binary and text data formats.
+//!
+//! The crates `zlisp-bin` and `zlisp-text` provide serde capabilities for
+//! the binary and text data formats, respectively. Converting between the
+//! formats with these crates requires an intermediate value, e.g. a
+//! `zlisp_value::Value`. This crate instead converts the data directly, which
+//! is faster and uses less memory.
+//!
+//! The [`Bool`] newtype allows booleans to be serialized and deserialized as
+//! integers in either format.
+#![warn(
+    missing_docs,
+    future_incompatible,
+    nonstandard_style,
+    rust_2018_idioms,
+    unused
+)]
+mod boolean;
+mod error;
+mod transcode;
+
+pub use boolean::Bool;
+pub use error::{Error, Result};
+pub use transcode::{transcode_bin_to_text, transcode_text_to_bin};
@@ -0,0 +1,80 @@
+use crate::error::Result;
+use zlisp_bin::list_header;
+use zlisp_text::{Element, WhitespaceConfig};
+
+/// Convert text zlisp data to binary zlisp data.
+///
+/// The output is identical to reading the text data into a value, and
+/// writing the value with [`zlisp_bin::to_vec`]. Since binary lists are
+/// prefixed by their length, each list's length is written once the list is
+/// complete.
+pub fn transcode_text_to_bin(s: &str) -> Result<Vec<u8>> {
+    use zlisp_text::Event;
+
+    let mut buf = Vec::new();
+    // binary zlisp data must always start with a list of length 1
+    buf.extend_from_slice(&list_header(1)?);
+    // the offset of each open list, and the number of elements in it
+    let mut lists: Vec<(usize, usize)> = Vec::new();
+    for event in zlisp_text::events(s) {
+        let event = event?;
+        if let (Some((_offset, count)), false) = (lists.last_mut(), event == Event::ListEnd) {
+            *count += 1;
+        }
+        match event {
+            Event::ListStart => {
+                lists.push((buf.len(), 0));
+                buf.extend_from_slice(&list_header(0)?);
+            }
+            Event::ListEnd => {
+                // PANIC: the events are balanced, so every end has a start
+                let (offset, count) = lists.pop().unwrap();
+                let header = list_header(count)?;
+                buf[offset..offset + header.len()].copy_from_slice(&header);
+            }
+            // the scalars are written by the binary writer, which also
+            // validates strings
+            Event::Int(v) => buf.extend(zlisp_bin::to_vec_raw(&v)?),
+            Event::Float(v) => buf.extend(zlisp_bin::to_vec_raw(&v)?),
+            Event::Str(v) => buf.extend(zlisp_bin::to_vec_raw(&v)?),
+        }
+    }
+    Ok(buf)
+}
+
+/// Convert binary zlisp data to text zlisp data.
+///
+/// The output is identical to reading the binary data into a value, and
+/// writing the value with [`zlisp_text::to_pretty`]. Since the layout of the
+/// text depends on the contents of lists, the elements are gathered before
+/// being written, but no value is built.
+///
+/// The text format only supports strings, so binary data containing
+/// [`zlisp_bin::Bytes`] can't be converted unless the bytes are a valid
+/// string.
+pub fn transcode_bin_to_text(data: &[u8], config: &WhitespaceConfig<'_>) -> Result<String> {
+    use zlisp_bin::Event;
+
+    // the elements of each open list, including a list for the root value
+    let mut lists: Vec<Vec<Element>> = vec![Vec::new()];
+    for event in zlisp_bin::events(data) {
+        let element = match event? {
+            Event::ListStart(len) => {
+                lists.push(Vec::with_capacity(len));
+                continue;
+            }
+            Event::ListEnd => {
+                // PANIC: the events are balanced, so every end has a start
+                Element::list(lists.pop().unwrap())?
+            }
+            Event::Int(v) => Element::int(v),
+            Event::Float(v) => Element::float(v),
+            Event::Str(v) => Element::string(v)?,
+        };
+        // PANIC: the root list is never popped
+        lists.last_mut().unwrap().push(element);
+    }
+    // PANIC: the events for valid data always contain exactly one root value
+    let root = lists.pop().and_then(|mut root| root.pop()).unwrap();
+    Ok(zlisp_text::write_element(root, config))
+}
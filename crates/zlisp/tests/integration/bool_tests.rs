@@ -0,0 +1,74 @@
+use assert_matches::assert_matches;
+use serde_derive::{Deserialize, Serialize};
+use zlisp::Bool;
+use zlisp_text::WhitespaceConfig;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Flags {
+    a: Bool,
+    b: Bool,
+    c: Option<Bool>,
+}
+
+const FLAGS: Flags = Flags {
+    a: Bool::new(true),
+    b: Bool::new(false),
+    c: Some(Bool::new(true)),
+};
+
+#[test]
+fn conv_tests() {
+    assert!(Bool::from(true).get());
+    assert!(!bool::from(Bool::new(false)));
+    assert!(Bool::new(true) == true);
+    assert!(false == Bool::new(false));
+    assert_eq!(Bool::default(), false);
+}
+
+#[test]
+fn bin_round_trip_tests() {
+    let bin = zlisp_bin::to_vec(&FLAGS).unwrap();
+    // the flags are written as ints
+    let ints = zlisp_bin::to_vec(&("a", 1, "b", 0, "c", (1,))).unwrap();
+    assert_eq!(bin, ints);
+    let actual: Flags = zlisp_bin::from_slice(&bin).unwrap();
+    assert_eq!(actual, FLAGS);
+}
+
+#[test]
+fn text_round_trip_tests() {
+    let config = WhitespaceConfig::default();
+    let text = zlisp_text::to_string(&FLAGS, config).unwrap();
+    let actual: Flags = zlisp_text::from_str(&text).unwrap();
+    assert_eq!(actual, FLAGS);
+
+    let text = zlisp_text::to_pretty(&FLAGS, config).unwrap();
+    assert_eq!(text, "(a\t1\tb\t0\tc\t(1))\r\n");
+    let actual: Flags = zlisp_text::from_str(&text).unwrap();
+    assert_eq!(actual, FLAGS);
+}
+
+#[test]
+fn invalid_tests() {
+    let err = zlisp_text::from_str::<Bool>("2").unwrap_err();
+    assert_matches!(err.code(), zlisp_text::ErrorCode::Custom(_));
+    assert_eq!(
+        err.to_string(),
+        "invalid value: integer `2`, expected the integer 0 or 1"
+    );
+    let err = zlisp_text::from_str::<Bool>("true").unwrap_err();
+    assert_matches!(err.code(), zlisp_text::ErrorCode::ParseIntError { .. });
+
+    let bin = zlisp_bin::to_vec(&-1).unwrap();
+    let err = zlisp_bin::from_slice::<Bool>(&bin).unwrap_err();
+    assert_matches!(err.code(), zlisp_bin::ErrorCode::Custom(_));
+}
+
+#[test]
+fn plain_bool_tests() {
+    // plain booleans are still not supported
+    let err = zlisp_text::to_string(&true, WhitespaceConfig::default()).unwrap_err();
+    assert_matches!(err.code(), zlisp_text::ErrorCode::UnsupportedType);
+    let err = zlisp_bin::to_vec(&true).unwrap_err();
+    assert_matches!(err.code(), zlisp_bin::ErrorCode::UnsupportedType);
+}
@@ -0,0 +1,2 @@
+mod bool_tests;
+mod transcode_tests;
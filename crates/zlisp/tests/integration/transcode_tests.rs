@@ -0,0 +1,102 @@
+use zlisp::{transcode_bin_to_text, transcode_text_to_bin, Error};
+use zlisp_text::WhitespaceConfig;
+use zlisp_value::Value;
+
+const TEXT: &str = r#"(
+	name	"foo bar"
+	int	-1
+	float	1.5
+	empty	()
+	compact	(1 2 3)
+	nested	((a (b (c))) (1 2 3 4 5 6 7 8))
+)"#;
+
+fn config() -> WhitespaceConfig<'static> {
+    WhitespaceConfig::builder().newline("\n").build()
+}
+
+#[test]
+fn text_to_bin_tests() {
+    let value: Value = zlisp_text::from_str(TEXT).unwrap();
+    let expected = zlisp_bin::to_vec(&value).unwrap();
+    let actual = transcode_text_to_bin(TEXT).unwrap();
+    assert_eq!(actual, expected);
+
+    for text in ["1", "1.0", "foo", "()", "((()))"] {
+        let value: Value = zlisp_text::from_str(text).unwrap();
+        let expected = zlisp_bin::to_vec(&value).unwrap();
+        let actual = transcode_text_to_bin(text).unwrap();
+        assert_eq!(actual, expected, "{}", text);
+    }
+}
+
+#[test]
+fn bin_to_text_tests() {
+    for text in [TEXT, "1", "1.0", "foo", "()", "((()))"] {
+        let value: Value = zlisp_text::from_str(text).unwrap();
+        let bin = zlisp_bin::to_vec(&value).unwrap();
+        let expected = zlisp_text::to_pretty(&value, &config()).unwrap();
+        let actual = transcode_bin_to_text(&bin, &config()).unwrap();
+        assert_eq!(actual, expected, "{}", text);
+    }
+}
+
+#[test]
+fn value_round_trip_tests() {
+    // the output is byte-identical to reading and writing a value
+    let max_list = format!("({})", "1 ".repeat(4096));
+    let cases = [
+        "-0.0",
+        "\"1\"",
+        "\"foo bar\"",
+        "\"\"",
+        "(() (()) ((1) 2))",
+        "(a 1 b \"2\" c 3.25 d -2147483648)",
+        max_list.as_str(),
+    ];
+    for text in cases {
+        let value: Value = zlisp_text::from_str(text).unwrap();
+        let expected = zlisp_bin::to_vec(&value).unwrap();
+        let bin = transcode_text_to_bin(text).unwrap();
+        assert_eq!(bin, expected, "{}", text);
+
+        let expected = zlisp_text::to_pretty(&value, &config()).unwrap();
+        let actual = transcode_bin_to_text(&bin, &config()).unwrap();
+        assert_eq!(actual, expected, "{}", text);
+    }
+}
+
+#[test]
+fn round_trip_tests() {
+    let value: Value = zlisp_text::from_str(TEXT).unwrap();
+    let expected = zlisp_text::to_pretty(&value, &config()).unwrap();
+    let bin = transcode_text_to_bin(TEXT).unwrap();
+    let text = transcode_bin_to_text(&bin, &config()).unwrap();
+    assert_eq!(text, expected);
+    assert_eq!(transcode_text_to_bin(&text).unwrap(), bin);
+}
+
+#[test]
+fn text_to_bin_err_tests() {
+    let err = transcode_text_to_bin("(1 2").unwrap_err();
+    assert!(matches!(err, Error::Text(_)), "{:?}", err);
+
+    // the text format allows longer lists than the binary format
+    let text = format!("({})", "1 ".repeat(4097));
+    let err = transcode_text_to_bin(&text).unwrap_err();
+    match err {
+        Error::Bin(e) => assert!(matches!(e.code(), zlisp_bin::ErrorCode::SequenceTooLong)),
+        e => panic!("{:?}", e),
+    }
+}
+
+#[test]
+fn bin_to_text_err_tests() {
+    let err = transcode_bin_to_text(&[], &config()).unwrap_err();
+    assert!(matches!(err, Error::Bin(_)), "{:?}", err);
+
+    let mut bin = transcode_text_to_bin("1").unwrap();
+    bin.push(0);
+    let err = transcode_bin_to_text(&bin, &config()).unwrap_err();
+    assert!(matches!(err, Error::Bin(_)), "{:?}", err);
+}
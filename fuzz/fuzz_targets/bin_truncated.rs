@@ -0,0 +1,19 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use zlisp_bin::{events, from_slice, RawDocument};
+use zlisp_value::Value;
+
+fuzz_target!(|data: &[u8]| {
+    // truncate the input at every token boundary, since a token is always a
+    // multiple of 4 bytes, except for string data
+    for len in (0..=data.len()).step_by(4) {
+        let data = &data[..len];
+        let _ = from_slice::<Value>(data);
+        let _ = RawDocument::from_slice(data);
+        for event in events(data) {
+            if event.is_err() {
+                break;
+            }
+        }
+    }
+});